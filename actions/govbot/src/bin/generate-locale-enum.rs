@@ -16,6 +16,8 @@ struct LocaleConfig {
     #[allow(dead_code)]
     template: String,
     #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
     labels: Vec<String>,
 }
 
@@ -33,15 +35,19 @@ fn locale_to_variant(locale: &str) -> String {
     }
 }
 
-fn get_working_locales(config_path: &PathBuf) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+/// Working locale codes with their display names (the code itself when the config has no name)
+fn get_working_locales(config_path: &PathBuf) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
     let content = fs::read_to_string(config_path)?;
     let config: Config = serde_yaml::from_str(&content)?;
 
-    let mut working_locales: Vec<String> = config
+    let mut working_locales: Vec<(String, String)> = config
         .locales
         .into_iter()
         .filter(|(_, locale_config)| locale_config.labels.contains(&"working".to_string()))
-        .map(|(locale, _)| locale)
+        .map(|(locale, locale_config)| {
+            let name = locale_config.name.unwrap_or_else(|| locale.to_uppercase());
+            (locale, name)
+        })
         .collect();
 
     working_locales.sort();
@@ -49,13 +55,13 @@ fn get_working_locales(config_path: &PathBuf) -> Result<Vec<String>, Box<dyn std
 }
 
 fn generate_rust_enum(
-    locales: &[String],
+    locales: &[(String, String)],
     output_path: &PathBuf,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Create mapping of locale -> variant name
     let locale_variants: Vec<(String, String)> = locales
         .iter()
-        .map(|loc| (loc.clone(), locale_to_variant(loc)))
+        .map(|(loc, _)| (loc.clone(), locale_to_variant(loc)))
         .collect();
 
     // Generate enum variants
@@ -84,6 +90,18 @@ fn generate_rust_enum(
         })
         .collect();
 
+    // Generate match arms for display_name
+    let display_name_arms: Vec<String> = locales
+        .iter()
+        .map(|(locale, name)| {
+            format!(
+                "            WorkingLocale::{} => {:?},",
+                locale_to_variant(locale),
+                name
+            )
+        })
+        .collect();
+
     // Generate match arms for From<&str>
     let from_str_arms: Vec<String> = locale_variants
         .iter()
@@ -135,6 +153,14 @@ impl WorkingLocale {{
     pub fn as_lowercase(&self) -> &'static str {{
         match self {{
             WorkingLocale::All => "all",
+{}
+        }}
+    }}
+    
+    /// Get the locale's display name (e.g. "Wyoming")
+    pub fn display_name(&self) -> &'static str {{
+        match self {{
+            WorkingLocale::All => "All",
 {}
         }}
     }}
@@ -160,6 +186,7 @@ impl std::fmt::Display for WorkingLocale {{
         all_items.join("\n"),
         as_str_arms.join("\n"),
         as_lowercase_arms.join("\n"),
+        display_name_arms.join("\n"),
         from_str_arms.join("\n")
     );
 
@@ -198,7 +225,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         output_path.display(),
         locales.len()
     );
-    println!("📋 Working locales: {}", locales.join(", "));
+    let codes: Vec<&str> = locales.iter().map(|(locale, _)| locale.as_str()).collect();
+    println!("📋 Working locales: {}", codes.join(", "));
 
     Ok(())
 }
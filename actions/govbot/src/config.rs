@@ -8,11 +8,29 @@ pub enum SortOrder {
     Descending,
 }
 
+impl SortOrder {
+    /// Accepted spellings, as listed in error messages
+    pub const VALUES: &'static [&'static str] = &["ASC", "DESC"];
+
+    /// Parse `ASC` or `DESC` (case-insensitive), rejecting anything else
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.trim().to_uppercase().as_str() {
+            "ASC" => Ok(SortOrder::Ascending),
+            "DESC" => Ok(SortOrder::Descending),
+            _ => Err(Error::Config(format!(
+                "Invalid sort order '{}'. Allowed values are: {}",
+                s,
+                Self::VALUES.join(", ")
+            ))),
+        }
+    }
+}
+
 impl From<&str> for SortOrder {
     fn from(s: &str) -> Self {
         match s.to_uppercase().as_str() {
             "ASC" => SortOrder::Ascending,
-            "DESC" | _ => SortOrder::Descending,
+            _ => SortOrder::Descending,
         }
     }
 }
@@ -21,8 +39,37 @@ impl From<&str> for SortOrder {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum JoinOption {
     Bill,
+    /// Attach the complete metadata.json to each log entry
+    FullMetadata,
 }
 
+impl JoinOption {
+    /// Accepted names, as listed in error messages
+    pub const VALUES: &'static [&'static str] = &["bill", "full_metadata"];
+
+    /// Parse one join name, listing the accepted ones when it isn't known
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.trim() {
+            "bill" => Ok(JoinOption::Bill),
+            "full_metadata" => Ok(JoinOption::FullMetadata),
+            "" => Err(Error::Config(format!(
+                "Empty join option. Allowed values are: {}",
+                Self::VALUES.join(", ")
+            ))),
+            other => Err(Error::Config(format!(
+                "Invalid join value '{}'. Allowed values are: {}",
+                other,
+                Self::VALUES.join(", ")
+            ))),
+        }
+    }
+}
+
+/// Default `Config::max_depth`. Log files sit 8 levels below a repo directory
+/// (`country:*/state:*/sessions/<session>/bills/<bill>/logs/<file>.json`) and 9 below the
+/// repos directory, so this leaves room for extra nesting without walking a looped tree forever.
+pub const DEFAULT_MAX_DEPTH: usize = 12;
+
 /// Configuration for the pipeline processor
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -31,6 +78,19 @@ pub struct Config {
     pub sort_order: SortOrder,
     pub limit: Option<usize>,
     pub join_options: Vec<JoinOption>,
+    /// Validate each log entry against the embedded JSON Schema (off by default)
+    pub validate: bool,
+    /// Threads used to walk the repo tree during discovery (`None` uses all cores)
+    pub walk_threads: Option<usize>,
+    /// Read vote-event file bodies into `LogContent::VoteEvent::detail` (off by default)
+    pub vote_event_detail: bool,
+    /// Skip log files larger than this many bytes instead of reading them (`None` is unlimited)
+    pub max_file_size: Option<u64>,
+    /// Stream-parse regular log files, keeping only `action`, the bill id, and `timestamp`
+    /// instead of the whole document (off by default; for logs with large embedded documents)
+    pub streaming_parse: bool,
+    /// Deepest level walked below each search directory (`None` is unbounded; see `DEFAULT_MAX_DEPTH`)
+    pub max_depth: Option<usize>,
 }
 
 impl Config {
@@ -42,9 +102,20 @@ impl Config {
             sort_order: SortOrder::Descending,
             limit: None,
             join_options: vec![],
+            validate: false,
+            walk_threads: None,
+            vote_event_detail: false,
+            max_file_size: None,
+            streaming_parse: false,
+            max_depth: Some(DEFAULT_MAX_DEPTH),
         }
     }
 
+    /// Default configuration for the repos directory named by `GOVBOT_DIR` (see `GovbotEnv`)
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::new(GovbotEnv::from_env()?.repos_dir()))
+    }
+
     /// Validate the configuration
     pub fn validate(&self) -> Result<()> {
         if !self.git_dir.exists() {
@@ -61,6 +132,26 @@ impl Config {
             )));
         }
 
+        if let Err(e) = std::fs::read_dir(&self.git_dir) {
+            return Err(Error::Config(format!(
+                "Git directory is not readable: {} ({})",
+                self.git_dir.display(),
+                e
+            )));
+        }
+
+        if self.limit == Some(0) {
+            return Err(Error::Config(
+                "Limit must be at least 1 (leave it unset for no limit)".to_string(),
+            ));
+        }
+
+        if self.walk_threads == Some(0) {
+            return Err(Error::Config(
+                "Walk threads must be at least 1 (leave it unset to use all cores)".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -103,9 +194,9 @@ impl ConfigBuilder {
         self
     }
 
-    /// Set sort order from string
+    /// Set sort order from string (`ASC` or `DESC`)
     pub fn sort_order_str(mut self, order: &str) -> Result<Self> {
-        self.config.sort_order = SortOrder::from(order);
+        self.config.sort_order = SortOrder::parse(order)?;
         Ok(self)
     }
 
@@ -136,30 +227,57 @@ impl ConfigBuilder {
             return Ok(self);
         }
 
-        let opts: Result<Vec<JoinOption>> = options
-            .split(',')
-            .map(|s| {
-                let trimmed = s.trim();
-                if trimmed.is_empty() {
-                    return Err(Error::Config("Empty join option".to_string()));
-                }
-                match trimmed {
-                    "bill" => Ok(JoinOption::Bill),
-                    _ => Err(Error::Config(format!(
-                        "Invalid join value '{}'. Allowed values are: bill",
-                        trimmed
-                    ))),
-                }
-            })
-            .collect();
+        let opts: Result<Vec<JoinOption>> = options.split(',').map(JoinOption::parse).collect();
 
         self.config.join_options = opts?;
         Ok(self)
     }
 
+    /// Enable or disable schema validation of log entries
+    pub fn validate_entries(mut self, validate: bool) -> Self {
+        self.config.validate = validate;
+        self
+    }
+
+    /// Limit the number of threads used to walk the repo tree
+    pub fn walk_threads(mut self, threads: usize) -> Self {
+        self.config.walk_threads = Some(threads);
+        self
+    }
+
+    /// Include vote-event file bodies (tallies, individual votes) in emitted entries
+    pub fn vote_event_detail(mut self, detail: bool) -> Self {
+        self.config.vote_event_detail = detail;
+        self
+    }
+
+    /// Skip log files larger than `bytes`
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.config.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Read only the fields a log entry needs from each log file rather than the full document
+    pub fn streaming_parse(mut self, streaming: bool) -> Self {
+        self.config.streaming_parse = streaming;
+        self
+    }
+
+    /// Bound how deep discovery walks below each search directory (`None` removes the bound)
+    pub fn max_depth(mut self, depth: Option<usize>) -> Self {
+        self.config.max_depth = depth;
+        self
+    }
+
+    /// Check the configuration built so far: the git directory exists and is readable, and
+    /// limits and thread counts are at least 1. Sort order and join strings are checked when set.
+    pub fn validate(&self) -> Result<()> {
+        self.config.validate()
+    }
+
     /// Build the final configuration
     pub fn build(self) -> Result<Config> {
-        self.config.validate()?;
+        self.validate()?;
         Ok(self.config)
     }
 }
@@ -169,3 +287,61 @@ impl Default for Config {
         Self::new("tmp/repos")
     }
 }
+
+/// Parallel jobs when neither `--parallel` nor `GOVBOT_JOBS` is set
+pub const DEFAULT_JOBS: usize = 4;
+
+/// Settings shared by the CLI commands, resolved as flag > environment variable > default.
+/// Empty environment variables count as unset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GovbotEnv {
+    /// Base directory holding `repos/`, models, and manifests (`GOVBOT_DIR`, default `$CWD/.govbot`)
+    pub govbot_dir: PathBuf,
+    /// Parallel clone/delete operations (`GOVBOT_JOBS`, default `DEFAULT_JOBS`)
+    pub jobs: usize,
+    /// Token for private repos (`TOKEN`)
+    pub token: Option<String>,
+}
+
+impl GovbotEnv {
+    /// Resolve from environment variables and defaults only
+    pub fn from_env() -> Result<Self> {
+        Self::resolve(None, None, None)
+    }
+
+    /// Resolve with explicit flag values taking precedence over the environment
+    pub fn resolve(govbot_dir: Option<&str>, jobs: Option<usize>, token: Option<&str>) -> Result<Self> {
+        let govbot_dir = match govbot_dir.map(str::to_string).or_else(|| env_value("GOVBOT_DIR")) {
+            Some(dir) => PathBuf::from(dir),
+            None => std::env::current_dir()
+                .map_err(|_| Error::Config("Could not determine current working directory.".to_string()))?
+                .join(".govbot"),
+        };
+
+        let jobs = match jobs {
+            Some(jobs) => jobs,
+            None => match env_value("GOVBOT_JOBS") {
+                Some(value) => value.trim().parse().map_err(|_| {
+                    Error::Config(format!("GOVBOT_JOBS must be a positive integer, got '{}'", value))
+                })?,
+                None => DEFAULT_JOBS,
+            },
+        };
+        if jobs == 0 {
+            return Err(Error::Config("Parallel jobs must be at least 1".to_string()));
+        }
+
+        let token = token.map(str::to_string).or_else(|| env_value("TOKEN"));
+
+        Ok(Self { govbot_dir, jobs, token })
+    }
+
+    /// Directory the data pipeline repos are cloned into
+    pub fn repos_dir(&self) -> PathBuf {
+        self.govbot_dir.join("repos")
+    }
+}
+
+fn env_value(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
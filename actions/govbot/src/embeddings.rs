@@ -3,6 +3,7 @@ use ort::session::Session;
 use ort::value::Value;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use tokenizers::Tokenizer;
 
 use ndarray::Array1;
@@ -10,7 +11,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use crate::selectors::ocd_files_select_default;
+use crate::selectors::{ocd_files_select, TextExtractionConfig};
 
 /// Breakdown of scoring components for a tag match
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,14 +35,44 @@ pub struct TagFile {
     pub bills: HashMap<String, BillTagResult>,
 }
 
+/// Current `.tag.json` schema version; files written before versioning read as 0
+pub const TAG_FILE_VERSION: u32 = 1;
+
 /// Metadata about the tag file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TagFileMetadata {
+    /// Schema version the file was written with (see `TAG_FILE_VERSION`)
+    #[serde(default)]
+    pub version: u32,
     pub last_run: String,
     pub model: String,
     pub tag_config_hash: String,
 }
 
+impl TagFile {
+    /// Upgrade a file written by an older schema version in place.
+    /// Returns true when anything changed and the file should be rewritten.
+    pub fn migrate(&mut self) -> bool {
+        if self.metadata.version >= TAG_FILE_VERSION {
+            return false;
+        }
+        // Fields added since the file was written were filled with serde defaults on load;
+        // the config hash is recomputed over the tag config as it deserializes today
+        self.metadata.tag_config_hash =
+            hash_text(&serde_json::to_string(&self.tag_config).unwrap_or_default());
+        self.metadata.version = TAG_FILE_VERSION;
+        true
+    }
+}
+
+/// Read a `.tag.json` file, migrating it in memory if it predates `TAG_FILE_VERSION`.
+/// The file on disk is left as is; `tag` writes the upgraded form the next time it updates it.
+pub fn load_tag_file<P: AsRef<Path>>(path: P) -> anyhow::Result<TagFile> {
+    let mut tag_file: TagFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    tag_file.migrate();
+    Ok(tag_file)
+}
+
 /// Result for a single bill
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BillTagResult {
@@ -56,6 +87,14 @@ pub fn hash_text(text: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Fast non-cryptographic hash (XXH3) for in-memory cache keys.
+///
+/// Stable across runs, but not collision resistant; anything written to tag files keeps using
+/// [`hash_text`].
+pub fn hash_text_fast(text: &str) -> u64 {
+    xxhash_rust::xxh3::xxh3_64(text.as_bytes())
+}
+
 /// Tag definition provided by the creator
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TagDefinition {
@@ -120,10 +159,289 @@ pub fn load_tags_config<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<TagDefini
     Ok(tags)
 }
 
+/// Load the optional `text_extraction:` section of govbot.yml (defaults when absent)
+pub fn load_text_extraction_config<P: AsRef<Path>>(path: P) -> anyhow::Result<TextExtractionConfig> {
+    #[derive(Deserialize)]
+    struct Raw {
+        #[serde(default)]
+        text_extraction: TextExtractionConfig,
+    }
+    let contents = std::fs::read_to_string(path)?;
+    let raw: Raw = serde_yaml::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Failed to parse govbot.yml: {}", e))?;
+    Ok(raw.text_extraction)
+}
+
+/// Smallest plausible size of the all-MiniLM-L6-v2 ONNX export (the real file is ~90MB)
+pub const MIN_MODEL_SIZE: u64 = 1024 * 1024;
+
+/// Smallest plausible size of a Hugging Face tokenizer.json
+pub const MIN_TOKENIZER_SIZE: u64 = 1024;
+
+/// Check that a downloaded ONNX model looks complete before handing it to ONNX Runtime.
+///
+/// ONNX files are protobuf-encoded `ModelProto` messages whose first field is
+/// `ir_version` (field 1, varint), so a valid file starts with the byte `0x08`.
+/// Combined with a minimum size this catches empty, truncated, and HTML error-page downloads.
+pub fn validate_model_file<P: AsRef<Path>>(path: P) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    let size = std::fs::metadata(path)?.len();
+    if size < MIN_MODEL_SIZE {
+        return Err(anyhow::anyhow!(
+            "{} is too small to be a valid ONNX model ({} bytes, expected at least {})",
+            path.display(),
+            size,
+            MIN_MODEL_SIZE
+        ));
+    }
+
+    let mut magic = [0u8; 1];
+    std::io::Read::read_exact(&mut std::fs::File::open(path)?, &mut magic)?;
+    if magic[0] != 0x08 {
+        return Err(anyhow::anyhow!(
+            "{} does not look like an ONNX model (unexpected leading byte 0x{:02x})",
+            path.display(),
+            magic[0]
+        ));
+    }
+    Ok(())
+}
+
+/// Check that a downloaded tokenizer.json is complete, parseable JSON with a `model` section.
+pub fn validate_tokenizer_file<P: AsRef<Path>>(path: P) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    let size = std::fs::metadata(path)?.len();
+    if size < MIN_TOKENIZER_SIZE {
+        return Err(anyhow::anyhow!(
+            "{} is too small to be a valid tokenizer ({} bytes, expected at least {})",
+            path.display(),
+            size,
+            MIN_TOKENIZER_SIZE
+        ));
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    let value: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("{} is not valid JSON (truncated download?): {}", path.display(), e))?;
+    if value.get("model").is_none() {
+        return Err(anyhow::anyhow!("{} is missing the tokenizer `model` section", path.display()));
+    }
+    Ok(())
+}
+
+/// Number of attempts `download_file` makes before giving up
+pub const DOWNLOAD_ATTEMPTS: usize = 4;
+
+/// Download `url` to `path`, retrying transient failures and resuming partial downloads.
+/// Any existing file at `path` is replaced.
+pub fn download_file(url: &str, path: &Path) -> crate::Result<()> {
+    download_file_with_retries(url, path, DOWNLOAD_ATTEMPTS, std::time::Duration::from_secs(2))
+}
+
+/// `download_file` with an explicit attempt count and delay between attempts
+/// (the delay grows linearly with each failed attempt).
+///
+/// The response body is streamed to disk. When an attempt fails partway through,
+/// the next attempt asks for the remainder with an HTTP `Range` request; servers
+/// that ignore it simply send the whole file again.
+pub fn download_file_with_retries(
+    url: &str,
+    path: &Path,
+    attempts: usize,
+    retry_delay: std::time::Duration,
+) -> crate::Result<()> {
+    fetch_with_retries(url, path, attempts, retry_delay).map(|_| ())
+}
+
+/// Body of `download_file_with_retries`; returns the SHA-256 the server advertised for the file, if any
+fn fetch_with_retries(
+    url: &str,
+    path: &Path,
+    attempts: usize,
+    retry_delay: std::time::Duration,
+) -> crate::Result<Option<String>> {
+    let client = reqwest::blocking::Client::builder()
+        .connect_timeout(std::time::Duration::from_secs(30))
+        .timeout(None)
+        .build()
+        .map_err(|e| crate::Error::Download(format!("failed to build HTTP client: {}", e)))?;
+
+    // Start fresh; only bytes written by this call are eligible for resuming
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+
+    let mut last_error = String::new();
+    for attempt in 1..=attempts.max(1) {
+        eprintln!("Downloading {}...", url);
+        match download_attempt(&client, url, path) {
+            Ok(sha256) => return Ok(sha256),
+            Err(e) => {
+                eprintln!("Download attempt {}/{} failed: {}", attempt, attempts, e);
+                last_error = e.to_string();
+                if attempt < attempts {
+                    std::thread::sleep(retry_delay * attempt as u32);
+                }
+            }
+        }
+    }
+
+    Err(crate::Error::Download(format!(
+        "{} failed after {} attempts: {}",
+        url, attempts, last_error
+    )))
+}
+
+/// Number of times `download_verified` downloads a file that fails verification
+pub const VERIFY_ATTEMPTS: usize = 3;
+
+/// Download `url` to `path` and check the result, deleting and re-downloading a file that fails.
+///
+/// A file must match the SHA-256 the server advertises (Hugging Face sends it as
+/// `X-Linked-Etag` for LFS files) and pass `validate`.
+pub fn download_verified(url: &str, path: &Path, validate: fn(&Path) -> anyhow::Result<()>) -> anyhow::Result<()> {
+    download_verified_with_retries(url, path, VERIFY_ATTEMPTS, std::time::Duration::from_secs(2), validate)
+}
+
+/// `download_verified` with an explicit attempt count and delay between transient-failure retries
+pub fn download_verified_with_retries(
+    url: &str,
+    path: &Path,
+    attempts: usize,
+    retry_delay: std::time::Duration,
+    validate: fn(&Path) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let mut last_error = None;
+    for attempt in 1..=attempts.max(1) {
+        let result = fetch_with_retries(url, path, DOWNLOAD_ATTEMPTS, retry_delay)
+            .map_err(anyhow::Error::from)
+            .and_then(|expected| match expected {
+                Some(expected) => verify_sha256(path, &expected),
+                None => Ok(()),
+            })
+            .and_then(|_| validate(path));
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                eprintln!("Attempt {}/{} for {} failed verification: {}", attempt, attempts, path.display(), e);
+                let _ = std::fs::remove_file(path);
+                last_error = Some(e);
+            }
+        }
+    }
+    Err(anyhow::anyhow!(
+        "{} could not be downloaded intact after {} attempts: {}",
+        path.display(),
+        attempts,
+        last_error.map(|e| e.to_string()).unwrap_or_default()
+    ))
+}
+
+/// Check that the file at `path` hashes to `expected` (lowercase hex SHA-256)
+pub fn verify_sha256(path: &Path, expected: &str) -> anyhow::Result<()> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut std::fs::File::open(path)?, &mut hasher)?;
+    let actual = format!("{:x}", hasher.finalize());
+    if actual != expected {
+        return Err(anyhow::anyhow!(
+            "{} has the wrong SHA-256 (expected {}, got {})",
+            path.display(),
+            expected,
+            actual
+        ));
+    }
+    Ok(())
+}
+
+/// SHA-256 advertised in an `X-Linked-Etag` header; other etags (e.g. git blob ids) are ignored
+fn advertised_sha256(response: &reqwest::blocking::Response) -> Option<String> {
+    let etag = response.headers().get("x-linked-etag")?.to_str().ok()?;
+    let etag = etag.trim_start_matches("W/").trim_matches('"').to_ascii_lowercase();
+    (etag.len() == 64 && etag.bytes().all(|b| b.is_ascii_hexdigit())).then_some(etag)
+}
+
+/// A single download attempt, resuming from the current partial file length if any;
+/// returns the SHA-256 the server advertised for the file
+fn download_attempt(client: &reqwest::blocking::Client, url: &str, path: &Path) -> crate::Result<Option<String>> {
+    let offset = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if offset > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+    }
+    let mut response = request
+        .send()
+        .map_err(|e| crate::Error::Download(format!("{}: {}", url, e)))?;
+
+    let status = response.status();
+    let mut file = if status == reqwest::StatusCode::PARTIAL_CONTENT && offset > 0 {
+        std::fs::OpenOptions::new().append(true).open(path)?
+    } else if status.is_success() {
+        // Full body (first attempt, or the server ignored the Range header)
+        std::fs::File::create(path)?
+    } else {
+        if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            // Partial file is unusable; restart from scratch next attempt
+            std::fs::remove_file(path)?;
+        }
+        return Err(crate::Error::Download(format!("{}: HTTP {}", url, status)));
+    };
+
+    // Stream the body straight to disk so the ~90MB model is never held in memory
+    let expected = response.content_length();
+    let written = std::io::copy(&mut response, &mut file)
+        .map_err(|e| crate::Error::Download(format!("{}: interrupted: {}", url, e)))?;
+    if let Some(expected) = expected {
+        if written != expected {
+            return Err(crate::Error::Download(format!(
+                "{}: truncated: received {} of {} bytes",
+                url, written, expected
+            )));
+        }
+    }
+    Ok(advertised_sha256(&response))
+}
+
+/// Model output the matcher mean-pools into an embedding
+pub const HIDDEN_STATE_OUTPUT: &str = "last_hidden_state";
+
+/// Check the outputs of a probe run (each output's name and shape) against what `embed` needs:
+/// a token-level `last_hidden_state` of shape `[batch, seq, hidden]`
+pub fn validate_encoder_outputs(outputs: &[(String, Vec<i64>)]) -> anyhow::Result<()> {
+    let Some((_, shape)) = outputs.iter().find(|(name, _)| name == HIDDEN_STATE_OUTPUT) else {
+        let names: Vec<&str> = outputs.iter().map(|(name, _)| name.as_str()).collect();
+        return Err(anyhow::anyhow!(
+            "model output '{}' not found (model outputs: {}); this matcher requires a token-level encoder",
+            HIDDEN_STATE_OUTPUT,
+            if names.is_empty() { "none".to_string() } else { names.join(", ") }
+        ));
+    };
+    if shape.len() != 3 || shape.iter().any(|&d| d < 1) {
+        return Err(anyhow::anyhow!(
+            "model output '{}' has shape {:?}, expected [batch, seq, hidden]; this matcher requires a token-level encoder",
+            HIDDEN_STATE_OUTPUT,
+            shape
+        ));
+    }
+    Ok(())
+}
+
+/// Turns text into an embedding vector; `TagMatcher` scores against whatever this produces
+pub trait Embedder: Send {
+    fn embed(&mut self, text: &str) -> anyhow::Result<Array1<f32>>;
+
+    /// Another embedder over the same model, for a worker scoring alongside this one
+    fn fork(&self) -> anyhow::Result<Self>
+    where
+        Self: Sized;
+}
+
 /// Lightweight embedding service powered by ONNX Runtime
 pub struct EmbeddingService {
     session: Session,
     tokenizer: Tokenizer,
+    /// Model file contents, kept so `fork` can open more sessions without rereading the file
+    model: Arc<Vec<u8>>,
 }
 
 impl EmbeddingService {
@@ -131,14 +449,29 @@ impl EmbeddingService {
         let tokenizer = Tokenizer::from_file(tokenizer_path.as_ref())
             .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
 
-        let session = Session::builder()?.commit_from_file(model_path)?;
+        let model = Arc::new(std::fs::read(model_path.as_ref())?);
+        let session = Session::builder()?.commit_from_memory(&model)?;
 
-        Ok(Self { session, tokenizer })
+        let mut service = Self { session, tokenizer, model };
+        service.probe()?;
+        Ok(service)
     }
 
-    /// Embed text using the configured model with mean pooling over last hidden state
-    pub fn embed(&mut self, text: &str) -> anyhow::Result<Array1<f32>> {
-        // Tokenize
+    /// Run a tiny input through the model and check its output before any entries are processed
+    fn probe(&mut self) -> anyhow::Result<()> {
+        let outputs = self.run("probe")?;
+        let shapes: Vec<(String, Vec<i64>)> = outputs
+            .iter()
+            .map(|(name, value)| {
+                let shape = value.dtype().tensor_shape().map(|s| s.to_vec()).unwrap_or_default();
+                (name.to_string(), shape)
+            })
+            .collect();
+        validate_encoder_outputs(&shapes)
+    }
+
+    /// Tokenize `text` and run it through the model as a batch of one
+    fn run(&mut self, text: &str) -> anyhow::Result<ort::session::SessionOutputs<'_>> {
         let encoding = self
             .tokenizer
             .encode(text, true)
@@ -152,14 +485,19 @@ impl EmbeddingService {
         let attention_mask_vec: Vec<i64> = mask.iter().map(|&x| x as i64).collect();
         let token_type_vec: Vec<i64> = type_ids.iter().map(|&x| x as i64).collect();
 
-        let outputs = self.session.run(inputs![
+        Ok(self.session.run(inputs![
             "input_ids" => Value::from_array((vec![1_i64, ids.len() as i64], input_ids))?,
             "attention_mask" => Value::from_array((vec![1_i64, mask.len() as i64], attention_mask_vec))?,
             "token_type_ids" => Value::from_array((vec![1_i64, type_ids.len() as i64], token_type_vec))?,
-        ])?;
+        ])?)
+    }
+
+    /// Embed text using the configured model with mean pooling over last hidden state
+    pub fn embed(&mut self, text: &str) -> anyhow::Result<Array1<f32>> {
+        let outputs = self.run(text)?;
 
         // Use last_hidden_state and mean-pool
-        let hidden = outputs["last_hidden_state"].try_extract_array::<f32>()?;
+        let hidden = outputs[HIDDEN_STATE_OUTPUT].try_extract_array::<f32>()?;
 
         // hidden shape: [batch, seq_len, hidden_dim]
         let shape = hidden.shape();
@@ -175,8 +513,8 @@ impl EmbeddingService {
                 pooled[h] += hidden[[0, i, h]];
             }
         }
-        for h in 0..hidden_dim {
-            pooled[h] /= seq_len as f32;
+        for value in pooled.iter_mut() {
+            *value /= seq_len as f32;
         }
         let pooled = Array1::from(pooled);
 
@@ -184,13 +522,34 @@ impl EmbeddingService {
     }
 
     pub fn cosine_similarity(&self, a: &Array1<f32>, b: &Array1<f32>) -> f32 {
-        let dot = a.dot(b);
-        let norm_a = a.dot(a).sqrt();
-        let norm_b = b.dot(b).sqrt();
-        dot / (norm_a * norm_b).max(1e-9)
+        cosine_similarity(a, b)
     }
 }
 
+impl Embedder for EmbeddingService {
+    fn embed(&mut self, text: &str) -> anyhow::Result<Array1<f32>> {
+        EmbeddingService::embed(self, text)
+    }
+
+    /// A new session over the same model bytes and tokenizer; the model already passed `probe`
+    fn fork(&self) -> anyhow::Result<Self> {
+        let session = Session::builder()?.commit_from_memory(&self.model)?;
+        Ok(Self {
+            session,
+            tokenizer: self.tokenizer.clone(),
+            model: Arc::clone(&self.model),
+        })
+    }
+}
+
+/// Cosine similarity of two embeddings
+pub fn cosine_similarity(a: &Array1<f32>, b: &Array1<f32>) -> f32 {
+    let dot = a.dot(b);
+    let norm_a = a.dot(a).sqrt();
+    let norm_b = b.dot(b).sqrt();
+    dot / (norm_a * norm_b).max(1e-9)
+}
+
 /// Return all keywords from the list that appear in the text
 /// (case-insensitive, word-boundary aware).
 fn find_matching_keywords(text: &str, keywords: &[String]) -> Vec<String> {
@@ -224,30 +583,122 @@ fn find_matching_keywords(text: &str, keywords: &[String]) -> Vec<String> {
     matches
 }
 
-/// Matcher that precomputes tag embeddings and scores logs against them
-pub struct TagMatcher {
-    embeddings: std::sync::Mutex<EmbeddingService>,
+/// Whether any of `keywords` appears in `text`, with the same case-insensitive, word-boundary
+/// matching tags use for `include_keywords` and `exclude_keywords`
+pub fn matches_keywords(text: &str, keywords: &[String]) -> bool {
+    !keywords.is_empty() && !find_matching_keywords(text, keywords).is_empty()
+}
+
+/// Keyword boost added to the weighted score when an include keyword matches
+const KEYWORD_BOOST: f32 = 0.4;
+
+/// Largest amount subtracted for similarity to a negative example
+const NEGATIVE_PENALTY_WEIGHT: f32 = 0.25;
+
+/// Combine a tag's signals into its final score in `[0, 1]`.
+///
+/// `base` is the similarity to the tag's description, `example` the best similarity to one of
+/// its examples, and `negative` the best similarity to one of its negative examples.
+pub fn composite_score(
+    base: Option<f32>,
+    example: Option<f32>,
+    keyword_match: bool,
+    negative: Option<f32>,
+    threshold: f32,
+) -> f32 {
+    let mut score = 0.0;
+    let mut weight_sum = 0.0;
+
+    // Weight embeddings less when keywords match (keywords will add boost)
+    if let Some(base) = base {
+        let weight = if keyword_match { 0.35 } else { 0.5 };
+        score += base * weight;
+        weight_sum += weight;
+    }
+    if let Some(example) = example {
+        let weight = if keyword_match { 0.25 } else { 0.35 };
+        score += example * weight;
+        weight_sum += weight;
+    }
+
+    // Keywords are explicit signals from the tag definition, curated and highly reliable
+    if keyword_match {
+        score += KEYWORD_BOOST;
+        weight_sum += KEYWORD_BOOST;
+    }
+
+    // Normalize the weighted combination
+    if weight_sum > 0.0 {
+        score /= weight_sum;
+    }
+
+    // Keyword matches guarantee the threshold unless negated
+    if keyword_match {
+        score = score.max(threshold);
+    }
+
+    // Higher negative similarity = stronger penalty
+    if let Some(negative) = negative {
+        score = (score - negative * NEGATIVE_PENALTY_WEIGHT).max(0.0);
+    }
+
+    score.clamp(0.0, 1.0)
+}
+
+/// Lowest base similarity that can still reach `threshold` without a keyword match,
+/// assuming a perfect example similarity. Slightly conservative to absorb rounding.
+pub fn min_base_similarity(threshold: f32, has_examples: bool) -> f32 {
+    const MARGIN: f32 = 1e-4;
+    let floor = if has_examples {
+        // (0.5 * base + 0.35 * 1.0) / 0.85 >= threshold
+        (0.85 * threshold - 0.35) / 0.5
+    } else {
+        threshold
+    };
+    floor - MARGIN
+}
+
+/// Tag definitions and their precomputed embeddings, shared read-only by `TagMatcher::clone_shared`
+#[derive(Clone)]
+struct TagIndex {
     tag_embeddings: HashMap<String, Array1<f32>>,
     example_embeddings: HashMap<String, Vec<Array1<f32>>>,
     negative_example_embeddings: HashMap<String, Vec<Array1<f32>>>,
+    /// Per-tag `min_base_similarity`, below which scoring stops early
+    base_floors: HashMap<String, f32>,
     tags: HashMap<String, TagDefinition>,
 }
 
-impl TagMatcher {
+/// Matcher that precomputes tag embeddings and scores logs against them
+pub struct TagMatcher<E = EmbeddingService> {
+    embeddings: std::sync::Mutex<E>,
+    index: Arc<TagIndex>,
+    text_extraction: TextExtractionConfig,
+}
+
+impl TagMatcher<EmbeddingService> {
     pub fn from_files<P: AsRef<Path>>(
         model_path: P,
         tokenizer_path: P,
         tags_path: P,
     ) -> anyhow::Result<Self> {
-        let mut embeddings = EmbeddingService::new(&model_path, &tokenizer_path)?;
+        let embeddings = EmbeddingService::new(&model_path, &tokenizer_path)?;
 
         // Load tags YAML
         let tag_defs = load_tags_config(tags_path)?;
 
+        Self::new(embeddings, tag_defs)
+    }
+}
+
+impl<E: Embedder> TagMatcher<E> {
+    /// Build a matcher over `tag_defs`, embedding each tag's description and examples up front
+    pub fn new(mut embeddings: E, tag_defs: Vec<TagDefinition>) -> anyhow::Result<Self> {
         // Precompute tag embeddings
         let mut tag_embeddings = HashMap::new();
         let mut example_embeddings = HashMap::new();
         let mut negative_example_embeddings = HashMap::new();
+        let mut base_floors = HashMap::new();
         let mut tags_map = HashMap::new();
 
         for tag in tag_defs {
@@ -276,18 +727,56 @@ impl TagMatcher {
             }
             negative_example_embeddings.insert(tag.name.clone(), neg_example_embs);
 
+            base_floors.insert(tag.name.clone(), min_base_similarity(tag.threshold, !tag.examples.is_empty()));
             tags_map.insert(tag.name.clone(), tag);
         }
 
         Ok(Self {
             embeddings: std::sync::Mutex::new(embeddings),
-            tag_embeddings,
-            example_embeddings,
-            negative_example_embeddings,
-            tags: tags_map,
+            index: Arc::new(TagIndex {
+                tag_embeddings,
+                example_embeddings,
+                negative_example_embeddings,
+                base_floors,
+                tags: tags_map,
+            }),
+            text_extraction: TextExtractionConfig::default(),
+        })
+    }
+
+    /// A matcher for another worker: it gets its own embedding session (see `Embedder::fork`)
+    /// but shares this one's precomputed tag embeddings instead of recomputing them
+    pub fn clone_shared(&self) -> anyhow::Result<Self> {
+        Ok(Self {
+            embeddings: std::sync::Mutex::new(self.embeddings.lock().unwrap().fork()?),
+            index: Arc::clone(&self.index),
+            text_extraction: self.text_extraction.clone(),
         })
     }
 
+    /// Whether `other` was made by `clone_shared` from this matcher (or vice versa)
+    pub fn shares_tags_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.index, &other.index)
+    }
+
+    /// Choose which entry fields feed the text that is embedded and keyword-matched
+    pub fn with_text_extraction(mut self, config: TextExtractionConfig) -> Self {
+        self.text_extraction = config;
+        self
+    }
+
+    /// Use `threshold` for every tag instead of each tag's configured value
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        let index = Arc::make_mut(&mut self.index);
+        for tag in index.tags.values_mut() {
+            tag.threshold = threshold;
+            index
+                .base_floors
+                .insert(tag.name.clone(), min_base_similarity(threshold, !tag.examples.is_empty()));
+        }
+        self
+    }
+
     /// Calculate composite score using multiple signals
     fn calculate_composite_score(
         &self,
@@ -295,7 +784,6 @@ impl TagMatcher {
         log_text: &str,
         tag_name: &str,
         tag_def: &TagDefinition,
-        embeddings: &mut EmbeddingService,
     ) -> ScoreBreakdown {
         // 4. Exclude keywords: zero out if exclude keywords match (check first).
         // We don't currently expose which exclude keyword matched; we just block the tag.
@@ -320,75 +808,64 @@ impl TagMatcher {
         };
         let has_keyword_match = !include_matches.is_empty();
 
-        let mut score = 0.0;
-        let mut weight_sum = 0.0;
-        let mut base_embedding_score: Option<f32> = None;
-        let mut example_similarity_score: Option<f32> = None;
-
         // 1. Base score: embedding similarity to description + examples
         // Industry standard: embeddings are the primary signal
-        if let Some(tag_emb) = self.tag_embeddings.get(tag_name) {
-            let base_score = embeddings.cosine_similarity(log_embedding, tag_emb);
-            base_embedding_score = Some(base_score);
-            // Weight embeddings less when keywords match (keywords will add boost)
-            let weight = if has_keyword_match { 0.35 } else { 0.5 };
-            score += base_score * weight;
-            weight_sum += weight;
+        let base_embedding_score = self
+            .index
+            .tag_embeddings
+            .get(tag_name)
+            .map(|tag_emb| cosine_similarity(log_embedding, tag_emb));
+
+        // Fast reject: without a keyword match, a base similarity under the tag's floor can't
+        // reach the threshold even if the examples matched perfectly, so skip the rest
+        if let (false, Some(base), Some(floor)) =
+            (has_keyword_match, base_embedding_score, self.index.base_floors.get(tag_name))
+        {
+            if base < *floor {
+                return ScoreBreakdown {
+                    final_score: 0.0,
+                    base_embedding: Some(base as f64),
+                    example_similarity: None,
+                    keyword_match: Vec::new(),
+                    negative_penalty: 0.0,
+                };
+            }
         }
 
         // 2. Example similarity: max similarity to individual examples
-        if let Some(example_embs) = self.example_embeddings.get(tag_name) {
-            if !example_embs.is_empty() {
-                let max_example_score = example_embs
+        let example_similarity_score = self
+            .index
+            .example_embeddings
+            .get(tag_name)
+            .filter(|example_embs| !example_embs.is_empty())
+            .map(|example_embs| {
+                example_embs
                     .iter()
-                    .map(|example_emb| embeddings.cosine_similarity(log_embedding, example_emb))
-                    .fold(0.0f32, f32::max);
-                example_similarity_score = Some(max_example_score);
-                let weight = if has_keyword_match { 0.25 } else { 0.35 };
-                score += max_example_score * weight;
-                weight_sum += weight;
-            }
-        }
-
-        // 3. Keyword boost: additive boost when keywords match
-        // Keywords are explicit signals and should have strong weight
-        // This ensures keyword matches are strong but still respect embedding quality
-        if has_keyword_match {
-            // Strong boost for keywords - they are explicit signals from the tag definition
-            // Higher than typical industry systems because keywords are curated and highly reliable
-            let keyword_boost = 0.4;
-            score += keyword_boost;
-            weight_sum += keyword_boost;
-        }
-
-        // Normalize the weighted combination
-        if weight_sum > 0.0 {
-            score = score / weight_sum;
-        }
-
-        // If keywords matched, ensure minimum score meets threshold (before negative penalty)
-        // Keywords are explicit signals, so they should guarantee threshold unless negated
-        if has_keyword_match {
-            score = score.max(tag_def.threshold);
-        }
-
-        // 5. Negative examples: penalty if too similar to negative examples
-        let mut negative_penalty = 0.0f32;
-        if let Some(neg_example_embs) = self.negative_example_embeddings.get(tag_name) {
-            if !neg_example_embs.is_empty() {
-                let max_neg_score = neg_example_embs
+                    .map(|example_emb| cosine_similarity(log_embedding, example_emb))
+                    .fold(0.0f32, f32::max)
+            });
+
+        // 5. Negative examples: max similarity to any negative example
+        let negative_similarity = self
+            .index
+            .negative_example_embeddings
+            .get(tag_name)
+            .filter(|neg_example_embs| !neg_example_embs.is_empty())
+            .map(|neg_example_embs| {
+                neg_example_embs
                     .iter()
-                    .map(|neg_emb| embeddings.cosine_similarity(log_embedding, neg_emb))
-                    .fold(0.0f32, f32::max);
-                // Apply penalty: subtract up to 0.25 based on negative similarity
-                // Higher negative similarity = stronger penalty
-                negative_penalty = max_neg_score * 0.25;
-                score = (score - negative_penalty).max(0.0);
-            }
-        }
-
-        // Clamp to [0, 1]
-        let final_score = score.min(1.0).max(0.0);
+                    .map(|neg_emb| cosine_similarity(log_embedding, neg_emb))
+                    .fold(0.0f32, f32::max)
+            });
+        let negative_penalty = negative_similarity.map_or(0.0, |s| s * NEGATIVE_PENALTY_WEIGHT);
+
+        let final_score = composite_score(
+            base_embedding_score,
+            example_similarity_score,
+            has_keyword_match,
+            negative_similarity,
+            tag_def.threshold,
+        );
 
         ScoreBreakdown {
             final_score: final_score as f64,
@@ -404,19 +881,20 @@ impl TagMatcher {
         &self,
         value: &serde_json::Value,
     ) -> anyhow::Result<Vec<(String, ScoreBreakdown)>> {
-        let text = ocd_files_select_default(value);
-        let mut embeddings = self.embeddings.lock().unwrap();
-        let log_embedding = embeddings.embed(&text)?;
+        self.match_json_value_verbose(value).map(|(_, results)| results)
+    }
+
+    /// Like `match_json_value`, but also returns the text that was extracted and embedded
+    pub fn match_json_value_verbose(
+        &self,
+        value: &serde_json::Value,
+    ) -> anyhow::Result<(String, Vec<(String, ScoreBreakdown)>)> {
+        let text = self.extract_text(value);
+        let log_embedding = self.embeddings.lock().unwrap().embed(&text)?;
 
         let mut results = Vec::new();
-        for (name, tag_def) in &self.tags {
-            let score_breakdown = self.calculate_composite_score(
-                &log_embedding,
-                &text,
-                name,
-                tag_def,
-                &mut *embeddings,
-            );
+        for (name, tag_def) in &self.index.tags {
+            let score_breakdown = self.calculate_composite_score(&log_embedding, &text, name, tag_def);
             if score_breakdown.final_score >= tag_def.threshold as f64 {
                 results.push((name.clone(), score_breakdown));
             }
@@ -428,22 +906,62 @@ impl TagMatcher {
                 .partial_cmp(&a.1.final_score)
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
-        Ok(results)
+        Ok((text, results))
+    }
+
+    /// Text this matcher embeds for a log entry, per its `text_extraction` config
+    pub fn extract_text(&self, value: &serde_json::Value) -> String {
+        ocd_files_select(value, &self.text_extraction)
     }
 
     /// Access tag definitions (name -> definition)
     pub fn tag_definitions(&self) -> &HashMap<String, TagDefinition> {
-        &self.tags
+        &self.index.tags
+    }
+}
+
+/// Score given to a keyword-mode match with a single hit out of very many include keywords
+const KEYWORD_BASE_SCORE: f64 = 0.5;
+
+/// Score a tag from its include-keyword hits: `0.5 + 0.5 * sqrt(matched / total)`.
+/// Any hit clears the default 0.5 threshold; higher thresholds require broader keyword coverage.
+pub fn keyword_score(matched: usize, total: usize) -> f64 {
+    if matched == 0 || total == 0 {
+        return 0.0;
     }
+    let fraction = (matched.min(total) as f64) / (total as f64);
+    KEYWORD_BASE_SCORE + (1.0 - KEYWORD_BASE_SCORE) * fraction.sqrt()
 }
 
 /// Keyword-based fallback matcher when embedding mode is unavailable
-/// Matches tags based on include_keywords and exclude_keywords from tag definitions
+/// Matches tags based on include_keywords and exclude_keywords from tag definitions.
+/// Any exclude keyword vetoes the tag; otherwise the score comes from `keyword_score`
+/// and must meet the tag's threshold, as in embedding mode.
 pub fn match_tags_keywords(
     tag_defs: &[TagDefinition],
     json_entry: &serde_json::Value,
 ) -> Vec<(String, ScoreBreakdown)> {
-    let text = ocd_files_select_default(json_entry);
+    match_tags_keywords_with_extraction(tag_defs, json_entry, &TextExtractionConfig::default())
+}
+
+/// `match_tags_keywords` over text extracted according to `extraction`
+pub fn match_tags_keywords_with_extraction(
+    tag_defs: &[TagDefinition],
+    json_entry: &serde_json::Value,
+    extraction: &TextExtractionConfig,
+) -> Vec<(String, ScoreBreakdown)> {
+    match_tags_keywords_with_threshold(tag_defs, json_entry, extraction, 0.0)
+}
+
+/// `match_tags_keywords_with_extraction` with a caller-supplied score floor on the 0.0–1.0
+/// scale. A match must clear both its tag's `threshold` and `min_score`.
+pub fn match_tags_keywords_with_threshold(
+    tag_defs: &[TagDefinition],
+    json_entry: &serde_json::Value,
+    extraction: &TextExtractionConfig,
+    min_score: f64,
+) -> Vec<(String, ScoreBreakdown)> {
+    let text = ocd_files_select(json_entry, extraction);
     let text_lower = text.to_lowercase();
 
     let mut results = Vec::new();
@@ -457,31 +975,26 @@ pub fn match_tags_keywords(
             }
         }
 
-        // Check include_keywords - if any match, create a match
-        let include_matches = if tag_def.include_keywords.is_empty() {
-            Vec::new()
-        } else {
-            find_matching_keywords(&text_lower, &tag_def.include_keywords)
-        };
-
-        if !include_matches.is_empty() {
-            // If keywords match, assign a score based on threshold
-            // Use threshold as the base score, or 0.6 if threshold is lower
-            let score = tag_def.threshold.max(0.6) as f64;
-
-            // Only include if score meets threshold
-            if score >= tag_def.threshold as f64 {
-                results.push((
-                    tag_def.name.clone(),
-                    ScoreBreakdown {
-                        final_score: score,
-                        base_embedding: None,
-                        example_similarity: None,
-                        keyword_match: include_matches,
-                        negative_penalty: 0.0,
-                    },
-                ));
-            }
+        // Check include_keywords - without a hit there is nothing to score
+        if tag_def.include_keywords.is_empty() {
+            continue;
+        }
+        let include_matches = find_matching_keywords(&text_lower, &tag_def.include_keywords);
+        let score = keyword_score(include_matches.len(), tag_def.include_keywords.len());
+
+        // Only include if score meets threshold
+        let threshold = (tag_def.threshold as f64).max(min_score);
+        if !include_matches.is_empty() && score >= threshold {
+            results.push((
+                tag_def.name.clone(),
+                ScoreBreakdown {
+                    final_score: score,
+                    base_embedding: None,
+                    example_similarity: None,
+                    keyword_match: include_matches,
+                    negative_penalty: 0.0,
+                },
+            ));
         }
     }
 
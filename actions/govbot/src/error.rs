@@ -27,6 +27,15 @@ pub enum Error {
     #[error("Invalid timestamp format: {0}")]
     InvalidTimestamp(String),
 
+    #[error("Download failed: {0}")]
+    Download(String),
+
+    #[error("Schema validation failed: {0}")]
+    Validation(String),
+
     #[error("Git error: {0}")]
     Git(#[from] git2::Error),
+
+    #[error("Uncommitted changes in {0} (use --allow-dirty to overwrite)")]
+    DirtyWorkTree(String),
 }
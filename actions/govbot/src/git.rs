@@ -1,7 +1,9 @@
 use crate::error::{Error, Result};
 use git2::{build::RepoBuilder, FetchOptions, RemoteCallbacks, Repository};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
 // Repository URL template - fully configurable for any git hosting service
 //
@@ -26,10 +28,60 @@ fn get_repo_url_template() -> String {
         .unwrap_or_else(|_| DEFAULT_REPO_URL_TEMPLATE.to_string())
 }
 
+/// Per-locale repo names or clone URLs registered from a repo manifest
+static REPO_OVERRIDES: RwLock<Option<HashMap<String, String>>> = RwLock::new(None);
+
+/// Register per-locale repo overrides (a repo name under the template's org, or a full clone URL).
+/// Replaces any previously registered set.
+pub fn set_repo_overrides(overrides: HashMap<String, String>) {
+    *REPO_OVERRIDES.write().unwrap_or_else(|e| e.into_inner()) = Some(overrides);
+}
+
+fn repo_override(locale: &str) -> Option<String> {
+    REPO_OVERRIDES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()?
+        .get(&locale.to_lowercase())
+        .cloned()
+}
+
+/// Whether tracked files in the working tree or index differ from HEAD.
+/// Untracked files are ignored since checkout leaves them alone.
+pub fn has_uncommitted_changes(repo: &Repository) -> Result<bool> {
+    let mut options = git2::StatusOptions::new();
+    options
+        .include_untracked(false)
+        .include_ignored(false)
+        .exclude_submodules(true);
+    let statuses = repo.statuses(Some(&mut options))?;
+    Ok(statuses.iter().any(|entry| entry.status() != git2::Status::CURRENT))
+}
+
+/// Whether a pull must skip `repo` to avoid clobbering local edits
+fn must_skip_dirty(repo: &Repository, allow_dirty: bool) -> Result<bool> {
+    Ok(!allow_dirty && has_uncommitted_changes(repo)?)
+}
+
+fn is_clone_url(repo: &str) -> bool {
+    repo.contains("://") || repo.ends_with(".git")
+}
+
 /// Build the clone URL for a repository
 pub fn build_clone_url(locale: &str) -> String {
     let template = get_repo_url_template();
-    template.replace("{locale}", locale)
+    match repo_override(locale) {
+        Some(repo) if is_clone_url(&repo) => repo,
+        // Swap the template's repo segment for the manifest's repo name
+        Some(repo) => {
+            let pattern = extract_repo_name_pattern(&template);
+            match template.rfind(&pattern) {
+                Some(start) => format!("{}{}{}", &template[..start], repo, &template[start + pattern.len()..]),
+                None => template.replace("{locale}", &repo),
+            }
+        }
+        None => template.replace("{locale}", locale),
+    }
 }
 
 /// Extract repository name from URL template
@@ -75,9 +127,15 @@ fn extract_repo_org(template: &str) -> String {
 
 /// Build the repository name (used for local directory names)
 pub fn build_repo_name(locale: &str) -> String {
-    let template = get_repo_url_template();
-    let pattern = extract_repo_name_pattern(&template);
-    pattern.replace("{locale}", locale)
+    match repo_override(locale) {
+        Some(repo) if is_clone_url(&repo) => extract_repo_name_pattern(&repo),
+        Some(repo) => repo,
+        None => {
+            let template = get_repo_url_template();
+            let pattern = extract_repo_name_pattern(&template);
+            pattern.replace("{locale}", locale)
+        }
+    }
 }
 
 /// Build the repository path (org/repo-name format, used for display)
@@ -117,11 +175,7 @@ fn build_callbacks(token: Option<&str>, show_progress: bool) -> RemoteCallbacks<
             if stats.total_objects() > 0 {
                 let received = stats.received_objects();
                 let total = stats.total_objects();
-                let percent = if total > 0 {
-                    (received * 100) / total
-                } else {
-                    0
-                };
+                let percent = (received * 100).checked_div(total).unwrap_or(0);
 
                 if received == total {
                     eprint!(
@@ -145,12 +199,24 @@ fn build_callbacks(token: Option<&str>, show_progress: bool) -> RemoteCallbacks<
 }
 
 /// Clone or pull a repository for a given locale with quiet option
-/// Returns action: "clone", "pulled", or "no_updates"
+/// Returns action: "clone", "pulled", "no_updates", or "skipped_dirty" (uncommitted changes)
 pub fn clone_or_pull_repo_quiet(
     locale: &str,
     repos_dir: &Path,
     token: Option<&str>,
     quiet: bool,
+) -> Result<&'static str> {
+    clone_or_pull_repo_with_allow_dirty(locale, repos_dir, token, quiet, false)
+}
+
+/// `clone_or_pull_repo_quiet`, pulling over uncommitted changes when `allow_dirty` is set
+/// (`clone --allow-dirty`) instead of skipping the repo
+pub fn clone_or_pull_repo_with_allow_dirty(
+    locale: &str,
+    repos_dir: &Path,
+    token: Option<&str>,
+    quiet: bool,
+    allow_dirty: bool,
 ) -> Result<&'static str> {
     let clone_url = build_clone_url(locale);
     let repo_name = build_repo_name(locale);
@@ -164,6 +230,14 @@ pub fn clone_or_pull_repo_quiet(
         let repo = Repository::open(&target_dir)
             .map_err(|e| Error::Config(format!("Failed to open repository: {}", e)))?;
 
+        // Pulling checks out over the working tree, so leave local edits alone
+        if must_skip_dirty(&repo, allow_dirty)? {
+            if !quiet {
+                eprintln!("Skipping {}: uncommitted changes (use --allow-dirty to overwrite)", repo_path);
+            }
+            return Ok("skipped_dirty");
+        }
+
         // Pull the latest changes (credentials will be used if token is provided)
         match pull_repo_internal(&repo, token, quiet) {
             Ok(had_updates) => {
@@ -239,44 +313,33 @@ pub fn clone_or_pull_repo_quiet(
     let repo = Repository::open(&target_dir)
         .map_err(|e| Error::Config(format!("Failed to open cloned repository: {}", e)))?;
 
-    // Try to find the default branch (main or master)
-    // Check local branches first
-    let default_branch = if repo.find_branch("main", git2::BranchType::Local).is_ok() {
-        "main"
-    } else if repo.find_branch("master", git2::BranchType::Local).is_ok() {
-        "master"
-    } else {
-        // Check remote branches
-        if repo
-            .find_branch("origin/main", git2::BranchType::Remote)
-            .is_ok()
-        {
-            // Create local main branch from remote
-            let remote_branch = repo.find_branch("origin/main", git2::BranchType::Remote)?;
-            let commit = remote_branch.get().target().ok_or_else(|| {
-                Error::Config("Failed to get commit from origin/main".to_string())
-            })?;
-            let commit_obj = repo.find_commit(commit)?;
-            repo.branch("main", &commit_obj, false)?;
-            "main"
-        } else if repo
-            .find_branch("origin/master", git2::BranchType::Remote)
-            .is_ok()
-        {
-            // Create local master branch from remote
-            let remote_branch = repo.find_branch("origin/master", git2::BranchType::Remote)?;
-            let commit = remote_branch.get().target().ok_or_else(|| {
-                Error::Config("Failed to get commit from origin/master".to_string())
-            })?;
-            let commit_obj = repo.find_commit(commit)?;
-            repo.branch("master", &commit_obj, false)?;
-            "master"
-        } else {
-            return Err(Error::Config(
-                "Neither 'main' nor 'master' branch found in repository".to_string(),
-            ));
-        }
-    };
+    // Use the branch origin/HEAD points at, falling back to main or master
+    let candidates = default_branch_candidates(origin_head_branch(&repo));
+    let default_branch = candidates
+        .iter()
+        .find(|branch| {
+            repo.find_branch(branch, git2::BranchType::Local).is_ok()
+                || repo
+                    .find_branch(&format!("origin/{}", branch), git2::BranchType::Remote)
+                    .is_ok()
+        })
+        .ok_or_else(|| {
+            Error::Config(format!(
+                "No default branch found in repository (tried {})",
+                quote_branches(&candidates)
+            ))
+        })?;
+
+    // Create the local branch from the remote one if the clone didn't
+    if repo.find_branch(default_branch, git2::BranchType::Local).is_err() {
+        let remote_name = format!("origin/{}", default_branch);
+        let remote_branch = repo.find_branch(&remote_name, git2::BranchType::Remote)?;
+        let commit = remote_branch.get().target().ok_or_else(|| {
+            Error::Config(format!("Failed to get commit from {}", remote_name))
+        })?;
+        let commit_obj = repo.find_commit(commit)?;
+        repo.branch(default_branch, &commit_obj, false)?;
+    }
 
     // Set HEAD to the default branch if it's not already set correctly
     if let Ok(head) = repo.head() {
@@ -355,7 +418,7 @@ fn pull_repo_internal(repo: &Repository, token: Option<&str>, quiet: bool) -> Re
         .and_then(|name| name.strip_prefix("refs/heads/"))
         .ok_or_else(|| Error::Config("Failed to determine local branch name".to_string()))?;
 
-    // Fetch from remote - try both main and master
+    // Fetch from remote - the default branch, or main and master
     let mut remote = repo
         .find_remote("origin")
         .map_err(|e| Error::Config(format!("Failed to find remote 'origin': {}", e)))?;
@@ -376,90 +439,42 @@ fn pull_repo_internal(repo: &Repository, token: Option<&str>, quiet: bool) -> Re
         let _ = remote.fetch(&all_refs, Some(&mut fetch_options), None);
     }
 
-    // Fetch both main and master branches (only fail if both fail)
-    let refspecs = vec![
-        "refs/heads/main:refs/remotes/origin/main",
-        "refs/heads/master:refs/remotes/origin/master",
-    ];
+    // Prefer the default branch the remote advertises; without one, keep a local master on
+    // master and otherwise try main before master
+    let preferred = query_remote_default_branch(&mut remote, token)
+        .or_else(|| origin_head_branch(repo))
+        .or_else(|| (local_branch_name == "master").then(|| "master".to_string()));
+    let candidates = default_branch_candidates(preferred);
 
-    // Try to fetch both branches - ignore errors for individual branches
-    let fetch_result = remote.fetch(&refspecs, Some(&mut fetch_options), None);
+    // Fetch every candidate branch (only fail if none can be found)
+    let refspecs: Vec<String> = candidates
+        .iter()
+        .map(|branch| format!("refs/heads/{0}:refs/remotes/origin/{0}", branch))
+        .collect();
 
-    // If fetch completely fails, return error
-    if fetch_result.is_err() {
-        // Check if at least one branch exists remotely by trying to find them
-        let has_main = repo
-            .find_branch("origin/main", git2::BranchType::Remote)
-            .is_ok();
-        let has_master = repo
-            .find_branch("origin/master", git2::BranchType::Remote)
-            .is_ok();
-
-        if !has_main && !has_master {
-            return Err(Error::Config(
-                "Failed to fetch from remote and neither 'main' nor 'master' branch found"
-                    .to_string(),
-            ));
-        }
-        // If at least one exists, continue (fetch might have partially succeeded)
-    }
+    // Try to fetch all branches - ignore errors for individual branches
+    let fetch_result = remote.fetch(&refspecs, Some(&mut fetch_options), None);
 
-    // Determine which remote branch to use based on local branch
-    // If local is main, use origin/main; if local is master, use origin/master
-    // Otherwise, prefer main over master
-    let (remote_branch_name, target_local_branch) = if local_branch_name == "main" {
-        if repo
-            .find_branch("origin/main", git2::BranchType::Remote)
-            .is_ok()
-        {
-            ("origin/main", "main")
-        } else if repo
-            .find_branch("origin/master", git2::BranchType::Remote)
-            .is_ok()
-        {
-            ("origin/master", "master")
-        } else {
-            return Err(Error::Config(
-                "Neither 'main' nor 'master' branch found in remote repository".to_string(),
-            ));
-        }
-    } else if local_branch_name == "master" {
-        if repo
-            .find_branch("origin/master", git2::BranchType::Remote)
-            .is_ok()
-        {
-            ("origin/master", "master")
-        } else if repo
-            .find_branch("origin/main", git2::BranchType::Remote)
-            .is_ok()
-        {
-            ("origin/main", "main")
-        } else {
-            return Err(Error::Config(
-                "Neither 'main' nor 'master' branch found in remote repository".to_string(),
-            ));
-        }
-    } else {
-        // Local branch is neither main nor master - prefer main, fallback to master
-        if repo
-            .find_branch("origin/main", git2::BranchType::Remote)
-            .is_ok()
-        {
-            ("origin/main", "main")
-        } else if repo
-            .find_branch("origin/master", git2::BranchType::Remote)
-            .is_ok()
-        {
-            ("origin/master", "master")
-        } else {
-            return Err(Error::Config(
-                "Neither 'main' nor 'master' branch found in remote repository".to_string(),
-            ));
-        }
-    };
+    // Use the first candidate that exists on the remote
+    let target_local_branch = candidates
+        .iter()
+        .find(|branch| {
+            repo.find_branch(&format!("origin/{}", branch), git2::BranchType::Remote)
+                .is_ok()
+        })
+        .ok_or_else(|| {
+            let action = if fetch_result.is_err() { "Failed to fetch from remote and no" } else { "No" };
+            Error::Config(format!(
+                "{} default branch found in remote repository (tried {})",
+                action,
+                quote_branches(&candidates)
+            ))
+        })?
+        .as_str();
+    let remote_branch_name = format!("origin/{}", target_local_branch);
 
     let remote_branch = repo
-        .find_branch(remote_branch_name, git2::BranchType::Remote)
+        .find_branch(&remote_branch_name, git2::BranchType::Remote)
         .map_err(|e| {
             Error::Config(format!(
                 "Failed to find remote branch {}: {}",
@@ -505,7 +520,7 @@ fn pull_repo_internal(repo: &Repository, token: Option<&str>, quiet: bool) -> Re
 
     if analysis.0.is_up_to_date() {
         // Already up to date
-        return Ok(false);
+        Ok(false)
     } else if analysis.0.is_fast_forward() {
         // Fast-forward merge
         let mut reference = head
@@ -520,27 +535,79 @@ fn pull_repo_internal(repo: &Repository, token: Option<&str>, quiet: bool) -> Re
             .map_err(|e| Error::Config(format!("Failed to checkout: {}", e)))?;
 
         // Updates were made
-        return Ok(true);
+        Ok(true)
     } else {
         // Need to merge
-        return Err(Error::Config(
+        Err(Error::Config(
             "Repository has diverged and cannot be fast-forwarded. Please resolve manually."
                 .to_string(),
-        ));
+        ))
     }
 }
 
+/// Branch `refs/remotes/origin/HEAD` points at (e.g. `develop`), if the symbolic ref exists
+fn origin_head_branch(repo: &Repository) -> Option<String> {
+    let origin_head = repo.find_reference("refs/remotes/origin/HEAD").ok()?;
+    origin_head
+        .symbolic_target()?
+        .strip_prefix("refs/remotes/origin/")
+        .map(str::to_string)
+}
+
+/// Default branch the remote advertises through its symbolic `HEAD`, if the server reports one
+fn query_remote_default_branch(remote: &mut git2::Remote, token: Option<&str>) -> Option<String> {
+    let connection = remote
+        .connect_auth(git2::Direction::Fetch, Some(build_callbacks(token, false)), None)
+        .ok()?;
+    let default_branch = connection.default_branch().ok()?;
+    default_branch
+        .as_str()?
+        .strip_prefix("refs/heads/")
+        .map(str::to_string)
+}
+
+/// Branches to try as the default, in order: `preferred` when known, then main and master
+fn default_branch_candidates(preferred: Option<String>) -> Vec<String> {
+    let mut candidates: Vec<String> = preferred.into_iter().collect();
+    for fallback in ["main", "master"] {
+        if !candidates.iter().any(|branch| branch == fallback) {
+            candidates.push(fallback.to_string());
+        }
+    }
+    candidates
+}
+
+fn quote_branches(branches: &[String]) -> String {
+    branches
+        .iter()
+        .map(|branch| format!("'{}'", branch))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Pull a repository for a given locale
 pub fn pull_repo(locale: &str, repos_dir: &Path, token: Option<&str>) -> Result<()> {
     pull_repo_quiet(locale, repos_dir, token, false)
 }
 
-/// Pull a repository for a given locale with quiet option
+/// Pull a repository for a given locale with quiet option.
+/// A repo with uncommitted changes is left alone and reported as `Error::DirtyWorkTree`.
 pub fn pull_repo_quiet(
     locale: &str,
     repos_dir: &Path,
     token: Option<&str>,
     quiet: bool,
+) -> Result<()> {
+    pull_repo_with_allow_dirty(locale, repos_dir, token, quiet, false)
+}
+
+/// `pull_repo_quiet`, pulling over uncommitted changes when `allow_dirty` is set
+pub fn pull_repo_with_allow_dirty(
+    locale: &str,
+    repos_dir: &Path,
+    token: Option<&str>,
+    quiet: bool,
+    allow_dirty: bool,
 ) -> Result<()> {
     let repo_name = build_repo_name(locale);
     let repo_path = build_repo_path(locale);
@@ -556,6 +623,11 @@ pub fn pull_repo_quiet(
         }
     };
 
+    // Pulling checks out over the working tree, so leave local edits alone
+    if must_skip_dirty(&repo, allow_dirty)? {
+        return Err(Error::DirtyWorkTree(repo_path));
+    }
+
     // Pull the latest changes (credentials will be used if token is provided)
     if !quiet {
         eprintln!("Pulling repository: {}", repo_path);
@@ -909,3 +981,110 @@ pub fn delete_repo(locale: &str, repos_dir: &Path) -> Result<()> {
         Ok(())
     }
 }
+
+/// Default ref to diff against: the position of HEAD before the last clone/pull
+pub const DEFAULT_SINCE_REF: &str = "HEAD@{1}";
+
+/// List log JSON files added or modified between `since_ref` and the current HEAD.
+///
+/// Returned paths are relative to the repository root and limited to files matching
+/// the pipeline layout (`.../logs/*.json` or `.../logs/*.json.gz`), so they can be fed back
+/// into log processing.
+pub fn changed_log_files(repo_path: &Path, since_ref: &str) -> Result<Vec<PathBuf>> {
+    changed_files(repo_path, since_ref, |path| {
+        (path.ends_with(".json") || path.ends_with(".gz")) && path.contains("/logs/")
+    })
+}
+
+/// List bill `metadata.json` files (`.../bills/<id>/metadata.json`) added or modified
+/// between `since_ref` and the current HEAD, relative to the repository root.
+pub fn changed_metadata_files(repo_path: &Path, since_ref: &str) -> Result<Vec<PathBuf>> {
+    changed_files(repo_path, since_ref, is_metadata_file)
+}
+
+/// List bill `metadata.json` files deleted, or renamed away (by their old name), between
+/// `since_ref` and the current HEAD, relative to the repository root.
+pub fn deleted_metadata_files(repo_path: &Path, since_ref: &str) -> Result<Vec<PathBuf>> {
+    let repo = Repository::open(repo_path)?;
+    let diff = diff_since(&repo, since_ref)?;
+
+    let mut paths = Vec::new();
+    for delta in diff.deltas() {
+        if !matches!(delta.status(), git2::Delta::Deleted | git2::Delta::Renamed) {
+            continue;
+        }
+        if let Some(path) = delta.old_file().path() {
+            if is_metadata_file(&path.to_string_lossy()) {
+                paths.push(path.to_path_buf());
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+fn is_metadata_file(path: &str) -> bool {
+    path.ends_with("/metadata.json") && path.contains("/bills/")
+}
+
+/// Paths added, modified, or renamed (by their new name) between `since_ref` and HEAD that
+/// satisfy `include`
+fn changed_files(repo_path: &Path, since_ref: &str, include: impl Fn(&str) -> bool) -> Result<Vec<PathBuf>> {
+    let repo = Repository::open(repo_path)?;
+    let diff = diff_since(&repo, since_ref)?;
+
+    let mut paths = Vec::new();
+    for delta in diff.deltas() {
+        if !matches!(
+            delta.status(),
+            git2::Delta::Added | git2::Delta::Modified | git2::Delta::Renamed | git2::Delta::Copied
+        ) {
+            continue;
+        }
+        if let Some(path) = delta.new_file().path() {
+            if include(&path.to_string_lossy()) {
+                paths.push(path.to_path_buf());
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Tree diff from `since_ref` to HEAD, with renames detected
+fn diff_since<'r>(repo: &'r Repository, since_ref: &str) -> Result<git2::Diff<'r>> {
+    let old_tree = repo
+        .revparse_single(since_ref)
+        .and_then(|obj| obj.peel_to_tree())
+        .map_err(|e| Error::Config(format!("Failed to resolve '{}': {}", since_ref, e)))?;
+    let new_tree = repo
+        .head()
+        .and_then(|head| head.peel_to_tree())
+        .map_err(|e| Error::Config(format!("Failed to resolve HEAD: {}", e)))?;
+
+    let mut diff = repo.diff_tree_to_tree(Some(&old_tree), Some(&new_tree), None)?;
+    // Report a moved file once, as a rename, rather than as a delete plus an add
+    diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))?;
+    Ok(diff)
+}
+
+/// Commit time of HEAD for a cloned repository
+pub fn head_commit_time(repo_path: &Path) -> Result<chrono::DateTime<chrono::Utc>> {
+    let repo = Repository::open(repo_path)?;
+    let commit = repo.head()?.peel_to_commit()?;
+    chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+        .ok_or_else(|| Error::InvalidTimestamp(format!("commit time {}", commit.time().seconds())))
+}
+
+/// Human-readable age of a commit, e.g. "last commit 3 days ago"
+pub fn format_commit_age(
+    commit_time: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let days = (now - commit_time).num_days().max(0);
+    match days {
+        0 => "last commit today".to_string(),
+        1 => "last commit 1 day ago".to_string(),
+        n => format!("last commit {} days ago", n),
+    }
+}
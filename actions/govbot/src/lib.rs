@@ -9,20 +9,24 @@ pub mod error;
 pub mod filter;
 pub mod git;
 pub mod locale_generated;
+pub mod manifest;
 pub mod processor;
 pub mod publish;
 pub mod rss;
 pub mod selectors;
+pub mod sqlite;
 pub mod types;
 
-pub use config::{Config, ConfigBuilder, JoinOption, SortOrder};
+pub use config::{Config, ConfigBuilder, GovbotEnv, JoinOption, SortOrder, DEFAULT_MAX_DEPTH};
 pub use embeddings::{
-    hash_text, BillTagResult, ScoreBreakdown, TagDefinition, TagFile, TagFileMetadata, TagMatcher,
+    hash_text, hash_text_fast, BillTagResult, Embedder, ScoreBreakdown, TagDefinition, TagFile, TagFileMetadata, TagMatcher,
+    TAG_FILE_VERSION,
 };
 pub use error::{Error, Result};
 pub use filter::{FilterAlias, FilterManager, FilterResult, LogFilter};
 pub use locale::WorkingLocale;
 pub use locale_generated as locale;
+pub use manifest::RepoManifest;
 pub use processor::PipelineProcessor;
 pub use types::{LogContent, LogEntry, Metadata, VoteEventResult};
 
@@ -233,6 +233,64 @@ impl WorkingLocale {
             WorkingLocale::WY => "wy",
         }
     }
+    
+    /// Get the locale's display name (e.g. "Wyoming")
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WorkingLocale::All => "All",
+            WorkingLocale::AK => "Alaska",
+            WorkingLocale::AL => "Alabama",
+            WorkingLocale::AR => "Arkansas",
+            WorkingLocale::CA => "California",
+            WorkingLocale::CO => "Colorado",
+            WorkingLocale::DE => "Delaware",
+            WorkingLocale::FL => "Florida",
+            WorkingLocale::GA => "Georgia",
+            WorkingLocale::GU => "Guam",
+            WorkingLocale::HI => "Hawaii",
+            WorkingLocale::IA => "Iowa",
+            WorkingLocale::ID => "Idaho",
+            WorkingLocale::IL => "Illinois",
+            WorkingLocale::IN => "Indiana",
+            WorkingLocale::KS => "Kansas",
+            WorkingLocale::KY => "Kentucky",
+            WorkingLocale::LA => "Louisiana",
+            WorkingLocale::MA => "Massachusetts",
+            WorkingLocale::MD => "Maryland",
+            WorkingLocale::ME => "Maine",
+            WorkingLocale::MI => "Michigan",
+            WorkingLocale::MN => "Minnesota",
+            WorkingLocale::MO => "Missouri",
+            WorkingLocale::MP => "Northern Mariana Islands",
+            WorkingLocale::MS => "Mississippi",
+            WorkingLocale::MT => "Montana",
+            WorkingLocale::NC => "North Carolina",
+            WorkingLocale::ND => "North Dakota",
+            WorkingLocale::NE => "Nebraska",
+            WorkingLocale::NH => "New Hampshire",
+            WorkingLocale::NJ => "New Jersey",
+            WorkingLocale::NM => "New Mexico",
+            WorkingLocale::NV => "Nevada",
+            WorkingLocale::NY => "New York",
+            WorkingLocale::OH => "Ohio",
+            WorkingLocale::OK => "Oklahoma",
+            WorkingLocale::OR => "Oregon",
+            WorkingLocale::PA => "Pennsylvania",
+            WorkingLocale::PR => "Puerto Rico",
+            WorkingLocale::RI => "Rhode Island",
+            WorkingLocale::SC => "South Carolina",
+            WorkingLocale::SD => "South Dakota",
+            WorkingLocale::TN => "Tennessee",
+            WorkingLocale::Usa => "United States",
+            WorkingLocale::UT => "Utah",
+            WorkingLocale::VI => "U.S. Virgin Islands",
+            WorkingLocale::VT => "Vermont",
+            WorkingLocale::WA => "Washington",
+            WorkingLocale::WI => "Wisconsin",
+            WorkingLocale::WV => "West Virginia",
+            WorkingLocale::WY => "Wyoming",
+        }
+    }
 }
 
 impl From<&str> for WorkingLocale {
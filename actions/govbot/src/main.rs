@@ -1,18 +1,18 @@
 use clap::{Parser, Subcommand};
 use govbot::git;
-use govbot::{TagMatcher, hash_text, TagFile, TagFileMetadata, BillTagResult};
-use govbot::selectors::ocd_files_select_default;
-use govbot::publish::{load_config, get_repos_from_config, filter_by_tags, deduplicate_entries, sort_by_timestamp};
+use govbot::{GovbotEnv, TagMatcher, hash_text, hash_text_fast, TagFile, TagFileMetadata, BillTagResult};
+use govbot::selectors::{extract_json_field, ocd_files_select, parse_fields_file, project_fields};
+use govbot::types::extract_path_info;
+use govbot::publish::{load_config, get_repos_from_config_with_locales, get_logs_limit_from_config, get_feed_options_from_config, get_feed_language_from_config, filter_by_tags, filter_by_tags_with_min_score, exclude_tags_from, deduplicate_entries_with_ids, DedupMode, sort_by_timestamp, sort_by_timestamp_order, paginate_entries, entries_after, read_cursor, write_cursor, LAST_CURSOR};
 use govbot::rss;
 use futures::StreamExt;
 use futures::stream;
 use std::io::{self, Write, BufRead, BufReader};
-use std::path::PathBuf;
-use serde_json;
+use std::path::{Path, PathBuf};
 use jwalk::WalkDir;
 use std::fs;
 use std::process::Command as ProcessCommand;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Write a line to stdout, gracefully handling broken pipe errors
 /// This is essential for piping to tools like yq, jq, etc.
@@ -37,10 +37,70 @@ fn write_json_line(line: &str) -> io::Result<()> {
     Ok(())
 }
 
+/// Destination for `logs` output: stdout, a file, or gzip of either; all are buffered and
+/// flushed once by `finish`
+enum LineWriter {
+    Stdout(io::BufWriter<io::Stdout>),
+    File(io::BufWriter<fs::File>),
+    Gzip(flate2::write::GzEncoder<Box<dyn Write>>),
+}
+
+impl LineWriter {
+    fn new(output: Option<&str>, compress: bool) -> anyhow::Result<Self> {
+        let compress = compress || output.is_some_and(|o| o.ends_with(".gz"));
+        let sink: Box<dyn Write> = match output {
+            Some(path) if path != "-" => {
+                let file = fs::File::create(path)
+                    .map_err(|e| anyhow::anyhow!("Failed to create output file {}: {}", path, e))?;
+                if !compress {
+                    return Ok(LineWriter::File(io::BufWriter::new(file)));
+                }
+                Box::new(io::BufWriter::new(file))
+            }
+            _ => {
+                if !compress {
+                    return Ok(LineWriter::Stdout(io::BufWriter::with_capacity(64 * 1024, io::stdout())));
+                }
+                Box::new(io::stdout())
+            }
+        };
+        Ok(LineWriter::Gzip(flate2::write::GzEncoder::new(sink, flate2::Compression::default())))
+    }
+
+    /// Write one record terminated by exactly one newline; broken pipes on stdout are
+    /// swallowed like `write_json_line`
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let mut record = String::with_capacity(line.len() + 1);
+        record.push_str(line.trim_end_matches(['\r', '\n']));
+        record.push('\n');
+        match self {
+            LineWriter::Stdout(w) => match w.write_all(record.as_bytes()) {
+                Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+                other => other,
+            },
+            LineWriter::File(w) => w.write_all(record.as_bytes()),
+            LineWriter::Gzip(w) => w.write_all(record.as_bytes()),
+        }
+    }
+
+    /// Flush buffered output and write the gzip trailer
+    fn finish(self) -> io::Result<()> {
+        let result = match self {
+            LineWriter::Stdout(mut w) => w.flush(),
+            LineWriter::File(mut w) => w.flush(),
+            LineWriter::Gzip(w) => w.finish().and_then(|mut inner| inner.flush()),
+        };
+        match result {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            other => other,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct CloneResult {
     locale: String,
-    result: String, // "cloned", "pulled", "no_updates", "failed"
+    result: String, // "clone", "pulled", "no_updates", "recloned", "skipped_dirty", "failed"
     position: String, // "1/37"
     size: Option<String>,
     local_size: Option<String>,
@@ -56,9 +116,59 @@ struct CloneResult {
 struct Args {
     #[command(subcommand)]
     command: Option<Command>,
+
+    /// Print ASCII status words instead of emoji (also enabled by NO_COLOR or GOVBOT_PLAIN)
+    #[arg(long, global = true)]
+    no_emoji: bool,
+
+    /// Path to govbot.yml (default: ./govbot.yml)
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+}
+
+/// Set once at startup from `--config`
+static CONFIG_PATH: std::sync::OnceLock<Option<PathBuf>> = std::sync::OnceLock::new();
+
+/// Location of govbot.yml: `--config` if given, else the current directory
+fn config_path() -> anyhow::Result<PathBuf> {
+    let path = CONFIG_PATH
+        .get()
+        .cloned()
+        .flatten()
+        .unwrap_or_else(|| PathBuf::from("govbot.yml"));
+    Ok(std::env::current_dir()?.join(path))
+}
+
+/// `config_path`, or an error naming where govbot.yml was expected
+fn require_config_path() -> anyhow::Result<PathBuf> {
+    let path = config_path()?;
+    if path.exists() {
+        Ok(path)
+    } else if CONFIG_PATH.get().is_some_and(|p| p.is_some()) {
+        Err(anyhow::anyhow!("Config file not found: {}", path.display()))
+    } else {
+        Err(anyhow::anyhow!("govbot.yml not found in current directory"))
+    }
+}
+
+/// Set once at startup; when true, status markers are printed as ASCII words
+static PLAIN_OUTPUT: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+
+/// Whether emoji should be replaced by ASCII status words
+fn plain_output() -> bool {
+    PLAIN_OUTPUT.get().copied().unwrap_or(false)
+}
+
+/// Pick the emoji or ASCII form of a status marker
+fn icon(emoji: &'static str, plain: &'static str) -> &'static str {
+    if plain_output() {
+        plain
+    } else {
+        emoji
+    }
 }
 
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Debug, Clone)]
 enum Command {
     /// Clone or pull data pipeline repositories (default: updates existing repos)
     /// Clones if repository doesn't exist, pulls if it does
@@ -87,6 +197,23 @@ enum Command {
         /// List available repos instead of cloning/pulling
         #[arg(long)]
         list: bool,
+
+        /// Show each repo's code and name, whether it is cloned, and how recent its last commit is,
+        /// instead of cloning/pulling
+        #[arg(long)]
+        status: bool,
+
+        /// Only clone repos that aren't present yet; existing repos are not pulled
+        #[arg(long = "only-missing")]
+        only_missing: bool,
+
+        /// Pull repos even if they have uncommitted changes, overwriting them (default: such repos are skipped)
+        #[arg(long = "allow-dirty")]
+        allow_dirty: bool,
+
+        /// Repo manifest listing extra locales/repos (default: repos.yml or repos.txt in the govbot dir)
+        #[arg(long)]
+        manifest: Option<String>,
     },
 
     /// Process and display pipeline log files
@@ -95,11 +222,12 @@ enum Command {
         #[arg(long, num_args = 0..)]
         repos: Vec<String>,
     
-        /// Per repo limit (default: 100) options: `none` | number
-        #[arg(long, default_value = "100")]
-        limit: String,
+        /// Per repo limit (default: `logs.limit` in ./govbot.yml, else 100) options: `none` | number
+        #[arg(long)]
+        limit: Option<String>,
 
         /// Join additional datasets (default: `bill,tags`) options: `bill`, `tags`, `bill,tags`, etc.
+        /// A `bill` field path picks one field, with `*` over arrays: `bill.sponsorships.*.name`
         #[arg(long, default_value = "bill,tags")]
         join: String,
 
@@ -118,6 +246,114 @@ enum Command {
         /// Govbot directory (default: $CWD/.govbot/repos, or GOVBOT_DIR env var)
         #[arg(long = "govbot-dir")]
         govbot_dir: Option<String>,        
+
+        /// Write output to a file instead of stdout (gzip-compressed if it ends in `.gz`)
+        #[arg(long)]
+        output: Option<String>,
+
+        /// Gzip-compress output (implied by an `--output` path ending in `.gz`)
+        #[arg(long)]
+        compress: bool,
+
+        /// Output format (default: `ndjson`, one compact JSON object per line) options: `ndjson`, `yaml`
+        /// (`---`-separated documents; multiline, so meant for `--output` or reading, not line-oriented pipes),
+        /// `table` (aligned id/title/tags/timestamp columns for terminal review; not JSON, so not for `jq`),
+        /// `geojson` (one FeatureCollection placing each entry at its jurisdiction; needs `--geometry`)
+        #[arg(long, default_value = "ndjson", value_parser = ["ndjson", "yaml", "table", "geojson"])]
+        format: String,
+
+        /// JSON object mapping jurisdictions (`state:` path components like `wy`, or `country:` ones
+        /// like `us`) to GeoJSON geometries, for `--format geojson`
+        #[arg(long)]
+        geometry: Option<PathBuf>,
+
+        /// Also emit a marker entry (`{"empty_session": {...}}`) for each session directory with no
+        /// log files, to audit coverage gaps (ndjson/yaml only)
+        #[arg(long = "include-empty-sessions", conflicts_with = "count_only")]
+        include_empty_sessions: bool,
+
+        /// Deepest directory level walked inside each repo (default: 12); log files normally sit 8
+        /// levels down, at `country:*/state:*/sessions/*/bills/*/logs/*.json`
+        #[arg(long = "max-depth", default_value_t = govbot::DEFAULT_MAX_DEPTH)]
+        max_depth: usize,
+
+        /// Keep only these comma-separated dotted paths in each ndjson/yaml entry, keyed by path,
+        /// e.g. `--fields id,bill.title,log.action.description`
+        #[arg(long)]
+        fields: Option<String>,
+
+        /// Read `--fields` paths from a file (a YAML list, or one path per line) so a standard
+        /// projection can be committed; `--fields` overrides it
+        #[arg(long = "fields-file")]
+        fields_file: Option<PathBuf>,
+
+        /// Write every file error (read, parse, missing metadata, ...) to this JSON file;
+        /// stderr only gets a summary grouped by kind
+        #[arg(long = "error-log")]
+        error_log: Option<PathBuf>,
+
+        /// Directory holding the `country:*/state:*/sessions/*/tags` trees for `--join tags`
+        /// (default: the directory containing govbot.yml), e.g. a checkout of a separate tags repo
+        #[arg(long = "tags-dir", alias = "only-tags-dir")]
+        tags_dir: Option<PathBuf>,
+
+        /// Indent JSON for human inspection (multi-line; breaks one-entry-per-line streaming)
+        #[arg(long)]
+        pretty: bool,
+
+        /// Threads used to walk repo directories (default: all cores; 1 walks serially)
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        threads: Option<usize>,
+
+        /// Repo manifest listing extra locales/repos (default: repos.yml or repos.txt in the govbot dir)
+        #[arg(long)]
+        manifest: Option<String>,
+
+        /// Only include these legislative sessions (comma-separated), e.g. `--session 104th`
+        #[arg(long)]
+        session: Option<String>,
+
+        /// Only include these `state:` path components (comma-separated), e.g. `--state wy`
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Only include these `country:` path components (comma-separated), e.g. `--country us`
+        #[arg(long)]
+        country: Option<String>,
+
+        /// Keep null and empty fields instead of pruning them (fixed schema for strict consumers)
+        #[arg(long)]
+        no_prune: bool,
+
+        /// Exit with an error when no entries were emitted
+        #[arg(long)]
+        fail_on_empty: bool,
+
+        /// Descend into symlinked directories inside repos (each file is still emitted once)
+        #[arg(long = "follow-symlinks")]
+        follow_symlinks: bool,
+
+        /// Skip log files larger than this many bytes instead of reading them (default: unlimited)
+        #[arg(long = "max-file-size")]
+        max_file_size: Option<u64>,
+
+        /// Without `--repos`, process the `repos:` list from govbot.yml (as `publish` does) instead of `all`
+        #[arg(long = "repos-from-config")]
+        repos_from_config: bool,
+
+        /// Print only the number of entries per repo and the grand total, without serializing them
+        #[arg(long = "count-only", conflicts_with = "pretty")]
+        count_only: bool,
+
+        /// Repos walked at once when writing to `--output` (default: 4, or GOVBOT_JOBS env var);
+        /// stdout is always written one repo at a time
+        #[arg(long)]
+        parallel: Option<usize>,
+
+        /// Read only the log files listed in this file, one path per line (`-` for stdin), instead
+        /// of walking each repo, e.g. `govbot diff | govbot logs --paths-from -`
+        #[arg(long = "paths-from")]
+        paths_from: Option<String>,
     },
 
     /// Delete data pipeline repositories
@@ -138,15 +374,43 @@ enum Command {
         /// Show verbose output
         #[arg(long)]
         verbose: bool,
+
+        /// Repo manifest listing extra locales/repos (default: repos.yml or repos.txt in the govbot dir)
+        #[arg(long)]
+        manifest: Option<String>,
+
+        /// Print a JSON array of per-locale results (`locale`, `status`, `freed_bytes`, `error`) to stdout
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List log files added or modified since the previous pull
+    /// Diffs each repo's HEAD against a previous ref and prints one log file path per line.
+    Diff {
+        /// Repos to diff (default: `all`) `--repos="il,ca"`
+        #[arg(long, num_args = 0..)]
+        repos: Vec<String>,
+
+        /// Git ref to compare HEAD against (default: HEAD@{1}, the commit before the last pull)
+        #[arg(long = "since-ref")]
+        since_ref: Option<String>,
+
+        /// Govbot directory (default: $CWD/.govbot/repos, or GOVBOT_DIR env var)
+        #[arg(long = "govbot-dir")]
+        govbot_dir: Option<String>,
     },
 
     /// Load bill metadata into a DuckDB database file
     /// Loads all metadata.json files from cloned repos into a DuckDB database for analysis.
     /// The database file is saved in the base govbot directory (e.g., ./.govbot/govbot.duckdb)
     Load {
-        /// Output database filename (default: govbot.duckdb). Saved in the base govbot directory.
-        #[arg(long, default_value = "govbot.duckdb")]
-        database: String,
+        /// Output database filename (default: govbot.duckdb, or govbot.sqlite with `--format sqlite`). Saved in the base govbot directory.
+        #[arg(long)]
+        database: Option<String>,
+
+        /// Database format: `duckdb` (requires the `duckdb` CLI) or `sqlite` (built in)
+        #[arg(long, default_value = "duckdb", value_parser = ["duckdb", "sqlite"])]
+        format: String,
 
         /// Directory containing repositories (default: $CWD/.govbot/repos, or GOVBOT_DIR env var)
         #[arg(long = "govbot-dir")]
@@ -159,6 +423,12 @@ enum Command {
         /// Number of threads for DuckDB (default: 4)
         #[arg(long)]
         threads: Option<usize>,
+
+        /// Only reload bills whose metadata.json changed since this git ref into the existing
+        /// database (e.g. `HEAD@{1}`), removing bills whose metadata.json was deleted; falls back
+        /// to a full load if the ref or database is missing
+        #[arg(long = "since-commit")]
+        since_commit: Option<String>,
     },
 
     /// Update govbot to the latest nightly version
@@ -171,6 +441,14 @@ enum Command {
         /// Force overwrite existing files
         #[arg(long)]
         force: bool,
+
+        /// Starting govbot.yml: `default` (embedding example), `keyword` (keyword-only matching), or `minimal`
+        #[arg(long, default_value = "default", value_parser = ["default", "keyword", "minimal"])]
+        template: String,
+
+        /// Comma-separated tag names to scaffold as empty tag blocks (e.g. `education,budget`)
+        #[arg(long)]
+        tags: Option<String>,
     },
 
     /// Build RSS feed and HTML index from govbot.yml configuration
@@ -180,6 +458,32 @@ enum Command {
         #[arg(long, num_args = 0..)]
         tags: Vec<String>,
         
+        /// Tags to leave out of the feed (applied after --tags / build.tags)
+        #[arg(long = "exclude-tags", num_args = 0..)]
+        exclude_tags: Vec<String>,
+
+        /// Only publish entries where a selected tag's `final_score` is at least this (0.0-1.0)
+        #[arg(long = "min-score")]
+        min_score: Option<f64>,
+
+        /// How duplicates are found: `guid` (same log path) or `content` (same session, bill,
+        /// action description, and date, e.g. after a re-scrape renamed the log; the newest is kept)
+        #[arg(long, default_value = "guid", value_parser = ["guid", "content"])]
+        dedup: String,
+
+        /// Compare bill ids by their normalized form (uppercase, no spaces or hyphens) when
+        /// deduplicating with `--dedup content`; items keep their raw ids
+        #[arg(long = "normalize-identifiers")]
+        normalize_identifiers: bool,
+        
+        /// Only publish entries newer than this GUID or timestamp (`@last` resumes from the previous build)
+        #[arg(long)]
+        after: Option<String>,
+        
+        /// Feed order (default: DESC, newest first) options: `ASC` | `DESC`
+        #[arg(long, default_value = "DESC", value_parser = ["ASC", "DESC"])]
+        sort: String,
+        
         /// Limit number of entries per feed (default: 100, use "none" for all entries)
         #[arg(long)]
         limit: Option<String>,
@@ -192,12 +496,57 @@ enum Command {
         #[arg(long)]
         output_file: Option<String>,
         
+        /// Base URL for feed links (default: from govbot.yml build.base_url)
+        #[arg(long = "base-url")]
+        base_url: Option<String>,
+        
+        /// Feed title (default: from govbot.yml build.title, or derived from tag names)
+        #[arg(long)]
+        title: Option<String>,
+        
+        /// Feed description (default: from govbot.yml build.description, or derived from tag descriptions)
+        #[arg(long)]
+        description: Option<String>,
+        
+        /// Re-parse every generated feed (archive pages and per-repo feeds too) and fail if one is
+        /// malformed or missing required elements
+        #[arg(long = "validate-feed")]
+        validate_feed: bool,
+
+        /// Keep items from the existing feed file (deduplicated by GUID) so history survives source rotation
+        #[arg(long)]
+        merge: bool,
+
+        /// Also write one feed per repo (`<output-dir>/<repo>.xml`); the limit applies per repo
+        #[arg(long = "feed-per-repo")]
+        feed_per_repo: bool,
+
+        /// Split the feed into pages of this many entries, linked as RFC 5005 archives: the newest
+        /// page is the output file, older pages are written as `<name>-<n>.xml` (page 1 is the oldest).
+        /// Every entry is paged (`--limit` only applies to `--feed-per-repo` feeds), and archive
+        /// pages left over from a longer previous run are removed
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "merge")]
+        paginate: Option<u64>,
+
+        /// Feed language tag, e.g. `fr-ca` (default: from govbot.yml build.language, or "en-us")
+        #[arg(long)]
+        language: Option<String>,
+
+        /// Keep running and rebuild whenever govbot.yml or a tag file changes (Ctrl-C to stop)
+        #[arg(long)]
+        watch: bool,
+
+        /// Print the feed XML and HTML index to stdout and list the other files instead of writing
+        /// anything (nothing under the output directory, no `--after @last` cursor)
+        #[arg(long, alias = "drafts", conflicts_with = "watch")]
+        preview: bool,
+        
         /// Govbot directory (default: $CWD/.govbot/repos, or GOVBOT_DIR env var)
         #[arg(long = "govbot-dir")]
         govbot_dir: Option<String>,
     },
 
-    /// Tag bills using semantic or built-in similarity based on govbot.yml in the current directory.
+    /// Tag bills using semantic or built-in similarity based on govbot.yml in the current directory (or `--config`).
     /// Reads JSON lines from stdin (from `govbot logs`), processes entries with bill identifiers,
     /// and writes per-tag files under the directory containing govbot.yml.
     /// By default, acts as a filter: only outputs lines that match tags.
@@ -217,41 +566,157 @@ enum Command {
         /// Force re-tagging even if bill already exists in tag files
         #[arg(long)]
         overwrite: bool,
+
+        /// Print the text extracted from each entry (what gets embedded and matched) to stderr
+        #[arg(long = "show-text")]
+        show_text: bool,
+
+        /// Read entries from this file instead of stdin; lines are counted up front to report progress and an ETA
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Never download the embedding model: use it if already present, else go straight to keyword matching
+        #[arg(long = "no-download")]
+        no_download: bool,
+
+        /// Use this threshold (0.0-1.0) for every tag in this run instead of each tag's configured `threshold`
+        #[arg(long)]
+        threshold: Option<f32>,
+
+        /// Only record matches whose `final_score` is at least this (0.0-1.0), on top of each
+        /// tag's threshold
+        #[arg(long = "min-score")]
+        min_score: Option<f64>,
+
+        /// Embedding sessions scoring entries at once (default: 1); workers share the precomputed tag embeddings
+        #[arg(long)]
+        jobs: Option<usize>,
+
+        /// Drop every tag match for entries whose text contains this keyword, on top of each tag's
+        /// `exclude_keywords` (repeatable)
+        #[arg(long = "exclude-keyword", alias = "exclude-keyword-global")]
+        exclude_keywords: Vec<String>,
+
+        /// Key tag files by normalized bill id (uppercase, no spaces or hyphens) so `HB 1`, `HB1`,
+        /// and `hb-1` share one entry; output lines keep their raw ids
+        #[arg(long = "normalize-identifiers")]
+        normalize_identifiers: bool,
+    },
+
+    /// Export every tagged bill as one file
+    /// Walks all `tags/` directories and writes one record per (bill, tag) with its score,
+    /// text hash, state, and session, as NDJSON or Parquet (Parquet requires the `duckdb` CLI).
+    Export {
+        /// Output format (default: `json`, one record per line) options: `json`, `parquet`
+        #[arg(long, default_value = "json", value_parser = ["json", "parquet"])]
+        format: String,
+
+        /// Output file (default: stdout; required for `parquet`)
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Directory holding the `country:*/state:*/sessions/*/tags` trees (default: the directory containing govbot.yml)
+        #[arg(long = "tags-dir")]
+        tags_dir: Option<PathBuf>,
+    },
+
+    /// Compare the tags configured in govbot.yml with the tag files on disk
+    /// Prints each tag's configured threshold, the number of sessions with a tag file, and the
+    /// total tagged bills; configured tags that never matched a bill are flagged
+    ListTags {
+        /// Directory holding the `country:*/state:*/sessions/*/tags` trees (default: the directory containing govbot.yml)
+        #[arg(long = "tags-dir")]
+        tags_dir: Option<PathBuf>,
+    },
+
+    /// Check the environment govbot runs in
+    /// Reports the govbot directory, DuckDB, the model host, free disk space, and govbot.yml as
+    /// pass/warn/fail; exits non-zero if any check fails
+    Doctor {
+        /// Govbot directory (default: $CWD/.govbot, or GOVBOT_DIR env var)
+        #[arg(long = "govbot-dir")]
+        govbot_dir: Option<String>,
+
+        /// Don't contact the model host
+        #[arg(long)]
+        offline: bool,
     },
 }
 
 fn print_available_commands() {
     println!("Available commands:");
-    println!("  init    Initialize a new govbot project (creates govbot.yml, .gitignore, and GitHub Actions workflow)");
-    println!("  clone   Clone or pull data pipeline repositories (default: updates existing repos, use 'clone all' to clone all)");
-    println!("  delete  Delete data pipeline repositories (use 'delete all' to delete all)");
-    println!("  logs    Process and display pipeline log files");
-    println!("  load    Load bill metadata into a DuckDB database file");
-    println!("  build   Generate RSS feed and HTML index from govbot.yml configuration");
-    println!("  tag     Tag bills using AI based on log entries");
-    println!("  update  Update govbot to the latest nightly version");
+    println!("  init       Initialize a new govbot project (creates govbot.yml, .gitignore, and GitHub Actions workflow)");
+    println!("  clone      Clone or pull data pipeline repositories (default: updates existing repos, use 'clone all' to clone all)");
+    println!("  delete     Delete data pipeline repositories (use 'delete all' to delete all)");
+    println!("  logs       Process and display pipeline log files");
+    println!("  diff       List log files added or modified since the last pull");
+    println!("  load       Load bill metadata into a DuckDB database file");
+    println!("  build      Generate RSS feed and HTML index from govbot.yml configuration");
+    println!("  tag        Tag bills using AI based on log entries");
+    println!("  export     Export all tagged bills with scores as one NDJSON or Parquet file");
+    println!("  list-tags  Show configured tags next to the tag files on disk, flagging tags with no matches");
+    println!("  doctor     Check the govbot directory, DuckDB, model host, disk space, and govbot.yml");
+    println!("  update     Update govbot to the latest nightly version");
 }
 
+/// Repos directory: `<govbot-dir>/repos`, with the govbot dir from the flag, `GOVBOT_DIR`, or `$CWD/.govbot`
 fn get_govbot_dir(govbot_dir: Option<String>) -> anyhow::Result<PathBuf> {
-    // Check flag first, then environment variable, then default
-    if let Some(govbot_dir) = govbot_dir {
-        // Append /repos to custom govbot-dir (default already includes /repos)
-        Ok(PathBuf::from(govbot_dir).join("repos"))
-    } else if let Ok(govbot_dir) = std::env::var("GOVBOT_DIR") {
-        // Append /repos to custom govbot-dir from env var
-        Ok(PathBuf::from(govbot_dir).join("repos"))
-    } else {
-        // Fall back to default: $CWD/.govbot/repos
-        git::default_repos_dir().map_err(|e| anyhow::anyhow!("{}", e))
+    Ok(GovbotEnv::resolve(govbot_dir.as_deref(), None, None)?.repos_dir())
+}
+
+/// Load the repo manifest from `--manifest` or the govbot dir (the parent of `repos_dir`)
+/// and register its repo overrides
+fn load_repo_manifest(manifest: Option<String>, repos_dir: &Path) -> anyhow::Result<Option<govbot::RepoManifest>> {
+    let path = match manifest {
+        Some(path) => Some(PathBuf::from(path)),
+        None => repos_dir.parent().and_then(govbot::RepoManifest::discover),
+    };
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    let manifest = govbot::RepoManifest::load(&path)?;
+    git::set_repo_overrides(manifest.repo_overrides());
+    Ok(Some(manifest))
+}
+
+/// Locale codes `all` expands to: the built-in set, extended or replaced by a manifest
+fn known_locales(manifest: Option<&govbot::RepoManifest>) -> Vec<String> {
+    match manifest {
+        Some(manifest) => manifest.locales(),
+        None => govbot::locale::WorkingLocale::all()
+            .iter()
+            .map(|l| l.as_lowercase().to_string())
+            .collect(),
+    }
+}
+
+/// Display name for a locale: the manifest entry's `name`, else the built-in locale table's
+fn locale_display_name<'a>(locale: &str, manifest: Option<&'a govbot::RepoManifest>) -> &'a str {
+    manifest
+        .and_then(|m| m.display_name(locale))
+        .or_else(|| {
+            govbot::locale::WorkingLocale::all()
+                .into_iter()
+                .find(|l| l.as_lowercase() == locale)
+                .map(|l| l.display_name())
+        })
+        .unwrap_or("")
+}
+
+/// Validate a locale named on the command line; manifest locales bypass the built-in check
+fn validate_locale(locale: &str, manifest: Option<&govbot::RepoManifest>) {
+    if !manifest.is_some_and(|m| m.contains(locale)) {
+        let _ = govbot::locale::WorkingLocale::from(locale);
     }
 }
 
 /// Process a single locale clone/pull operation
 fn process_single_locale(
     locale: &str,
-    repos_dir: &PathBuf,
+    repos_dir: &Path,
     token_str: Option<&str>,
     verbose: bool,
+    allow_dirty: bool,
 ) -> CloneResult {
     let repo_name = git::build_repo_name(locale);
     let target_dir = repos_dir.join(&repo_name);
@@ -262,7 +727,7 @@ fn process_single_locale(
         0
     };
     
-    match git::clone_or_pull_repo_quiet(locale, repos_dir, token_str, !verbose) {
+    match git::clone_or_pull_repo_with_allow_dirty(locale, repos_dir, token_str, !verbose, allow_dirty) {
         Ok(action) => {
             let final_size = if target_dir.exists() {
                 git::get_directory_size(&target_dir).unwrap_or(0)
@@ -270,17 +735,9 @@ fn process_single_locale(
                 0
             };
             
-            let result = match action {
-                "clone" => "🆕",
-                "pulled" => "⬇️",
-                "no_updates" => "✅",
-                "recloned" => "🔄",
-                _ => "processed",
-            };
-            
             let mut clone_result = CloneResult {
                 locale: locale.to_string(),
-                result: result.to_string(),
+                result: action.to_string(),
                 position: String::new(), // Will be set by caller
                 size: None,
                 local_size: None,
@@ -312,11 +769,17 @@ fn process_single_locale(
 /// Print a single clone result
 fn print_result(result: &CloneResult) {
     use std::io::Write;
-    if result.result == "failed" {
+    if result.result == "skipped_dirty" {
+        eprintln!(
+            "{}  {:<6}  skipped: uncommitted changes (use --allow-dirty to overwrite)",
+            icon("⚠️", "skipped"),
+            result.locale
+        );
+    } else if result.result == "failed" {
         if let Some(ref error) = result.error {
-            eprintln!("❌  {:<6}  {}", result.locale, error);
+            eprintln!("{}  {:<6}  {}", icon("❌", "failed"), result.locale, error);
         } else {
-            eprintln!("❌  {:<6}", result.locale);
+            eprintln!("{}  {:<6}", icon("❌", "failed"), result.locale);
         }
     } else {
         let size_str = if let Some(ref size) = result.size {
@@ -327,8 +790,13 @@ fn print_result(result: &CloneResult) {
             String::new()
         };
         
-        // result.result now contains the emoji directly (🆕, ⬇️, ✅, 🔄)
-        let action_emoji = &result.result;
+        let action_emoji = match result.result.as_str() {
+            "clone" => icon("🆕", "new"),
+            "pulled" => icon("⬇️", "pulled"),
+            "no_updates" => icon("✅", "ok"),
+            "recloned" => icon("🔄", "recloned"),
+            _ => "processed",
+        };
         
         if !size_str.is_empty() {
             eprintln!("{}  {:<6}  [{}]", action_emoji, result.locale, size_str);
@@ -347,6 +815,7 @@ async fn perform_clone_operations(
     token_str: Option<&str>,
     num_jobs: usize,
     verbose: bool,
+    allow_dirty: bool,
 ) -> anyhow::Result<Vec<CloneResult>> {
     let total = repos_to_clone.len();
     let mut all_results = Vec::new();
@@ -354,7 +823,7 @@ async fn perform_clone_operations(
     if total == 1 || num_jobs == 1 {
         // Sequential clone/pull - print as we go
         for (idx, locale) in repos_to_clone.iter().enumerate() {
-            let mut result = process_single_locale(locale, &repos_dir, token_str, verbose);
+            let mut result = process_single_locale(locale, &repos_dir, token_str, verbose, allow_dirty);
             result.position = format!("{}/{}", idx + 1, total);
             print_result(&result);
             all_results.push(result);
@@ -364,36 +833,39 @@ async fn perform_clone_operations(
         use std::sync::{Arc, Mutex};
         let completed = Arc::new(Mutex::new(0usize));
         
-        let clone_futures = stream::iter(repos_to_clone.iter())
-            .map(|locale| {
+        let clone_futures = stream::iter(repos_to_clone.iter().enumerate())
+            .map(|(idx, locale)| {
                 let locale = locale.clone();
                 let repos_dir = repos_dir.clone();
                 let token = token_str.map(|s| s.to_string());
                 let completed = completed.clone();
-                let total = total;
                 let verbose_flag = verbose;
                 
-                tokio::task::spawn_blocking(move || {
-                    let mut result = process_single_locale(&locale, &repos_dir, token.as_deref(), verbose_flag);
+                let task_locale = locale.clone();
+                let task = tokio::task::spawn_blocking(move || {
+                    let mut result = process_single_locale(&task_locale, &repos_dir, token.as_deref(), verbose_flag, allow_dirty);
                     let mut count = completed.lock().unwrap();
                     *count += 1;
                     result.position = format!("{}/{}", *count, total);
                     result
-                })
+                });
+                // Carry the request index so results can be put back in request order
+                async move { (idx, locale, task.await) }
             })
             .buffer_unordered(num_jobs);
 
         let mut stream = clone_futures;
+        let mut indexed_results = Vec::with_capacity(total);
         
-        while let Some(result) = stream.next().await {
+        while let Some((idx, locale, result)) = stream.next().await {
             match result {
                 Ok(data) => {
                     print_result(&data);
-                    all_results.push(data);
+                    indexed_results.push((idx, data));
                 }
                 Err(e) => {
                     let error_result = CloneResult {
-                        locale: "unknown".to_string(),
+                        locale,
                         result: "failed".to_string(),
                         position: "?".to_string(),
                         size: None,
@@ -402,13 +874,17 @@ async fn perform_clone_operations(
                         error: Some(format!("Task error: {}", e)),
                     };
                     print_result(&error_result);
-                    all_results.push(error_result);
+                    indexed_results.push((idx, error_result));
                 }
             }
             // Force flush after each result to ensure immediate output
             use std::io::Write;
             let _ = std::io::stderr().flush();
         }
+
+        // Progress lines follow completion order; the returned results follow request order
+        indexed_results.sort_by_key(|(idx, _)| *idx);
+        all_results.extend(indexed_results.into_iter().map(|(_, result)| result));
     }
     
     Ok(all_results)
@@ -423,31 +899,56 @@ async fn run_clone_command(cmd: Command) -> anyhow::Result<()> {
         parallel,
         verbose,
         list,
+        status,
+        only_missing,
+        allow_dirty,
+        manifest,
     } = cmd else {
         unreachable!()
     };
 
+    let repos_dir = get_govbot_dir(govbot_dir)?;
+    let manifest = load_repo_manifest(manifest, &repos_dir)?;
+    let all_locales = known_locales(manifest.as_ref());
+
     // If --list flag is set, show the list
     if list {
         println!("Available repos:");
-        let all_locales = govbot::locale::WorkingLocale::all();
-        for locale in all_locales {
-            println!("  {}", locale.as_lowercase());
+        for locale in &all_locales {
+            println!("  {}", locale);
         }
         println!("  all (clone all repos)");
         return Ok(());
     }
 
-    let repos_dir = get_govbot_dir(govbot_dir)?;
-    
-    // Get token from argument or environment variable
-    let env_token = std::env::var("TOKEN").ok();
-    let token_str = token.as_deref().or(env_token.as_deref());
+    if status {
+        let cloned = git::get_available_locales(&repos_dir).map_err(|e| anyhow::anyhow!("{}", e))?;
+        let now = chrono::Utc::now();
+        let names: Vec<&str> = all_locales
+            .iter()
+            .map(|code| locale_display_name(code, manifest.as_ref()))
+            .collect();
+        let name_width = names.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+        println!("Repo status ({}):", repos_dir.display());
+        for (code, name) in all_locales.iter().zip(&names) {
+            let repo_path = repos_dir.join(git::build_repo_name(code));
+            let state = if cloned.contains(code) || repo_path.join(".git").exists() {
+                match git::head_commit_time(&repo_path) {
+                    Ok(time) => git::format_commit_age(time, now),
+                    Err(e) => format!("cloned (unable to read HEAD: {})", e),
+                }
+            } else {
+                "not cloned".to_string()
+            };
+            println!("  {:<4} {:<width$} {}", code, name, state, width = name_width);
+        }
+        return Ok(());
+    }
     
-    // Get parallelization setting
-    let num_jobs = parallel
-        .or_else(|| std::env::var("GOVBOT_JOBS").ok().and_then(|s| s.parse().ok()))
-        .unwrap_or(4);
+    // Token and parallelism: flag, then TOKEN / GOVBOT_JOBS, then defaults
+    let env = GovbotEnv::resolve(None, parallel, token.as_deref())?;
+    let token_str = env.token.as_deref();
+    let num_jobs = env.jobs;
 
     // Parse repos and handle "all"
     let mut repos_to_clone = Vec::new();
@@ -455,10 +956,8 @@ async fn run_clone_command(cmd: Command) -> anyhow::Result<()> {
     if repos.is_empty() {
         // No repos specified: find existing repos to update
         // Check all known locales to see which repos exist
-        let all_locales = govbot::locale::WorkingLocale::all();
-        for locale in all_locales {
-            let locale_str = locale.as_lowercase();
-            let repo_name = git::build_repo_name(&locale_str);
+        for locale_str in &all_locales {
+            let repo_name = git::build_repo_name(locale_str);
             let repo_path = repos_dir.join(&repo_name);
             
             // Check if this is a git repository
@@ -487,25 +986,29 @@ async fn run_clone_command(cmd: Command) -> anyhow::Result<()> {
             }
             
             if repo == "all" {
-                // Add all working locales
-                let all_locales = govbot::locale::WorkingLocale::all();
-                for loc in all_locales {
-                    repos_to_clone.push(loc.as_lowercase().to_string());
-                }
+                // Add all known locales
+                repos_to_clone.extend(all_locales.iter().cloned());
             } else {
-                // Validate locale
-                let _ = govbot::locale::WorkingLocale::from(repo.as_str());
+                validate_locale(&repo, manifest.as_ref());
                 repos_to_clone.push(repo);
             }
         }
     }
 
+    if only_missing {
+        let before = repos_to_clone.len();
+        repos_to_clone.retain(|locale| {
+            git2::Repository::open(repos_dir.join(git::build_repo_name(locale))).is_err()
+        });
+        eprintln!("Skipping {} repos that are already cloned", before - repos_to_clone.len());
+    }
+
     if repos_to_clone.is_empty() {
         return Ok(());
-}
+    }
 
     // Print initial message with count
-    eprintln!("🔁 Syncing {} repos\n", repos_to_clone.len());
+    eprintln!("{} Syncing {} repos\n", icon("🔁", "sync"), repos_to_clone.len());
 
     // Perform clone operations and print results as they complete
     let results = perform_clone_operations(
@@ -514,17 +1017,30 @@ async fn run_clone_command(cmd: Command) -> anyhow::Result<()> {
         token_str,
         num_jobs,
         verbose,
+        allow_dirty,
     ).await?;
     
     // Show summary
     let errors: Vec<_> = results.iter()
         .filter(|r| r.result == "failed")
         .collect();
+    let dirty: Vec<&str> = results.iter()
+        .filter(|r| r.result == "skipped_dirty")
+        .map(|r| r.locale.as_str())
+        .collect();
     
     if !errors.is_empty() {
-        eprintln!("\n❌ Errors occurred: {}/{}", errors.len(), results.len());
+        eprintln!("\n{} Errors occurred: {}/{}", icon("❌", "failed"), errors.len(), results.len());
+        let failed: Vec<&str> = errors.iter().map(|r| r.locale.as_str()).collect();
+        eprintln!("Failed: {}", failed.join(", "));
+    } else if !dirty.is_empty() {
+        eprintln!("\n{} Processed {}/{} repos", icon("⚠️", "warn"), results.len() - dirty.len(), results.len());
     } else if !results.is_empty() {
-        eprintln!("\n✅ Successfully processed all {} repos!", results.len());
+        eprintln!("\n{} Successfully processed all {} repos!", icon("✅", "ok"), results.len());
+    }
+    
+    if !dirty.is_empty() {
+        eprintln!("Skipped with uncommitted changes: {}", dirty.join(", "));
     }
     
     Ok(())
@@ -537,6 +1053,8 @@ async fn run_delete_command(cmd: Command) -> anyhow::Result<()> {
         govbot_dir,
         parallel,
         verbose,
+        manifest,
+        json,
     } = cmd else {
         unreachable!()
     };
@@ -552,15 +1070,15 @@ async fn run_delete_command(cmd: Command) -> anyhow::Result<()> {
         eprintln!("  --govbot-dir <dir>    Directory containing repositories");
         eprintln!("  --parallel <num>      Number of parallel operations (default: 4)");
         eprintln!("  --verbose             Show verbose output");
+        eprintln!("  --json                Print per-locale results as JSON");
         return Ok(());
     }
 
     let repos_dir = get_govbot_dir(govbot_dir)?;
+    let manifest = load_repo_manifest(manifest, &repos_dir)?;
     
     // Get parallelization setting
-    let num_jobs = parallel
-        .or_else(|| std::env::var("GOVBOT_JOBS").ok().and_then(|s| s.parse().ok()))
-        .unwrap_or(4);
+    let num_jobs = GovbotEnv::resolve(None, parallel, None)?.jobs;
 
     // Parse locales and handle "all"
     let mut locales_to_delete = Vec::new();
@@ -571,14 +1089,10 @@ async fn run_delete_command(cmd: Command) -> anyhow::Result<()> {
         }
         
         if locale == "all" {
-            // Add all working locales
-            let all_locales = govbot::locale::WorkingLocale::all();
-            for loc in all_locales {
-                locales_to_delete.push(loc.as_lowercase().to_string());
-            }
+            // Add all known locales
+            locales_to_delete.extend(known_locales(manifest.as_ref()));
         } else {
-            // Validate locale
-            let _ = govbot::locale::WorkingLocale::from(locale.as_str());
+            validate_locale(&locale, manifest.as_ref());
             locales_to_delete.push(locale);
         }
     }
@@ -588,82 +1102,42 @@ async fn run_delete_command(cmd: Command) -> anyhow::Result<()> {
     }
 
     // Print initial message with count
-    eprintln!("🗑️  Deleting {} repos\n", locales_to_delete.len());
+    eprintln!("{}  Deleting {} repos\n", icon("🗑️", "delete"), locales_to_delete.len());
 
     // Perform delete operations
     let total = locales_to_delete.len();
-    let mut deleted_count = 0;
-    let mut failed_count = 0;
+    let mut results: Vec<DeleteResult> = Vec::with_capacity(total);
     
     if total == 1 || num_jobs == 1 {
         // Sequential delete
         for (idx, locale) in locales_to_delete.iter().enumerate() {
-            let repo_name = format!("{}-data-pipeline", locale);
-            let target_dir = repos_dir.join(&repo_name);
-            let existed = target_dir.exists();
-            
             if verbose {
                 eprintln!("[{}/{}] Deleting {}...", idx + 1, total, locale);
             }
             
-            match git::delete_repo(locale, &repos_dir) {
-                Ok(_) => {
-                    if existed {
-                        eprintln!("{:<4}  deleted", locale);
-                        deleted_count += 1;
-                    } else {
-                        eprintln!("{:<4}  not_found", locale);
-                    }
-                }
-                Err(e) => {
-                    eprintln!("{:<4}  failed     {}", locale, e);
-                    failed_count += 1;
-                }
-            }
+            let result = delete_single_locale(locale, &repos_dir);
+            print_delete_result(&result);
+            results.push(result);
         }
     } else {
         // Parallel delete
-        use std::sync::{Arc, Mutex};
-        let deleted = Arc::new(Mutex::new(0usize));
-        let failed = Arc::new(Mutex::new(0usize));
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        let started = Arc::new(AtomicUsize::new(0));
         
         let delete_futures = stream::iter(locales_to_delete.iter())
             .map(|locale| {
                 let locale = locale.clone();
                 let repos_dir = repos_dir.clone();
-                let deleted = deleted.clone();
-                let failed = failed.clone();
-                let total = total;
+                let started = started.clone();
                 let verbose_flag = verbose;
                 
                 tokio::task::spawn_blocking(move || {
-                    let repo_name = format!("{}-data-pipeline", locale);
-                    let target_dir = repos_dir.join(&repo_name);
-                    
                     if verbose_flag {
-                        let d = deleted.lock().unwrap();
-                        let f = failed.lock().unwrap();
-                        let current = *d + *f + 1;
+                        let current = started.fetch_add(1, Ordering::SeqCst) + 1;
                         eprintln!("[{}/{}] Deleting {}...", current, total, locale);
                     }
-                    
-                    let existed = target_dir.exists();
-                    match git::delete_repo(&locale, &repos_dir) {
-                        Ok(_) => {
-                            if existed {
-                                let mut d = deleted.lock().unwrap();
-                                *d += 1;
-                                (locale, Ok("deleted".to_string()))
-                            } else {
-                                (locale, Ok("not_found".to_string()))
-                            }
-                        }
-                        Err(e) => {
-                            let mut f = failed.lock().unwrap();
-                            *f += 1;
-                            (locale, Err(e.to_string()))
-                        }
-                    }
+                    delete_single_locale(&locale, &repos_dir)
                 })
             })
             .buffer_unordered(num_jobs);
@@ -672,36 +1146,95 @@ async fn run_delete_command(cmd: Command) -> anyhow::Result<()> {
         
         while let Some(result) = stream.next().await {
             match result {
-                Ok((locale, Ok(status))) => {
-                    eprintln!("{:<4}  {}", locale, status);
-                }
-                Ok((locale, Err(error))) => {
-                    eprintln!("{:<4}  failed     {}", locale, error);
+                Ok(result) => {
+                    print_delete_result(&result);
+                    results.push(result);
                 }
                 Err(e) => {
                     eprintln!("unknown  failed     Task error: {}", e);
-                    let mut f = failed.lock().unwrap();
-                    *f += 1;
+                    results.push(DeleteResult {
+                        locale: "unknown".to_string(),
+                        status: "failed",
+                        freed_bytes: 0,
+                        error: Some(format!("Task error: {}", e)),
+                    });
                 }
             }
         }
-        
-        deleted_count = *deleted.lock().unwrap();
-        failed_count = *failed.lock().unwrap();
+
+        // Report in the requested order regardless of completion order
+        results.sort_by_key(|r| locales_to_delete.iter().position(|l| *l == r.locale).unwrap_or(usize::MAX));
+    }
+
+    let deleted_count = results.iter().filter(|r| r.status == "deleted").count();
+    let failed_count = results.iter().filter(|r| r.status == "failed").count();
+
+    if json {
+        println!("{}", serde_json::to_string(&results)?);
     }
     
     // Show summary
     if failed_count > 0 {
-        eprintln!("\n❌ Errors occurred: {}/{}", failed_count, total);
+        eprintln!("\n{} Errors occurred: {}/{}", icon("❌", "failed"), failed_count, total);
     } else if deleted_count > 0 {
-        eprintln!("\n✅ Successfully deleted {} repositories!", deleted_count);
+        eprintln!("\n{} Successfully deleted {} repositories!", icon("✅", "ok"), deleted_count);
     } else {
-        eprintln!("\n✅ No repositories found to delete.");
+        eprintln!("\n{} No repositories found to delete.", icon("✅", "ok"));
     }
     
     Ok(())
 }
 
+/// Outcome of deleting one locale's repo (serialized by `delete --json`)
+#[derive(Debug, serde::Serialize)]
+struct DeleteResult {
+    locale: String,
+    /// "deleted", "not_found", or "failed"
+    status: &'static str,
+    freed_bytes: u64,
+    error: Option<String>,
+}
+
+/// Delete a single locale's repo, measuring its size first
+fn delete_single_locale(locale: &str, repos_dir: &Path) -> DeleteResult {
+    let target_dir = repos_dir.join(git::build_repo_name(locale));
+    let existed = target_dir.exists();
+    let size = if existed {
+        git::get_directory_size(&target_dir).unwrap_or(0)
+    } else {
+        0
+    };
+
+    match git::delete_repo(locale, repos_dir) {
+        Ok(_) => DeleteResult {
+            locale: locale.to_string(),
+            status: if existed { "deleted" } else { "not_found" },
+            freed_bytes: size,
+            error: None,
+        },
+        Err(e) => DeleteResult {
+            locale: locale.to_string(),
+            status: "failed",
+            freed_bytes: 0,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Print a single delete result
+fn print_delete_result(result: &DeleteResult) {
+    match &result.error {
+        Some(error) => eprintln!("{:<4}  failed     {}", result.locale, error),
+        None => eprintln!("{:<4}  {}", result.locale, result.status),
+    }
+}
+
+/// Leads the end-of-run summary `logs` prints to stderr (build strips it from forwarded warnings)
+const LOGS_SUMMARY_PREFIX: &str = "Summary:";
+
+/// Per-repo `logs` limit when neither `--limit` nor `logs.limit` is set
+const DEFAULT_LOGS_LIMIT: &str = "100";
+
 async fn run_logs_command(cmd: Command) -> anyhow::Result<()> {
     let Command::Logs {
         govbot_dir,
@@ -711,9 +1244,72 @@ async fn run_logs_command(cmd: Command) -> anyhow::Result<()> {
         join,
         select,
         filter,
+        output,
+        compress,
+        format,
+        pretty,
+        threads,
+        manifest,
+        session,
+        state,
+        country,
+        no_prune,
+        fail_on_empty,
+        follow_symlinks,
+        max_file_size,
+        repos_from_config,
+        count_only,
+        parallel,
+        geometry,
+        include_empty_sessions,
+        max_depth,
+        fields,
+        fields_file,
+        error_log,
+        tags_dir,
+        paths_from,
     } = cmd else {
         unreachable!()
     };
+
+    let yaml = format == "yaml";
+    let table = format == "table";
+    let geojson = format == "geojson";
+    if (yaml || table || geojson) && pretty {
+        anyhow::bail!("--pretty only applies to --format ndjson");
+    }
+    let geometry = match (geojson, geometry) {
+        (true, Some(path)) => Some(load_geometry(&path)?),
+        (true, None) => anyhow::bail!("--format geojson needs --geometry <file>"),
+        (false, Some(_)) => anyhow::bail!("--geometry only applies to --format geojson"),
+        (false, None) => None,
+    };
+    if include_empty_sessions && (table || geojson) {
+        anyhow::bail!("--include-empty-sessions only applies to --format ndjson or yaml");
+    }
+    // --fields wins over --fields-file
+    let fields = match (fields, fields_file) {
+        (Some(fields), _) => Some(split_comma_list(Some(&fields))),
+        (None, Some(path)) => {
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| anyhow::anyhow!("Failed to read --fields-file {}: {}", path.display(), e))?;
+            Some(parse_fields_file(&contents))
+        }
+        (None, None) => None,
+    };
+    if fields.as_ref().is_some_and(|f| f.is_empty()) {
+        anyhow::bail!("--fields/--fields-file lists no fields");
+    }
+    if fields.is_some() && (table || geojson) {
+        anyhow::bail!("--fields only applies to --format ndjson or yaml");
+    }
+    if pretty {
+        eprintln!("Warning: --pretty spreads each entry over multiple lines; output can't be streamed line-by-line (e.g. `jq -c`)");
+    }
+
+    let paths = paths_from.as_deref().map(read_path_list).transpose()?;
+
+    let writer = LineWriter::new(output.as_deref(), compress)?;
     
     // Parse join options - now supports field paths like "bill.title" and special "tags"
     let mut join_specs: Vec<(String, Vec<String>)> = Vec::new();
@@ -729,8 +1325,27 @@ async fn run_logs_command(cmd: Command) -> anyhow::Result<()> {
     }
 
     let git_dir = get_govbot_dir(govbot_dir)?;
+    let manifest = load_repo_manifest(manifest, &git_dir)?;
+    let num_jobs = GovbotEnv::resolve(None, parallel, None)?.jobs;
 
     // Parse limit: "none" means no limit, otherwise parse as usize
+    // Tag files live next to govbot.yml, where `tag` writes them, unless --tags-dir says otherwise
+    let config_path = config_path()?;
+    let tags_base_dir = tags_dir.unwrap_or_else(|| config_path.parent().map(Path::to_path_buf).unwrap_or_default());
+
+    // --limit wins, then `logs.limit` from govbot.yml (see `--config`)
+    let limit = match limit {
+        Some(limit) => limit,
+        None => {
+            let from_config = if config_path.exists() {
+                get_logs_limit_from_config(&load_config(&config_path)?)
+            } else {
+                None
+            };
+            from_config.unwrap_or_else(|| DEFAULT_LOGS_LIMIT.to_string())
+        }
+    };
+
     let limit_parsed: Option<usize> = if limit.to_lowercase() == "none" {
         None
     } else {
@@ -748,8 +1363,17 @@ async fn run_logs_command(cmd: Command) -> anyhow::Result<()> {
         repos
     };
 
-    // Default to "all" if no repos specified
-    if repo_list.is_empty() {
+    // Default to the configured repos with --repos-from-config, else "all"
+    if repo_list.is_empty() && repos_from_config {
+        if !config_path.exists() {
+            return Err(anyhow::anyhow!(
+                "--repos-from-config needs a govbot.yml ({} not found)",
+                config_path.display()
+            ));
+        }
+        repo_list = get_repos_from_config_with_locales(&load_config(&config_path)?, &known_locales(manifest.as_ref()))?;
+    }
+    if repo_list.is_empty() {
         repo_list.push("all".to_string());
     }
 
@@ -764,9 +1388,7 @@ async fn run_logs_command(cmd: Command) -> anyhow::Result<()> {
         if locale == "all" {
             // Find all existing repos in the directory
             if git_dir.exists() {
-                let all_locales = govbot::locale::WorkingLocale::all();
-                for loc in all_locales {
-                    let locale_str = loc.as_lowercase();
+                for locale_str in known_locales(manifest.as_ref()) {
                     let repo_name = git::build_repo_name(&locale_str);
                     let repo_path = git_dir.join(&repo_name);
                     
@@ -782,353 +1404,823 @@ async fn run_logs_command(cmd: Command) -> anyhow::Result<()> {
         }
     }
 
-    // Per-repo limit
-    let per_repo_limit = limit_parsed;
-
-    // Initialize filter (now has default value "default")
-    let filter_manager = govbot::FilterManager::new(govbot::FilterAlias::from(filter.as_str()));
+    let opts = std::sync::Arc::new(LogsRunOptions {
+        git_dir: git_dir.clone(),
+        tags_base_dir,
+        join_specs,
+        join_tags,
+        select,
+        // Initialize filter (now has default value "default")
+        filter_manager: govbot::FilterManager::new(govbot::FilterAlias::from(filter.as_str())),
+        // Per-repo limit
+        per_repo_limit: limit_parsed,
+        threads,
+        follow_symlinks,
+        max_file_size,
+        // Path-component filters, applied before a file is read
+        sessions: split_comma_list(session.as_deref()),
+        states: split_comma_list(state.as_deref()),
+        countries: split_comma_list(country.as_deref()),
+        no_prune,
+        count_only,
+        table,
+        yaml,
+        pretty,
+        format,
+        geometry,
+        include_empty_sessions,
+        max_depth,
+        fields,
+        paths,
+    });
 
-    // Process each repo (with optional filtering)
+    let mut existing_repos = Vec::new();
     for repo_name in repos_to_process {
         let repo_path = git_dir.join(&repo_name);
-        
         if !repo_path.exists() {
             eprintln!("Warning: Repository not found: {}", repo_path.display());
             continue;
         }
+        existing_repos.push((repo_name, repo_path));
+    }
+    let repos_processed = existing_repos.len();
 
-        // Walk the repo directory to find log files matching the pattern:
-        // repo_name/country:{country}/state:{state}/sessions/{session_name}/logs/*.json
-        let mut file_count = 0;
-        
-        for entry_result in WalkDir::new(&repo_path)
-            .process_read_dir(|_depth, _path, _read_dir_state, _children| {
-                // Optional: customize directory reading behavior
-            })
-            .into_iter()
-        {
-            let entry = match entry_result {
-                Ok(e) => e,
-                Err(_) => continue,
-            };
+    let mut sink = LogSink {
+        writer,
+        table_rows: Vec::new(),
+        features: Vec::new(),
+        seen_files: HashSet::new(),
+    };
 
-            // Check per-repo limit
-            if let Some(limit) = per_repo_limit {
-                if file_count >= limit {
-                    break;
+    let mut repo_stats: Vec<RepoLogStats> = Vec::with_capacity(repos_processed);
+    if output.is_some() && num_jobs > 1 && repos_processed > 1 {
+        // Writing to a file: walk repos concurrently, each streaming its entries through a
+        // bounded channel, and merge them in repo order. `--limit` counts entries the sink
+        // accepts, as in the serial path, so it is applied here rather than by the walk.
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        let mut walks = stream::iter(existing_repos)
+            .map(|(repo_name, repo_path)| {
+                let opts = opts.clone();
+                let (tx, rx) = tokio::sync::mpsc::channel(LOG_CHANNEL_CAPACITY);
+                let done = Arc::new(AtomicBool::new(false));
+                let walk = tokio::task::spawn_blocking({
+                    let repo_name = repo_name.clone();
+                    let done = done.clone();
+                    move || {
+                        collect_repo_logs(&repo_name, &repo_path, &opts, &mut |canonical, record| {
+                            // Markers come after the walk and are written regardless of --limit
+                            let wanted = matches!(record, LogRecord::Marker(_)) || !done.load(Ordering::Relaxed);
+                            if wanted && tx.blocking_send((canonical, record)).is_ok() {
+                                std::ops::ControlFlow::Continue(false)
+                            } else {
+                                std::ops::ControlFlow::Break(())
+                            }
+                        })
+                    }
+                });
+                futures::future::ready((repo_name, rx, done, walk))
+            })
+            .buffered(num_jobs);
+
+        while let Some((repo_name, mut records, done, walk)) = walks.next().await {
+            let mut emitted = 0;
+            while let Some((canonical, record)) = records.recv().await {
+                let counted = !matches!(record, LogRecord::Marker(_));
+                // Entries already in the channel when the limit was reached
+                if counted && opts.per_repo_limit.is_some_and(|limit| emitted >= limit) {
+                    continue;
+                }
+                if sink.accept(canonical, record) && counted {
+                    emitted += 1;
+                    if opts.per_repo_limit.is_some_and(|limit| emitted >= limit) {
+                        done.store(true, Ordering::Relaxed);
+                    }
                 }
             }
+            let mut stats = walk.await?;
+            stats.emitted = emitted;
+            if count_only {
+                sink.writer.write_line(&format!("{}\t{}", repo_name, stats.emitted))?;
+            }
+            repo_stats.push(stats);
+        }
+    } else {
+        // Stdout streams each repo's entries as they are found
+        for (repo_name, repo_path) in existing_repos {
+            let stats = collect_repo_logs(&repo_name, &repo_path, &opts, &mut |canonical, record| {
+                std::ops::ControlFlow::Continue(sink.accept(canonical, record))
+            });
+            if count_only {
+                sink.writer.write_line(&format!("{}\t{}", repo_name, stats.emitted))?;
+            }
+            repo_stats.push(stats);
+        }
+    }
 
-            let path = entry.path();
-            
-            // Check if it's a JSON file in a logs directory
-            if !path.is_file() {
-                continue;
+    // Run totals for the end-of-run summary
+    let total_emitted: usize = repo_stats.iter().map(|s| s.emitted).sum();
+    let total_skipped: usize = repo_stats.iter().map(|s| s.skipped).sum();
+    let parse_errors: usize = repo_stats.iter().map(|s| s.parse_errors).sum();
+    let oversized: usize = repo_stats.iter().map(|s| s.oversized).sum();
+    let no_geometry: usize = repo_stats.iter().map(|s| s.no_geometry).sum();
+    let errors: Vec<LogError> = repo_stats.iter_mut().flat_map(|s| std::mem::take(&mut s.errors)).collect();
+
+    if count_only {
+        sink.writer.write_line(&format!("total\t{}", total_emitted))?;
+    }
+    if table {
+        for line in render_log_table(&sink.table_rows) {
+            sink.writer.write_line(&line)?;
+        }
+    }
+    if geojson && !count_only {
+        let collection = serde_json::json!({
+            "type": "FeatureCollection",
+            "features": std::mem::take(&mut sink.features),
+        });
+        sink.writer.write_line(&serde_json::to_string(&collection)?)?;
+    }
+    sink.writer.finish()?;
+
+    if no_geometry > 0 {
+        eprintln!("Warning: Skipped {} entries with no matching geometry in --geometry", no_geometry);
+    }
+
+    if oversized > 0 {
+        eprintln!("Warning: Skipped {} files larger than --max-file-size", oversized);
+    }
+
+    if !errors.is_empty() {
+        eprintln!("Errors: {}", summarize_log_errors(&errors));
+    }
+    if let Some(path) = &error_log {
+        fs::write(path, serde_json::to_string_pretty(&errors)?)
+            .map_err(|e| anyhow::anyhow!("Failed to write --error-log {}: {}", path.display(), e))?;
+    } else if !errors.is_empty() {
+        eprintln!("  (pass --error-log <file> for the full list)");
+    }
+
+    eprintln!(
+        "{} {} repos processed, {} entries emitted, {} skipped by filter, {} parse errors",
+        LOGS_SUMMARY_PREFIX, repos_processed, total_emitted, total_skipped, parse_errors
+    );
+    if fail_on_empty && total_emitted == 0 {
+        return Err(anyhow::anyhow!("No log entries were emitted (--fail-on-empty)"));
+    }
+    Ok(())
+}
+
+
+/// Paths listed one per line in `source` (`-` for stdin), canonicalized so they can be matched
+/// against repo directories; blank lines are skipped and missing files are reported
+fn read_path_list(source: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let contents = if source == "-" {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(source).map_err(|e| anyhow::anyhow!("Failed to read --paths-from {}: {}", source, e))?
+    };
+    let mut paths = Vec::new();
+    for line in contents.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match Path::new(line).canonicalize() {
+            Ok(path) => paths.push(path),
+            Err(e) => eprintln!("Warning: Skipping --paths-from entry {}: {}", line, e),
+        }
+    }
+    Ok(paths)
+}
+
+/// Settings shared by every repo a `logs` run walks
+struct LogsRunOptions {
+    git_dir: PathBuf,
+    tags_base_dir: PathBuf,
+    join_specs: Vec<(String, Vec<String>)>,
+    join_tags: bool,
+    select: String,
+    filter_manager: govbot::FilterManager,
+    per_repo_limit: Option<usize>,
+    threads: Option<usize>,
+    follow_symlinks: bool,
+    max_file_size: Option<u64>,
+    sessions: Vec<String>,
+    states: Vec<String>,
+    countries: Vec<String>,
+    no_prune: bool,
+    count_only: bool,
+    table: bool,
+    yaml: bool,
+    pretty: bool,
+    format: String,
+    /// Jurisdiction geometries for `--format geojson`
+    geometry: Option<HashMap<String, serde_json::Value>>,
+    include_empty_sessions: bool,
+    max_depth: usize,
+    /// `--fields` projection applied to ndjson/yaml entries
+    fields: Option<Vec<String>>,
+    /// `--paths-from` files (canonicalized), read instead of walking each repo
+    paths: Option<Vec<PathBuf>>,
+}
+
+impl LogsRunOptions {
+    /// Whether any path-component filter (`--session`/`--state`/`--country`) is set
+    fn filter_by_path(&self) -> bool {
+        !self.sessions.is_empty() || !self.states.is_empty() || !self.countries.is_empty()
+    }
+
+    /// Whether path components pass `--country`/`--state`/`--session`
+    fn path_allowed(&self, country: &str, state: &str, session: &str) -> bool {
+        let allowed = |wanted: &[String], value: &str| wanted.is_empty() || wanted.iter().any(|w| w == value);
+        allowed(&self.sessions, session) && allowed(&self.states, state) && allowed(&self.countries, country)
+    }
+}
+
+/// One `logs` entry, ready for the output
+enum LogRecord {
+    /// A serialized ndjson/yaml entry
+    Line(String),
+    /// A serialized `--include-empty-sessions` marker; written like a line, not counted as an entry
+    Marker(String),
+    /// A `--format table` row, rendered once all rows are known
+    Row([String; 4]),
+    /// A `--format geojson` feature, written in one FeatureCollection at the end
+    Feature(serde_json::Value),
+    /// An entry counted by `--count-only`
+    Counted,
+}
+
+/// Per-repo totals for the end-of-run summary
+#[derive(Default)]
+struct RepoLogStats {
+    emitted: usize,
+    skipped: usize,
+    parse_errors: usize,
+    oversized: usize,
+    no_geometry: usize,
+    errors: Vec<LogError>,
+}
+
+impl RepoLogStats {
+    fn error(&mut self, path: &Path, kind: LogErrorKind, message: impl ToString) {
+        self.errors.push(LogError {
+            path: path.display().to_string(),
+            kind,
+            message: message.to_string(),
+        });
+    }
+}
+
+/// A file `logs` couldn't fully handle; written to `--error-log`
+#[derive(Debug, serde::Serialize)]
+struct LogError {
+    path: String,
+    kind: LogErrorKind,
+    message: String,
+}
+
+/// What went wrong with a file, for grouping the error summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum LogErrorKind {
+    Read,
+    Parse,
+    Serialize,
+    MetadataMissing,
+    MetadataRead,
+    MetadataParse,
+    JoinFieldMissing,
+    UnknownJoin,
+}
+
+impl LogErrorKind {
+    fn label(self) -> &'static str {
+        match self {
+            LogErrorKind::Read => "read error",
+            LogErrorKind::Parse => "JSON parse error",
+            LogErrorKind::Serialize => "serialization error",
+            LogErrorKind::MetadataMissing => "metadata missing",
+            LogErrorKind::MetadataRead => "metadata read error",
+            LogErrorKind::MetadataParse => "metadata parse error",
+            LogErrorKind::JoinFieldMissing => "join field not found",
+            LogErrorKind::UnknownJoin => "unknown join dataset",
+        }
+    }
+}
+
+/// One-line error summary grouped by kind, most frequent first
+/// (e.g. "12 files: metadata missing; 3 files: JSON parse error")
+fn summarize_log_errors(errors: &[LogError]) -> String {
+    let mut counts: BTreeMap<LogErrorKind, usize> = BTreeMap::new();
+    for error in errors {
+        *counts.entry(error.kind).or_default() += 1;
+    }
+    let mut counts: Vec<(LogErrorKind, usize)> = counts.into_iter().collect();
+    counts.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+    counts
+        .iter()
+        .map(|(kind, n)| format!("{} {}: {}", n, if *n == 1 { "file" } else { "files" }, kind.label()))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Entries a concurrent `logs` walk may queue ahead of the merge before it blocks
+const LOG_CHANNEL_CAPACITY: usize = 256;
+
+/// Where `logs` entries end up, in the order they are accepted
+struct LogSink {
+    writer: LineWriter,
+    /// `--format table` aligns columns across all entries, so rows are buffered until the end
+    table_rows: Vec<[String; 4]>,
+    features: Vec<serde_json::Value>,
+    /// Canonical paths already emitted, so a file reachable through several links appears once
+    seen_files: HashSet<PathBuf>,
+}
+
+impl LogSink {
+    /// Write one entry; false if it was already emitted or couldn't be written
+    fn accept(&mut self, canonical: PathBuf, record: LogRecord) -> bool {
+        if !self.seen_files.insert(canonical) {
+            return false;
+        }
+        match record {
+            // Ignore broken pipe errors (e.g., when piped to yq/jq that closes early)
+            LogRecord::Line(line) | LogRecord::Marker(line) => self.writer.write_line(&line).is_ok(),
+            LogRecord::Row(row) => {
+                self.table_rows.push(row);
+                true
             }
+            LogRecord::Feature(feature) => {
+                self.features.push(feature);
+                true
+            }
+            LogRecord::Counted => true,
+        }
+    }
+}
 
-            if path.extension().and_then(|s| s.to_str()) != Some("json") {
-                continue;
+/// Walk one repo for log files, handing each kept entry to `emit`, which continues with whether
+/// it was accepted (only accepted entries count toward `--limit`) or breaks to end the walk
+fn collect_repo_logs(
+    repo_name: &str,
+    repo_path: &Path,
+    opts: &LogsRunOptions,
+    emit: &mut dyn FnMut(PathBuf, LogRecord) -> std::ops::ControlFlow<(), bool>,
+) -> RepoLogStats {
+    let stopped = std::cell::Cell::new(false);
+    let mut emit = |canonical: PathBuf, record: LogRecord| match emit(canonical, record) {
+        std::ops::ControlFlow::Continue(accepted) => accepted,
+        std::ops::ControlFlow::Break(()) => {
+            stopped.set(true);
+            false
+        }
+    };
+    let mut stats = RepoLogStats::default();
+    // Files already seen in this repo; `emit` also drops files another repo emitted
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+
+    // Walk the repo directory to find log files matching the pattern:
+    // repo_name/country:{country}/state:{state}/sessions/{session_name}/logs/*.json
+    let mut file_count = 0;
+
+    // With --paths-from, the listed files inside this repo (spelled under `repo_path`); else a walk
+    let candidates: Box<dyn Iterator<Item = PathBuf>> = match &opts.paths {
+        Some(paths) => {
+            let canonical_repo = repo_path.canonicalize().unwrap_or_else(|_| repo_path.to_path_buf());
+            Box::new(
+                paths
+                    .iter()
+                    .filter_map(move |path| path.strip_prefix(&canonical_repo).ok().map(|rel| repo_path.join(rel))),
+            )
+        }
+        None => Box::new(
+            WalkDir::new(repo_path)
+                .parallelism(govbot::processor::walk_parallelism(opts.threads))
+                .max_depth(opts.max_depth)
+                .follow_links(opts.follow_symlinks)
+                .into_iter()
+                .filter_map(|entry| entry.ok().map(|e| e.path())),
+        ),
+    };
+
+    for path in candidates {
+        // Check per-repo limit
+        if let Some(limit) = opts.per_repo_limit {
+            if file_count >= limit {
+                break;
             }
+        }
+        if stopped.get() {
+            break;
+        }
 
-            // Check if path matches: country:{country}/state:{state}/sessions/{session_name}/logs/*.json
-            let path_str = path.to_string_lossy();
-            let repo_prefix = repo_path.to_string_lossy();
-            
-            // Get relative path by stripping the repo prefix
-            // Handle both absolute and relative paths
-            let relative_path = if let Some(stripped) = path_str.strip_prefix(&*repo_prefix) {
-                // Remove leading slash if present
-                stripped.strip_prefix('/').unwrap_or(stripped)
-            } else {
-                // If prefix doesn't match, skip this file
-                continue;
-            };
+        // Check if it's a JSON file in a logs directory
+        if !path.is_file() {
+            continue;
+        }
+
+        if !govbot::processor::is_log_file(&path) {
+            continue;
+        }
+
+        // Check if path matches: country:{country}/state:{state}/sessions/{session_name}/logs/*.json[.gz]
+        let path_str = path.to_string_lossy();
+        let repo_prefix = repo_path.to_string_lossy();
+        
+        // Get relative path by stripping the repo prefix
+        // Handle both absolute and relative paths
+        let relative_path = if let Some(stripped) = path_str.strip_prefix(&*repo_prefix) {
+            // Remove leading slash if present
+            stripped.strip_prefix('/').unwrap_or(stripped)
+        } else {
+            // If prefix doesn't match, skip this file
+            continue;
+        };
+        
+        // Match pattern: country:*/state:*/sessions/*/logs/*.json[.gz]
+        // Use a simple regex-like check: must have these components in order
+        if relative_path.starts_with("country:") 
+            && relative_path.contains("/state:") 
+            && relative_path.contains("/sessions/")
+            && relative_path.contains("/logs/")
+        {
+            // Verify order by checking positions
+            let country_pos = relative_path.find("country:").unwrap_or(0);
+            let state_pos = relative_path.find("/state:").unwrap_or(usize::MAX);
+            let sessions_pos = relative_path.find("/sessions/").unwrap_or(usize::MAX);
+            let logs_pos = relative_path.find("/logs/").unwrap_or(usize::MAX);
             
-            // Match pattern: country:*/state:*/sessions/*/logs/*.json
-            // Use a simple regex-like check: must have these components in order
-            if relative_path.starts_with("country:") 
-                && relative_path.contains("/state:") 
-                && relative_path.contains("/sessions/")
-                && relative_path.contains("/logs/")
-                && relative_path.ends_with(".json")
-            {
-                // Verify order by checking positions
-                let country_pos = relative_path.find("country:").unwrap_or(0);
-                let state_pos = relative_path.find("/state:").unwrap_or(usize::MAX);
-                let sessions_pos = relative_path.find("/sessions/").unwrap_or(usize::MAX);
-                let logs_pos = relative_path.find("/logs/").unwrap_or(usize::MAX);
+            // Verify order: country < state < sessions < logs
+            if country_pos < state_pos && state_pos < sessions_pos && sessions_pos < logs_pos {
+                if opts.filter_by_path() {
+                    let Some((path_country, path_state, session_id)) = extract_path_info(relative_path) else {
+                        continue;
+                    };
+                    if !opts.path_allowed(&path_country, &path_state, &session_id) {
+                        continue;
+                    }
+                }
+
+                let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+                if !seen.insert(canonical.clone()) {
+                    continue;
+                }
+
+                match govbot::processor::oversized_file(&path, opts.max_file_size) {
+                    Ok(None) => {}
+                    Ok(Some(size)) => {
+                        eprintln!(
+                            "Warning: Skipping {} ({} bytes exceeds --max-file-size {})",
+                            path.display(),
+                            size,
+                            opts.max_file_size.unwrap_or_default()
+                        );
+                        stats.oversized += 1;
+                        continue;
+                    }
+                    Err(e) => {
+                        stats.error(&path, LogErrorKind::Read, format!("failed to read file size: {}", e));
+                        stats.parse_errors += 1;
+                        continue;
+                    }
+                }
+
+                // Compute relative source path
+                let source_path_str = compute_relative_source_path(&path, &opts.git_dir);
                 
-                // Verify order: country < state < sessions < logs
-                if country_pos < state_pos && state_pos < sessions_pos && sessions_pos < logs_pos {
-                    // Compute relative source path
-                    let source_path_str = compute_relative_source_path(&path, &git_dir);
-                    
-                    // Read JSON file, parse it, and build extensible output structure
-                    match fs::read_to_string(&path) {
-                        Ok(contents) => {
-                            // Parse JSON
-                            match serde_json::from_str::<serde_json::Value>(&contents) {
-                                Ok(json_value) => {
-                                    // Extract bill_id early (before moving json_value)
-                                    // The json_value IS the log data, so bill_id is at the top level
-                                    let bill_id_opt = json_value
-                                        .get("bill_id")
-                                        .or_else(|| json_value.get("bill_identifier"))
-                                        .and_then(|id| id.as_str())
-                                        .map(|s| s.to_string());
-                                    
-                                    // Build output with extensible structure:
-                                    // - Data keys (log, bill, etc.) are singular entity names matching source keys
-                                    // - sources object automatically tracks all data sources
-                                    let mut output = serde_json::Map::new();
-                                    
-                                    // Add the log data with key "log" (matching sources.log)
-                                    output.insert("log".to_string(), json_value);
-                                    
-                                    // Add sources with the log path
-                                    let mut sources = serde_json::Map::new();
-                                    sources.insert("log".to_string(), serde_json::Value::String(source_path_str.clone()));
-                                    
-                                    // Join additional datasets if requested
-                                    for (dataset_name, field_path) in &join_specs {
-                                        match dataset_name.as_str() {
-                                            "bill" => {
-                                                // Hardcoded: metadata.json is in the parent directory of logs/
-                                                // log path: .../bills/{bill_id}/logs/file.json
-                                                // metadata path: .../bills/{bill_id}/metadata.json
-                                                let canonical_log_path = match path.canonicalize() {
-                                                    Ok(p) => p,
-                                                    Err(_) => path.clone(),
-                                                };
-                                                
-                                                let metadata_path = canonical_log_path.parent()
-                                                    .and_then(|logs_dir| {
-                                                        logs_dir.parent().map(|bill_dir| {
-                                                            bill_dir.join("metadata.json")
-                                                        })
-                                                    });
-                                                
-                                                if let Some(ref metadata_path) = metadata_path {
-                                                    if metadata_path.exists() {
-                                                        match fs::read_to_string(metadata_path) {
-                                                            Ok(metadata_contents) => {
-                                                                match serde_json::from_str::<serde_json::Value>(&metadata_contents) {
-                                                                    Ok(metadata_value) => {
-                                                                        // If field_path is specified, extract just that field
-                                                                        // Otherwise, include the full bill data
-                                                                        if field_path.is_empty() {
-                                                                            // No field path specified, include full bill data
-                                                                            output.insert("bill".to_string(), metadata_value);
+                // Read JSON file (gunzipping `.gz`), parse it, and build extensible output structure
+                match govbot::processor::read_log_to_string(&path) {
+                    Ok(contents) => {
+                        // Parse JSON
+                        match serde_json::from_str::<serde_json::Value>(&contents) {
+                            Ok(json_value) => {
+                                // Extract bill_id early (before moving json_value)
+                                // The json_value IS the log data, so bill_id is at the top level
+                                let bill_id_opt = govbot::types::extract_bill_identifier(&json_value);
+                                
+                                // Build output with extensible structure:
+                                // - Data keys (log, bill, etc.) are singular entity names matching source keys
+                                // - sources object automatically tracks all data sources
+                                let mut output = serde_json::Map::new();
+                                
+                                // Add the log data with key "log" (matching sources.log)
+                                output.insert("log".to_string(), json_value);
+                                
+                                // Add sources with the log path
+                                let mut sources = serde_json::Map::new();
+                                sources.insert("log".to_string(), serde_json::Value::String(source_path_str.clone()));
+                                
+                                // Join additional datasets if requested
+                                for (dataset_name, field_path) in &opts.join_specs {
+                                    match dataset_name.as_str() {
+                                        "bill" => {
+                                            // Hardcoded: metadata.json is in the parent directory of logs/
+                                            // log path: .../bills/{bill_id}/logs/file.json
+                                            // metadata path: .../bills/{bill_id}/metadata.json
+                                            let canonical_log_path = match path.canonicalize() {
+                                                Ok(p) => p,
+                                                Err(_) => path.clone(),
+                                            };
+                                            
+                                            let metadata_path = canonical_log_path.parent()
+                                                .and_then(|logs_dir| {
+                                                    logs_dir.parent().map(|bill_dir| {
+                                                        bill_dir.join("metadata.json")
+                                                    })
+                                                });
+                                            
+                                            if let Some(ref metadata_path) = metadata_path {
+                                                if metadata_path.exists() {
+                                                    match fs::read_to_string(metadata_path) {
+                                                        Ok(metadata_contents) => {
+                                                            match serde_json::from_str::<serde_json::Value>(&metadata_contents) {
+                                                                Ok(metadata_value) => {
+                                                                    // If field_path is specified, extract just that field
+                                                                    // Otherwise, include the full bill data
+                                                                    if field_path.is_empty() {
+                                                                        // No field path specified, include full bill data
+                                                                        output.insert("bill".to_string(), metadata_value);
+                                                                    } else {
+                                                                        // Extract specific field(s) from bill data
+                                                                        if let Some(field_value) = extract_json_field(&metadata_value, field_path) {
+                                                                            // Use the full join path as the key (e.g., "bill.title")
+                                                                            let output_key = format!("{}.{}", dataset_name, field_path.join("."));
+                                                                            output.insert(output_key, field_value);
                                                                         } else {
-                                                                            // Extract specific field(s) from bill data
-                                                                            if let Some(field_value) = extract_json_field(&metadata_value, field_path) {
-                                                                                // Use the full join path as the key (e.g., "bill.title")
-                                                                                let output_key = format!("{}.{}", dataset_name, field_path.join("."));
-                                                                                output.insert(output_key, field_value);
-                                                                            } else {
-                                                                                eprintln!("Warning: Field path {:?} not found in metadata from {}", field_path, metadata_path.display());
-                                                                            }
+                                                                            stats.error(metadata_path, LogErrorKind::JoinFieldMissing, format!("field path {} not found", field_path.join(".")));
                                                                         }
-                                                                        
-                                                                        // Add bill source path
-                                                                        let bill_source_path = compute_relative_source_path(metadata_path, &git_dir);
-                                                                        sources.insert("bill".to_string(), serde_json::Value::String(bill_source_path));
-                                                                    }
-                                                                    Err(e) => {
-                                                                        eprintln!("Error parsing metadata JSON from {}: {}", metadata_path.display(), e);
                                                                     }
+                                                                    
+                                                                    // Add bill source path
+                                                                    let bill_source_path = compute_relative_source_path(metadata_path, &opts.git_dir);
+                                                                    sources.insert("bill".to_string(), serde_json::Value::String(bill_source_path));
+                                                                }
+                                                                Err(e) => {
+                                                                    stats.error(metadata_path, LogErrorKind::MetadataParse, e);
                                                                 }
                                                             }
-                                                            Err(e) => {
-                                                                eprintln!("Error reading metadata from {}: {}", metadata_path.display(), e);
-                                                            }
                                                         }
-                                                    } else {
-                                                        eprintln!("Warning: Metadata file does not exist: {}", metadata_path.display());
+                                                        Err(e) => {
+                                                            stats.error(metadata_path, LogErrorKind::MetadataRead, e);
+                                                        }
                                                     }
                                                 } else {
-                                                    eprintln!("Warning: Could not determine metadata path for log file: {}", relative_path);
+                                                    stats.error(metadata_path, LogErrorKind::MetadataMissing, "metadata.json does not exist");
                                                 }
+                                            } else {
+                                                stats.error(&path, LogErrorKind::MetadataMissing, "could not determine metadata path");
                                             }
-                                            _ => {
-                                                eprintln!("Warning: Unknown join dataset: {}", dataset_name);
-                                            }
+                                        }
+                                        _ => {
+                                            stats.error(&path, LogErrorKind::UnknownJoin, format!("unknown join dataset: {}", dataset_name));
                                         }
                                     }
-                                    
-                                    // Join tags if requested
-                                    if join_tags {
-                                        // Extract country, state, session_id from the path
-                                        if let Some((country, state, session_id)) = extract_path_info(&source_path_str) {
-                                            // Use bill_id extracted earlier
-                                            if let Some(ref bill_id) = bill_id_opt {
-                                                // Look for tags in cwd/country:us/state:{state}/sessions/{session_id}/tags/
-                                                let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-                                                let tags_dir = cwd
-                                                    .join(&format!("country:{}", country))
-                                                    .join(&format!("state:{}", state))
-                                                    .join("sessions")
-                                                    .join(&session_id)
-                                                    .join("tags");
-                                                
-                                                if tags_dir.exists() && tags_dir.is_dir() {
-                                                    let mut matched_tags = serde_json::Map::new();
-                                                    if let Ok(entries) = fs::read_dir(&tags_dir) {
-                                                        for entry in entries.flatten() {
-                                                            let path = entry.path();
-                                                            // Check for both .tag.json and .json files
-                                                            if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
-                                                                if ext == "json" {
-                                                                    if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                                                                        // Remove .tag suffix if present (e.g., "budget.tag" -> "budget")
-                                                                        let tag_name = stem.strip_suffix(".tag").unwrap_or(stem);
-                                                                        match fs::read_to_string(&path) {
-                                                                            Ok(contents) => {
-                                                                                if let Ok(tag_file) = serde_json::from_str::<govbot::TagFile>(&contents) {
-                                                                                    // Check if bill_id exists in bills map
-                                                                                    if let Some(bill_result) = tag_file.bills.get(bill_id) {
-                                                                                        // Return the score breakdown
-                                                                                        matched_tags.insert(tag_name.to_string(), serde_json::to_value(&bill_result.score).unwrap_or(serde_json::Value::Null));
-                                                                                    }
-                                                                                }
-                                                                            }
-                                                                            Err(_) => {}
-                                                                        }
-                                                                    }
+                                }
+                                
+                                // Join tags if requested
+                                if opts.join_tags {
+                                    // Extract country, state, session_id from the path
+                                    if let Some((country, state, session_id)) = extract_path_info(&source_path_str) {
+                                        // Use bill_id extracted earlier
+                                        if let Some(ref bill_id) = bill_id_opt {
+                                            // Look for tags in {govbot.yml dir}/country:us/state:{state}/sessions/{session_id}/tags/
+                                            let tags_dir = opts.tags_base_dir
+                                                .join(format!("country:{}", country))
+                                                .join(format!("state:{}", state))
+                                                .join("sessions")
+                                                .join(&session_id)
+                                                .join("tags");
+                                            
+                                            if tags_dir.exists() && tags_dir.is_dir() {
+                                                let mut matched_tags = serde_json::Map::new();
+                                                let mut tag_paths: Vec<PathBuf> = fs::read_dir(&tags_dir)
+                                                    .map(|entries| entries.flatten().map(|e| e.path()).collect())
+                                                    .unwrap_or_default();
+                                                // Sort so that, when both `x.tag.json` and `x.json` exist, `x.tag.json` is read last and wins
+                                                tag_paths.sort();
+                                                for path in tag_paths {
+                                                    // Check for both .tag.json and .json files
+                                                    if path.extension().and_then(|s| s.to_str()) != Some("json") {
+                                                        continue;
+                                                    }
+                                                    if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                                                        // Remove .tag suffix if present (e.g., "budget.tag" -> "budget")
+                                                        let tag_name = stem.strip_suffix(".tag").unwrap_or(stem);
+                                                        if let Ok(contents) = fs::read_to_string(&path) {
+                                                            if let Ok(tag_file) = serde_json::from_str::<govbot::TagFile>(&contents) {
+                                                                // Check if bill_id exists in bills map, under the raw id or
+                                                                // the key `tag --normalize-identifiers` would have written
+                                                                let bill_result = tag_file.bills.get(bill_id).or_else(|| {
+                                                                    tag_file.bills.get(&govbot::types::normalize_bill_identifier(bill_id))
+                                                                });
+                                                                if let Some(bill_result) = bill_result {
+                                                                    // Return the score breakdown
+                                                                    matched_tags.insert(tag_name.to_string(), serde_json::to_value(&bill_result.score).unwrap_or(serde_json::Value::Null));
                                                                 }
                                                             }
                                                         }
                                                     }
-                                                    if !matched_tags.is_empty() {
-                                                        output.insert("tags".to_string(), serde_json::Value::Object(matched_tags));
-                                                    }
+                                                }
+                                                if !matched_tags.is_empty() {
+                                                    output.insert("tags".to_string(), serde_json::Value::Object(matched_tags));
                                                 }
                                             }
                                         }
                                     }
+                                }
+                                
+                                output.insert("sources".to_string(), serde_json::Value::Object(sources));
+                                
+                                // Extract timestamp from sources.log path (after "logs/" and before "_")
+                                // Do this after sources is inserted so we can use the final sources.log value
+                                let timestamp = extract_timestamp_from_path(&source_path_str);
+                                if let Some(ref ts) = timestamp {
+                                    output.insert("timestamp".to_string(), serde_json::Value::String(ts.clone()));
+                                }
+                                
+                                let mut output_value = serde_json::Value::Object(output);
+                                
+                                // Apply select transformation if requested
+                                if opts.select == "default" {
+                                    // Select specific keys from nested objects, preserving structure
+                                    let mut selected_output = serde_json::Map::new();
                                     
-                                    output.insert("sources".to_string(), serde_json::Value::Object(sources));
-                                    
-                                    // Extract timestamp from sources.log path (after "logs/" and before "_")
-                                    // Do this after sources is inserted so we can use the final sources.log value
-                                    let timestamp = extract_timestamp_from_path(&source_path_str);
-                                    if let Some(ref ts) = timestamp {
-                                        output.insert("timestamp".to_string(), serde_json::Value::String(ts.clone()));
+                                    // Top: id (from log.bill_id), then log object with selected fields
+                                    if let Some(id) = govbot::types::extract_bill_identifier(&output_value) {
+                                        selected_output.insert("id".to_string(), serde_json::Value::String(id));
                                     }
                                     
-                                    let mut output_value = serde_json::Value::Object(output);
+                                    // Create log object with only action and bill_id
+                                    if let Some(log) = output_value.get("log") {
+                                        let mut log_obj = serde_json::Map::new();
+                                        if let Some(action) = log.get("action") {
+                                            log_obj.insert("action".to_string(), action.clone());
+                                        }
+                                        if let Some(bill_id) = log.get("bill_id").or_else(|| log.get("bill_identifier")) {
+                                            log_obj.insert("bill_id".to_string(), bill_id.clone());
+                                        }
+                                        if !log_obj.is_empty() {
+                                            selected_output.insert("log".to_string(), serde_json::Value::Object(log_obj));
+                                        }
+                                    }
                                     
-                                    // Apply select transformation if requested
-                                    if select == "default" {
-                                        // Select specific keys from nested objects, preserving structure
-                                        let mut selected_output = serde_json::Map::new();
-                                        
-                                        // Top: id (from log.bill_id), then log object with selected fields
-                                        if let Some(id) = output_value.get("log").and_then(|l| l.get("bill_id").or_else(|| l.get("bill_identifier"))).and_then(|v| v.as_str()) {
-                                            selected_output.insert("id".to_string(), serde_json::Value::String(id.to_string()));
+                                    // Create bill object with only selected fields
+                                    if let Some(bill) = output_value.get("bill") {
+                                        let mut bill_obj = serde_json::Map::new();
+                                        if let Some(title) = bill.get("title") {
+                                            bill_obj.insert("title".to_string(), title.clone());
                                         }
-                                        
-                                        // Create log object with only action and bill_id
-                                        if let Some(log) = output_value.get("log") {
-                                            let mut log_obj = serde_json::Map::new();
-                                            if let Some(action) = log.get("action") {
-                                                log_obj.insert("action".to_string(), action.clone());
-                                            }
-                                            if let Some(bill_id) = log.get("bill_id").or_else(|| log.get("bill_identifier")) {
-                                                log_obj.insert("bill_id".to_string(), bill_id.clone());
-                                            }
-                                            if !log_obj.is_empty() {
-                                                selected_output.insert("log".to_string(), serde_json::Value::Object(log_obj));
-                                            }
+                                        if let Some(abstracts) = bill.get("abstracts") {
+                                            bill_obj.insert("abstracts".to_string(), abstracts.clone());
                                         }
-                                        
-                                        // Create bill object with only selected fields
-                                        if let Some(bill) = output_value.get("bill") {
-                                            let mut bill_obj = serde_json::Map::new();
-                                            if let Some(title) = bill.get("title") {
-                                                bill_obj.insert("title".to_string(), title.clone());
-                                            }
-                                            if let Some(abstracts) = bill.get("abstracts") {
-                                                bill_obj.insert("abstracts".to_string(), abstracts.clone());
-                                            }
-                                            if let Some(subject) = bill.get("subject") {
-                                                bill_obj.insert("subject".to_string(), subject.clone());
-                                            }
-                                            if let Some(identifier) = bill.get("identifier") {
-                                                bill_obj.insert("identifier".to_string(), identifier.clone());
-                                            }
-                                            if let Some(session) = bill.get("legislative_session") {
-                                                bill_obj.insert("legislative_session".to_string(), session.clone());
-                                            }
-                                            if let Some(org) = bill.get("from_organization") {
-                                                bill_obj.insert("from_organization".to_string(), org.clone());
-                                            }
-                                            if !bill_obj.is_empty() {
-                                                selected_output.insert("bill".to_string(), serde_json::Value::Object(bill_obj));
-                                            }
+                                        if let Some(subject) = bill.get("subject") {
+                                            bill_obj.insert("subject".to_string(), subject.clone());
+                                        }
+                                        if let Some(identifier) = bill.get("identifier") {
+                                            bill_obj.insert("identifier".to_string(), identifier.clone());
                                         }
-                                        
-                                        // Always include tags (even if empty/null) since it's part of the default selector
-                                        if let Some(tags) = output_value.get("tags") {
-                                            selected_output.insert("tags".to_string(), tags.clone());
-                                        } else {
-                                            // Include empty tags object if not present
-                                            selected_output.insert("tags".to_string(), serde_json::Value::Null);
+                                        if let Some(session) = bill.get("legislative_session") {
+                                            bill_obj.insert("legislative_session".to_string(), session.clone());
                                         }
-                                        
-                                        // Bottom: sources, timestamp
-                                        if let Some(sources) = output_value.get("sources") {
-                                            selected_output.insert("sources".to_string(), sources.clone());
+                                        if let Some(org) = bill.get("from_organization") {
+                                            bill_obj.insert("from_organization".to_string(), org.clone());
                                         }
-                                        if let Some(timestamp) = output_value.get("timestamp") {
-                                            selected_output.insert("timestamp".to_string(), timestamp.clone());
+                                        if !bill_obj.is_empty() {
+                                            selected_output.insert("bill".to_string(), serde_json::Value::Object(bill_obj));
                                         }
-                                        
-                                        output_value = serde_json::Value::Object(selected_output);
                                     }
                                     
-                                    // Apply filter
-                                    let should_output = match filter_manager.should_keep(&output_value, &repo_name) {
-                                        govbot::FilterResult::Keep => true,
-                                        govbot::FilterResult::FilterOut => false,
+                                    // Always include tags (even if empty/null) since it's part of the default selector
+                                    if let Some(tags) = output_value.get("tags") {
+                                        selected_output.insert("tags".to_string(), tags.clone());
+                                    } else {
+                                        // Include empty tags object if not present
+                                        selected_output.insert("tags".to_string(), serde_json::Value::Null);
+                                    }
+                                    
+                                    // Bottom: sources, timestamp
+                                    if let Some(sources) = output_value.get("sources") {
+                                        selected_output.insert("sources".to_string(), sources.clone());
+                                    }
+                                    if let Some(timestamp) = output_value.get("timestamp") {
+                                        selected_output.insert("timestamp".to_string(), timestamp.clone());
+                                    }
+                                    
+                                    output_value = serde_json::Value::Object(selected_output);
+                                }
+                                
+                                // Apply filter
+                                let should_output = match opts.filter_manager.should_keep(&output_value, repo_name) {
+                                    govbot::FilterResult::Keep => true,
+                                    govbot::FilterResult::FilterOut => false,
+                                };
+                                
+                                if should_output && opts.count_only {
+                                    if emit(canonical, LogRecord::Counted) {
+                                        file_count += 1;
+                                    }
+                                } else if should_output {
+                                    // Deep prune empty/null values before serialization (unless --no-prune)
+                                    let pruned_value = if opts.no_prune {
+                                        output_value
+                                    } else {
+                                        deep_prune_json(output_value)
                                     };
                                     
-                                    if should_output {
-                                        // Deep prune empty/null values before serialization
-                                        let pruned_value = deep_prune_json(output_value);
-                                        
-                                        // Serialize as compact JSON (single line)
-                                        match serde_json::to_string(&pruned_value) {
-                                            Ok(json_line) => {
-                                                // Ignore broken pipe errors (e.g., when piped to yq/jq that closes early)
-                                                if write_json_line(&json_line).is_ok() {
+                                    if opts.table {
+                                        if emit(canonical, LogRecord::Row(log_table_row(&pruned_value))) {
+                                            file_count += 1;
+                                        }
+                                        continue;
+                                    }
+
+                                    if let Some(geometry) = &opts.geometry {
+                                        match log_geojson_feature(&pruned_value, &source_path_str, geometry) {
+                                            Some(feature) => {
+                                                if emit(canonical, LogRecord::Feature(feature)) {
                                                     file_count += 1;
                                                 }
                                             }
-                                            Err(e) => {
-                                                eprintln!("Error serializing JSON from {}: {}", path.display(), e);
+                                            None => stats.no_geometry += 1,
+                                        }
+                                        continue;
+                                    }
+
+                                    let pruned_value = match &opts.fields {
+                                        Some(fields) => project_fields(&pruned_value, fields),
+                                        None => pruned_value,
+                                    };
+
+                                    // Serialize as compact JSON (single line), indented with --pretty,
+                                    // or as one YAML document
+                                    let serialized = if opts.yaml {
+                                        serde_yaml::to_string(&pruned_value)
+                                            .map(|doc| format!("---\n{}", doc))
+                                            .map_err(|e| e.to_string())
+                                    } else if opts.pretty {
+                                        serde_json::to_string_pretty(&pruned_value).map_err(|e| e.to_string())
+                                    } else {
+                                        serde_json::to_string(&pruned_value).map_err(|e| e.to_string())
+                                    };
+                                    match serialized {
+                                        Ok(json_line) => {
+                                            if emit(canonical, LogRecord::Line(json_line)) {
+                                                file_count += 1;
                                             }
                                         }
+                                        Err(e) => {
+                                            stats.error(&path, LogErrorKind::Serialize, format!("{}: {}", opts.format, e));
+                                        }
                                     }
-                                }
-                                Err(e) => {
-                                    eprintln!("Error parsing JSON from {}: {}", path.display(), e);
+                                } else {
+                                    stats.skipped += 1;
                                 }
                             }
-                        }
-                        Err(e) => {
-                            eprintln!("Error reading {}: {}", path.display(), e);
+                            Err(e) => {
+                                stats.error(&path, LogErrorKind::Parse, e);
+                                stats.parse_errors += 1;
+                            }
                         }
                     }
+                    Err(e) => {
+                        stats.error(&path, LogErrorKind::Read, e);
+                        stats.parse_errors += 1;
+                    }
                 }
             }
         }
     }
+    if opts.include_empty_sessions {
+        for session_dir in govbot::processor::find_empty_sessions(repo_path) {
+            let source = compute_relative_source_path(&session_dir, &opts.git_dir);
+            let Some((country, state, session)) = extract_path_info(&source) else {
+                continue;
+            };
+            if !opts.path_allowed(&country, &state, &session) {
+                continue;
+            }
+            let marker = serde_json::json!({
+                "empty_session": { "country": country, "state": state, "session": session },
+                "sources": { "session": source },
+            });
+            let serialized = if opts.yaml {
+                serde_yaml::to_string(&marker).map(|doc| format!("---\n{}", doc)).map_err(|e| e.to_string())
+            } else if opts.pretty {
+                serde_json::to_string_pretty(&marker).map_err(|e| e.to_string())
+            } else {
+                serde_json::to_string(&marker).map_err(|e| e.to_string())
+            };
+            match serialized {
+                Ok(line) => {
+                    emit(session_dir, LogRecord::Marker(line));
+                }
+                Err(e) => stats.error(&session_dir, LogErrorKind::Serialize, format!("{} marker: {}", opts.format, e)),
+            }
+        }
+    }
 
-    Ok(())
+    stats.emitted = file_count;
+    stats
 }
 
-
 /// Parse a join string like "bill.title" into (dataset_name, field_path)
 fn parse_join_string(join_str: &str) -> Option<(String, Vec<String>)> {
     let parts: Vec<&str> = join_str.split('.').collect();
@@ -1146,27 +2238,100 @@ fn parse_join_string(join_str: &str) -> Option<(String, Vec<String>)> {
     Some((dataset_name, field_path))
 }
 
-/// Extract a value from JSON using a field path (e.g., ["title"] or ["bill", "title"])
-fn extract_json_field(value: &serde_json::Value, field_path: &[String]) -> Option<serde_json::Value> {
-    let mut current = value;
-    
-    for field in field_path {
-        match current {
-            serde_json::Value::Object(map) => {
-                current = map.get(field)?;
-            }
-            serde_json::Value::Array(arr) => {
-                if let Ok(idx) = field.parse::<usize>() {
-                    current = arr.get(idx)?;
-                } else {
-                    return None;
-                }
-            }
-            _ => return None,
+/// Longest `bill.title` shown by `logs --format table`; longer titles end in `...`
+const TABLE_TITLE_WIDTH: usize = 60;
+
+/// Columns `logs --format table` shows for one entry: id, title, tags, timestamp
+fn log_table_row(entry: &serde_json::Value) -> [String; 4] {
+    let id = govbot::types::extract_bill_identifier(entry).unwrap_or_default();
+    let title = entry
+        .get("bill")
+        .and_then(|b| b.get("title"))
+        .and_then(|t| t.as_str())
+        .unwrap_or_default();
+    let title = if title.chars().count() > TABLE_TITLE_WIDTH {
+        format!("{}...", title.chars().take(TABLE_TITLE_WIDTH - 3).collect::<String>())
+    } else {
+        title.to_string()
+    };
+    let mut tags: Vec<&str> = match entry.get("tags") {
+        Some(serde_json::Value::Object(tags)) => tags.keys().map(String::as_str).collect(),
+        Some(serde_json::Value::Array(tags)) => tags.iter().filter_map(|t| t.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    tags.sort();
+    let timestamp = entry.get("timestamp").and_then(|t| t.as_str()).unwrap_or_default();
+    [id, title, tags.join(", "), timestamp.to_string()]
+}
+
+/// Read a `--geometry` file: a JSON object of jurisdiction -> GeoJSON geometry
+fn load_geometry(path: &Path) -> anyhow::Result<HashMap<String, serde_json::Value>> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read --geometry {}: {}", path.display(), e))?;
+    let geometry: HashMap<String, serde_json::Value> = serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("--geometry {} must be a JSON object of geometries: {}", path.display(), e))?;
+    for (jurisdiction, shape) in &geometry {
+        if shape.get("type").and_then(|t| t.as_str()).is_none() {
+            anyhow::bail!("--geometry {}: `{}` is not a GeoJSON geometry (no `type`)", path.display(), jurisdiction);
         }
     }
-    
-    Some(current.clone())
+    Ok(geometry.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect())
+}
+
+/// GeoJSON feature placing an entry at its state's geometry (else its country's); None when neither is known
+fn log_geojson_feature(
+    entry: &serde_json::Value,
+    source_path: &str,
+    geometry: &HashMap<String, serde_json::Value>,
+) -> Option<serde_json::Value> {
+    let (country, state, session) = extract_path_info(source_path)?;
+    let shape = geometry
+        .get(&state.to_lowercase())
+        .or_else(|| geometry.get(&country.to_lowercase()))?;
+    let id = govbot::types::extract_bill_identifier(entry);
+    let title = entry.get("bill").and_then(|b| b.get("title")).and_then(|t| t.as_str());
+    let mut tags: Vec<&str> = match entry.get("tags") {
+        Some(serde_json::Value::Object(tags)) => tags.keys().map(String::as_str).collect(),
+        _ => Vec::new(),
+    };
+    tags.sort();
+    Some(serde_json::json!({
+        "type": "Feature",
+        "geometry": shape,
+        "properties": {
+            "id": id,
+            "title": title,
+            "tags": tags,
+            "jurisdiction": state,
+            "session": session,
+            "timestamp": entry.get("timestamp"),
+        },
+    }))
+}
+
+/// Header, rule, and one aligned line per row
+fn render_log_table(rows: &[[String; 4]]) -> Vec<String> {
+    const HEADER: [&str; 4] = ["ID", "TITLE", "TAGS", "TIMESTAMP"];
+    let mut widths = HEADER.map(|h| h.len());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: [&str; 4]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![line(HEADER), line(widths.map(|w| "-".repeat(w)).each_ref().map(String::as_str))];
+    lines.extend(rows.iter().map(|row| line(row.each_ref().map(String::as_str))));
+    lines
 }
 
 /// Deep prune JSON value by removing null, empty strings, empty arrays, and empty objects
@@ -1258,22 +2423,90 @@ fn compute_relative_source_path(file_path: &PathBuf, git_dir: &PathBuf) -> Strin
     }
 }
 
+async fn run_diff_command(cmd: Command) -> anyhow::Result<()> {
+    let Command::Diff {
+        repos,
+        since_ref,
+        govbot_dir,
+    } = cmd else {
+        unreachable!()
+    };
+
+    let git_dir = get_govbot_dir(govbot_dir)?;
+    let since_ref = since_ref.unwrap_or_else(|| git::DEFAULT_SINCE_REF.to_string());
+
+    // Parse comma-separated repos if provided as single string
+    let repo_list: Vec<String> = if repos.len() == 1 && repos[0].contains(',') {
+        repos[0]
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    } else {
+        repos
+    };
+
+    // Default to all cloned repos
+    let locales = if repo_list.is_empty() || repo_list.iter().any(|r| r.eq_ignore_ascii_case("all")) {
+        git::get_available_locales(&git_dir).map_err(|e| anyhow::anyhow!("{}", e))?
+    } else {
+        repo_list.iter().map(|r| r.trim().to_lowercase()).collect()
+    };
+
+    for locale in locales {
+        let repo_path = git_dir.join(git::build_repo_name(&locale));
+        if !repo_path.exists() {
+            eprintln!("Warning: Repository not found: {}", repo_path.display());
+            continue;
+        }
+
+        match git::changed_log_files(&repo_path, &since_ref) {
+            Ok(paths) => {
+                for path in paths {
+                    write_json_line(&repo_path.join(path).to_string_lossy())?;
+                }
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to diff {}: {}", repo_path.display(), e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Where DuckDB install instructions live
+const DUCKDB_INSTALL_URL: &str = "https://duckdb.org/docs/installation/";
+
+/// `duckdb --version` output, or `None` if the DuckDB CLI isn't installed
+fn duckdb_version() -> Option<String> {
+    let output = ProcessCommand::new("duckdb").arg("--version").output().ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn print_duckdb_missing() {
+    eprintln!("Error: 'duckdb' command not found.");
+    eprintln!("Please install DuckDB: {}", DUCKDB_INSTALL_URL);
+}
+
 async fn run_load_command(cmd: Command) -> anyhow::Result<()> {
     let Command::Load {
         database,
+        format,
         govbot_dir,
         memory_limit,
         threads,
+        since_commit,
     } = cmd else {
         unreachable!()
     };
 
     let repos_dir = get_govbot_dir(govbot_dir)?;
 
-    // Check if directory exists
-    if !repos_dir.exists() {
-        eprintln!("Error: Govbot repos directory not found: {}", repos_dir.display());
-        eprintln!("Run 'govbot clone all' first to clone repositories.");
+    // Same check as `govbot doctor`: the repos must be cloned and writable
+    let (status, detail) = check_govbot_dir(&repos_dir);
+    if status == CheckStatus::Fail {
+        eprintln!("Error: {}", detail);
         return Ok(());
     }
 
@@ -1285,14 +2518,14 @@ async fn run_load_command(cmd: Command) -> anyhow::Result<()> {
     // Ensure base directory exists
     std::fs::create_dir_all(base_govbot_dir)?;
 
-    // Check if duckdb is available
-    let duckdb_check = ProcessCommand::new("duckdb")
-        .arg("--version")
-        .output();
+    let sqlite = format == "sqlite";
+    let database = database.unwrap_or_else(|| {
+        if sqlite { "govbot.sqlite" } else { "govbot.duckdb" }.to_string()
+    });
 
-    if duckdb_check.is_err() {
-        eprintln!("Error: 'duckdb' command not found.");
-        eprintln!("Please install DuckDB: https://duckdb.org/docs/installation/");
+    // Check if duckdb is available
+    if !sqlite && duckdb_version().is_none() {
+        print_duckdb_missing();
         return Ok(());
     }
 
@@ -1303,21 +2536,66 @@ async fn run_load_command(cmd: Command) -> anyhow::Result<()> {
         .join(&database);
     let db_path_str = db_path.to_string_lossy().to_string();
 
-    // Remove existing database if it exists
-    if db_path.exists() {
-        eprintln!("Removing existing database: {}", db_path.display());
-        std::fs::remove_file(&db_path)?;
+    // Incremental mode: metadata files changed or deleted since the ref, or None to reload everything
+    let changes = match since_commit {
+        Some(ref since) if db_path.exists() => changed_metadata_since(&repos_dir, since),
+        Some(_) => {
+            eprintln!("Database not found: {}; running a full load", db_path.display());
+            None
+        }
+        None => None,
+    };
+    if changes.as_ref().is_some_and(|c| c.changed.is_empty() && c.deleted.is_empty()) {
+        eprintln!("No bill metadata changed since {}", since_commit.as_deref().unwrap_or_default());
+        return Ok(());
+    }
+
+    if let Some(ref changes) = changes {
+        eprintln!(
+            "Updating {} bills and removing {} in {}...",
+            changes.changed.len(),
+            changes.deleted.len(),
+            db_path.display()
+        );
+    } else {
+        // Remove existing database if it exists
+        if db_path.exists() {
+            eprintln!("Removing existing database: {}", db_path.display());
+            std::fs::remove_file(&db_path)?;
+        }
+
+        eprintln!("Loading data into {}...", db_path.display());
+        eprintln!("This may take a few minutes depending on the number of files...");
     }
 
-    eprintln!("Loading data into {}...", db_path.display());
-    eprintln!("This may take a few minutes depending on the number of files...");
+    if sqlite {
+        let count = match changes {
+            Some(ref changes) => {
+                let count = govbot::sqlite::load_bills_with_deletions(&db_path, &changes.changed, &changes.deleted)?;
+                println!("Bills updated: {}", changes.changed.len());
+                println!("Bills removed: {}", changes.deleted.len());
+                count
+            }
+            None => govbot::sqlite::load_bills(&db_path, &govbot::sqlite::find_metadata_files(&repos_dir))?,
+        };
+        println!("Bills loaded: {}", count);
+
+        let verb = if changes.is_some() { "updated" } else { "created" };
+        eprintln!("\n{} Database {}: {}", icon("✅", "ok"), verb, db_path.display());
+        eprintln!("\nQuery from command line:");
+        eprintln!("  sqlite3 {}", db_path.display());
+        eprintln!("\nAvailable tables:");
+        eprintln!("  - bills (bill metadata from metadata.json files; nested values as JSON text)");
+        eprintln!("  - bills_summary (summary view)");
+        return Ok(());
+    }
 
     // Create SQL script
     let mut sql_script = String::new();
     sql_script.push_str("-- Load JSON extension\n");
     sql_script.push_str("INSTALL json;\n");
     sql_script.push_str("LOAD json;\n");
-    sql_script.push_str("\n");
+    sql_script.push('\n');
 
     // Set memory limit if provided
     if let Some(ref mem_limit) = memory_limit {
@@ -1331,39 +2609,74 @@ async fn run_load_command(cmd: Command) -> anyhow::Result<()> {
     let num_threads = threads.unwrap_or(4);
     sql_script.push_str(&format!("SET threads={};\n", num_threads));
     sql_script.push_str("SET preserve_insertion_order=false;\n");
-    sql_script.push_str("\n");
-
-    // Create table from metadata.json files
-    let repos_dir_str = repos_dir.to_string_lossy();
-    sql_script.push_str("-- Create table from metadata.json files only\n");
-    sql_script.push_str("-- Using union_by_name to handle schema variations across files\n");
-    sql_script.push_str("CREATE TABLE bills AS\n");
-    sql_script.push_str("SELECT \n");
-    sql_script.push_str("    *,\n");
-    sql_script.push_str("    filename as source_file\n");
-    sql_script.push_str(&format!("FROM read_json_auto('{}/**/bills/*/metadata.json', \n", repos_dir_str));
-    sql_script.push_str("    filename=true, \n");
-    sql_script.push_str("    union_by_name=true);\n");
-    sql_script.push_str("\n");
-
-    // Create summary view
-    sql_script.push_str("-- Create some useful views\n");
-    sql_script.push_str("CREATE VIEW bills_summary AS\n");
-    sql_script.push_str("SELECT \n");
-    sql_script.push_str("    identifier,\n");
-    sql_script.push_str("    title,\n");
-    sql_script.push_str("    legislative_session,\n");
-    sql_script.push_str("    jurisdiction->>'id' as jurisdiction_id,\n");
-    sql_script.push_str("    jurisdiction->>'name' as jurisdiction_name,\n");
-    sql_script.push_str("    json_array_length(actions) as action_count,\n");
-    sql_script.push_str("    json_array_length(sponsorships) as sponsor_count,\n");
-    sql_script.push_str("    source_file\n");
-    sql_script.push_str("FROM bills;\n");
-    sql_script.push_str("\n");
-
-    // Show summary
-    sql_script.push_str("-- Show summary\n");
-    sql_script.push_str("SELECT 'Bills loaded:' as info, COUNT(*) as count FROM bills;\n");
+    sql_script.push('\n');
+
+    if let Some(ref changes) = changes {
+        // Replace the changed bills' rows and drop the deleted ones, keyed by source file (the
+        // table has no primary key for INSERT OR REPLACE). Paths are built from repos_dir exactly
+        // as the full load's glob.
+        let sql_list = |files: &[PathBuf]| {
+            files
+                .iter()
+                .map(|f| format!("'{}'", f.to_string_lossy().replace('\'', "''")))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let stale = sql_list(&[changes.changed.as_slice(), changes.deleted.as_slice()].concat());
+        if changes.changed.is_empty() {
+            sql_script.push_str("-- Remove bills whose metadata.json was deleted\n");
+            sql_script.push_str(&format!("DELETE FROM bills WHERE source_file IN ({});\n", stale));
+        } else {
+            // Rebuild with UNION ALL BY NAME so columns only the changed files have are added,
+            // as union_by_name does for the full load (INSERT ... BY NAME rejects them)
+            sql_script.push_str("-- Replace bills whose metadata.json changed or was deleted\n");
+            sql_script.push_str("CREATE OR REPLACE TABLE bills AS\n");
+            sql_script.push_str(&format!("SELECT * FROM bills WHERE source_file NOT IN ({})\n", stale));
+            sql_script.push_str("UNION ALL BY NAME\n");
+            sql_script.push_str("SELECT \n");
+            sql_script.push_str("    *,\n");
+            sql_script.push_str("    filename as source_file\n");
+            sql_script.push_str(&format!("FROM read_json_auto([{}], \n", sql_list(&changes.changed)));
+            sql_script.push_str("    filename=true, \n");
+            sql_script.push_str("    union_by_name=true);\n");
+        }
+        sql_script.push('\n');
+        sql_script.push_str(&format!("SELECT 'Bills updated:' as info, {} as count;\n", changes.changed.len()));
+        sql_script.push_str(&format!("SELECT 'Bills removed:' as info, {} as count;\n", changes.deleted.len()));
+        sql_script.push_str("SELECT 'Bills loaded:' as info, COUNT(*) as count FROM bills;\n");
+    } else {
+        // Create table from metadata.json files
+        let repos_dir_str = repos_dir.to_string_lossy();
+        sql_script.push_str("-- Create table from metadata.json files only\n");
+        sql_script.push_str("-- Using union_by_name to handle schema variations across files\n");
+        sql_script.push_str("CREATE TABLE bills AS\n");
+        sql_script.push_str("SELECT \n");
+        sql_script.push_str("    *,\n");
+        sql_script.push_str("    filename as source_file\n");
+        sql_script.push_str(&format!("FROM read_json_auto('{}/**/bills/*/metadata.json', \n", repos_dir_str));
+        sql_script.push_str("    filename=true, \n");
+        sql_script.push_str("    union_by_name=true);\n");
+        sql_script.push('\n');
+
+        // Create summary view
+        sql_script.push_str("-- Create some useful views\n");
+        sql_script.push_str("CREATE VIEW bills_summary AS\n");
+        sql_script.push_str("SELECT \n");
+        sql_script.push_str("    identifier,\n");
+        sql_script.push_str("    title,\n");
+        sql_script.push_str("    legislative_session,\n");
+        sql_script.push_str("    jurisdiction->>'id' as jurisdiction_id,\n");
+        sql_script.push_str("    jurisdiction->>'name' as jurisdiction_name,\n");
+        sql_script.push_str("    json_array_length(actions) as action_count,\n");
+        sql_script.push_str("    json_array_length(sponsorships) as sponsor_count,\n");
+        sql_script.push_str("    source_file\n");
+        sql_script.push_str("FROM bills;\n");
+        sql_script.push('\n');
+
+        // Show summary
+        sql_script.push_str("-- Show summary\n");
+        sql_script.push_str("SELECT 'Bills loaded:' as info, COUNT(*) as count FROM bills;\n");
+    }
 
     // Run duckdb as subprocess
     let mut duckdb_cmd = ProcessCommand::new("duckdb");
@@ -1395,7 +2708,8 @@ async fn run_load_command(cmd: Command) -> anyhow::Result<()> {
         print!("{}", stdout);
     }
 
-    eprintln!("\n✅ Database created: {}", db_path.display());
+    let verb = if changes.is_some() { "updated" } else { "created" };
+    eprintln!("\n{} Database {}: {}", icon("✅", "ok"), verb, db_path.display());
     eprintln!("\nTo open in DuckDB UI, run:");
     eprintln!("  duckdb --ui {}", db_path.display());
     eprintln!("\nOr query from command line:");
@@ -1407,87 +2721,566 @@ async fn run_load_command(cmd: Command) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Extract country, state, and session_id from a log path
-/// Path format: .../country:us/state:il/sessions/104th/bills/...
-fn extract_path_info(path: &str) -> Option<(String, String, String)> {
-    // Find country: pattern
-    let country_start = path.find("country:")?;
-    let country_end = path[country_start + 8..].find('/').unwrap_or(path.len() - country_start - 8);
-    let country = path[country_start + 8..country_start + 8 + country_end].to_string();
-    
-    // Find state: pattern
-    let state_start = path.find("/state:")?;
-    let state_end = path[state_start + 7..].find('/').unwrap_or(path.len() - state_start - 7);
-    let state = path[state_start + 7..state_start + 7 + state_end].to_string();
-    
-    // Find sessions/ pattern
-    let sessions_start = path.find("/sessions/")?;
-    let session_end = path[sessions_start + 10..].find('/').unwrap_or(path.len() - sessions_start - 10);
-    let session_id = path[sessions_start + 10..sessions_start + 10 + session_end].to_string();
-    
-    Some((country, state, session_id))
+/// Bill metadata files changed and deleted since a ref, as paths under the repos directory
+struct MetadataChanges {
+    changed: Vec<PathBuf>,
+    deleted: Vec<PathBuf>,
+}
+
+/// Bill metadata files changed and deleted since `since_ref` across every repo in `repos_dir`,
+/// or None (with a warning) when any repo can't resolve the ref and a full load is needed
+fn changed_metadata_since(repos_dir: &Path, since_ref: &str) -> Option<MetadataChanges> {
+    let mut repo_paths: Vec<PathBuf> = fs::read_dir(repos_dir)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    repo_paths.sort();
+
+    let mut changes = MetadataChanges {
+        changed: Vec::new(),
+        deleted: Vec::new(),
+    };
+    for repo_path in repo_paths {
+        let diff = git::changed_metadata_files(&repo_path, since_ref)
+            .and_then(|changed| Ok((changed, git::deleted_metadata_files(&repo_path, since_ref)?)));
+        match diff {
+            Ok((changed, deleted)) => {
+                changes.changed.extend(changed.into_iter().map(|p| repo_path.join(p)));
+                changes.deleted.extend(deleted.into_iter().map(|p| repo_path.join(p)));
+            }
+            Err(e) => {
+                eprintln!("Warning: {}: {}; running a full load", repo_path.display(), e);
+                return None;
+            }
+        }
+    }
+    Some(changes)
+}
+
+/// Tag files under `tags_root` as (path, tag name, country, state, session), in path order
+fn find_tag_files(tags_root: &Path) -> Vec<(PathBuf, String, String, String, String)> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(tags_root).sort(true).into_iter().flatten() {
+        let path = entry.path();
+        let in_tags_dir = path.parent().and_then(|p| p.file_name()).is_some_and(|n| n == "tags");
+        if !in_tags_dir || path.extension().and_then(|s| s.to_str()) != Some("json") {
+            continue;
+        }
+        let Some((country, state, session)) = extract_path_info(&path.to_string_lossy()) else {
+            continue;
+        };
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        // Remove .tag suffix if present (e.g., "budget.tag" -> "budget")
+        let tag_name = stem.strip_suffix(".tag").unwrap_or(stem).to_string();
+        files.push((path, tag_name, country, state, session));
+    }
+    files
+}
+
+/// One export record per (bill, tag) from every tag file under `tags_root`,
+/// sorted by state, session, tag, and bill
+fn collect_tag_rows(tags_root: &Path) -> Vec<serde_json::Value> {
+    let mut rows = Vec::new();
+    for (path, tag_name, country, state, session) in find_tag_files(tags_root) {
+        // Same reading as check_existing_tags: unreadable files are skipped, older ones migrated
+        match govbot::embeddings::load_tag_file(&path) {
+            Ok(tag_file) => {
+                for (bill_id, result) in tag_file.bills {
+                    rows.push(serde_json::json!({
+                        "bill_id": bill_id,
+                        "tag": tag_name,
+                        "final_score": result.score.final_score,
+                        "text_hash": result.text_hash,
+                        "country": country,
+                        "state": state,
+                        "session": session,
+                    }));
+                }
+            }
+            Err(e) => eprintln!("Warning: Skipping {}: {}", path.display(), e),
+        }
+    }
+
+    let key = |row: &serde_json::Value| {
+        ["state", "session", "tag", "bill_id"].map(|k| row[k].as_str().unwrap_or_default().to_string())
+    };
+    rows.sort_by_key(key);
+    rows
+}
+
+fn run_export_command(cmd: Command) -> anyhow::Result<()> {
+    let Command::Export { format, output, tags_dir } = cmd else {
+        unreachable!()
+    };
+
+    let tags_root = match tags_dir {
+        Some(dir) => dir,
+        None => config_path()?
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or(std::env::current_dir()?),
+    };
+    if !tags_root.is_dir() {
+        return Err(anyhow::anyhow!("Tags directory not found: {}", tags_root.display()));
+    }
+
+    let rows = collect_tag_rows(&tags_root);
+    let ndjson: String = rows.iter().map(|row| format!("{}\n", row)).collect();
+
+    if format == "parquet" {
+        let output = output.as_deref().ok_or_else(|| anyhow::anyhow!("--format parquet requires --output"))?;
+        if duckdb_version().is_none() {
+            print_duckdb_missing();
+            return Err(anyhow::anyhow!("duckdb is required for --format parquet"));
+        }
+
+        // DuckDB converts the NDJSON rows; an empty export still gets the full schema
+        let staging = tempfile_path(output, "ndjson");
+        fs::write(&staging, &ndjson)?;
+        let columns = "{bill_id: 'VARCHAR', tag: 'VARCHAR', final_score: 'DOUBLE', text_hash: 'VARCHAR', \
+                       country: 'VARCHAR', state: 'VARCHAR', session: 'VARCHAR'}";
+        let sql = format!(
+            "COPY (SELECT * FROM read_json('{}', format='newline_delimited', columns={})) TO '{}' (FORMAT PARQUET);\n",
+            staging.to_string_lossy().replace('\'', "''"),
+            columns,
+            output.to_string_lossy().replace('\'', "''"),
+        );
+        let result = ProcessCommand::new("duckdb").arg("-c").arg(&sql).output();
+        let _ = fs::remove_file(&staging);
+        let result = result?;
+        if !result.status.success() {
+            eprintln!("{}", String::from_utf8_lossy(&result.stderr));
+            return Err(anyhow::anyhow!("DuckDB command failed"));
+        }
+    } else if let Some(ref path) = output {
+        fs::write(path, &ndjson)?;
+    } else {
+        let mut stdout = io::stdout().lock();
+        if let Err(e) = stdout.write_all(ndjson.as_bytes()) {
+            if e.kind() != io::ErrorKind::BrokenPipe {
+                return Err(e.into());
+            }
+        }
+    }
+
+    if let Some(ref path) = output {
+        eprintln!("{} Exported {} bill/tag records to {}", icon("✓", "ok"), rows.len(), path.display());
+    }
+    Ok(())
 }
 
-/// Download a file from a URL to a local path
-fn download_file(url: &str, path: &std::path::Path) -> anyhow::Result<()> {
-    eprintln!("Downloading {}...", url);
-    let response = reqwest::blocking::get(url)?;
-    if !response.status().is_success() {
-        return Err(anyhow::anyhow!("Failed to download {}: HTTP {}", url, response.status()));
+fn run_list_tags_command(cmd: Command) -> anyhow::Result<()> {
+    let Command::ListTags { tags_dir } = cmd else {
+        unreachable!()
+    };
+
+    let config_path = require_config_path()?;
+    let configured = govbot::embeddings::load_tags_config(&config_path)?;
+    let tags_root = match tags_dir {
+        Some(dir) => dir,
+        None => config_path.parent().map(Path::to_path_buf).unwrap_or(std::env::current_dir()?),
+    };
+
+    // Per tag: (configured threshold, sessions with a tag file, tagged bills)
+    let mut tags: BTreeMap<String, (Option<f32>, usize, usize)> = configured
+        .iter()
+        .map(|tag| (tag.name.clone(), (Some(tag.threshold), 0, 0)))
+        .collect();
+    for (path, tag_name, ..) in find_tag_files(&tags_root) {
+        match govbot::embeddings::load_tag_file(&path) {
+            Ok(tag_file) => {
+                let counts = tags.entry(tag_name).or_insert((None, 0, 0));
+                counts.1 += 1;
+                counts.2 += tag_file.bills.len();
+            }
+            Err(e) => eprintln!("Warning: Skipping {}: {}", path.display(), e),
+        }
+    }
+
+    let mut rows: Vec<[String; 5]> = vec![["TAG", "THRESHOLD", "SESSIONS", "BILLS", ""].map(String::from)];
+    for (name, (threshold, sessions, bills)) in &tags {
+        let note = match threshold {
+            None => format!("{} not in govbot.yml", icon("⚠️", "warn")),
+            Some(_) if *bills == 0 => format!("{} no matches", icon("⚠️", "warn")),
+            Some(_) => String::new(),
+        };
+        let threshold = threshold.map(|t| format!("{:.2}", t)).unwrap_or_else(|| "-".to_string());
+        rows.push([name.clone(), threshold, sessions.to_string(), bills.to_string(), note]);
+    }
+    let widths: Vec<usize> = (0..4)
+        .map(|col| rows.iter().map(|row| row[col].chars().count()).max().unwrap_or(0))
+        .collect();
+    for row in &rows {
+        let line = format!(
+            "{:<w0$}  {:>w1$}  {:>w2$}  {:>w3$}  {}",
+            row[0], row[1], row[2], row[3], row[4],
+            w0 = widths[0], w1 = widths[1], w2 = widths[2], w3 = widths[3],
+        );
+        println!("{}", line.trim_end());
     }
-    let mut file = std::fs::File::create(path)?;
-    std::io::copy(&mut response.bytes()?.as_ref(), &mut file)?;
     Ok(())
 }
 
-/// Ensure embedding model and tokenizer exist; if missing, download them from Hugging Face.
+/// Rough disk footprint of `clone all` at the default clone depth, for `doctor`'s disk check
+const FULL_CLONE_ESTIMATE_BYTES: u64 = 20 * 1024 * 1024 * 1024;
+
+/// Outcome of one `doctor` check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn marker(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => icon("✅", "pass"),
+            CheckStatus::Warn => icon("⚠️", "warn"),
+            CheckStatus::Fail => icon("❌", "fail"),
+        }
+    }
+}
+
+async fn run_doctor_command(cmd: Command) -> anyhow::Result<()> {
+    let Command::Doctor { govbot_dir, offline } = cmd else {
+        unreachable!()
+    };
+
+    let mut checks: Vec<(&str, CheckStatus, String)> = Vec::new();
+    let govbot_dir = match GovbotEnv::resolve(govbot_dir.as_deref(), None, None) {
+        Ok(env) => Some(env.govbot_dir),
+        Err(e) => {
+            checks.push(("govbot dir", CheckStatus::Fail, e.to_string()));
+            None
+        }
+    };
+    if let Some(dir) = &govbot_dir {
+        let (status, detail) = check_govbot_dir(dir);
+        checks.push(("govbot dir", status, detail));
+    }
+
+    let (status, detail) = match duckdb_version() {
+        Some(version) => (CheckStatus::Pass, version),
+        None => (
+            CheckStatus::Warn,
+            format!("not found; needed for `load` and `export --format parquet` ({})", DUCKDB_INSTALL_URL),
+        ),
+    };
+    checks.push(("duckdb", status, detail));
+
+    let model_dir = govbot_dir.clone();
+    let (status, detail) = tokio::task::spawn_blocking(move || check_model_host(model_dir.as_deref(), offline)).await?;
+    checks.push(("model", status, detail));
+
+    let (status, detail) = check_disk_space(govbot_dir.as_deref());
+    checks.push(("disk space", status, detail));
+
+    let (status, detail) = check_config();
+    checks.push(("govbot.yml", status, detail));
+
+    for (name, status, detail) in &checks {
+        println!("{} {:<12} {}", status.marker(), name, detail);
+    }
+
+    let failed = checks.iter().filter(|(_, status, _)| *status == CheckStatus::Fail).count();
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} check(s) failed", failed));
+    }
+    Ok(())
+}
+
+/// The govbot dir exists and a file can be created in it
+fn check_govbot_dir(dir: &Path) -> (CheckStatus, String) {
+    if !dir.is_dir() {
+        return (
+            CheckStatus::Fail,
+            format!("{} not found (run `govbot clone` to create it)", dir.display()),
+        );
+    }
+    let probe = dir.join(".govbot-write-check");
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            (CheckStatus::Pass, format!("{} is writable", dir.display()))
+        }
+        Err(e) => (CheckStatus::Fail, format!("{} is not writable: {}", dir.display(), e)),
+    }
+}
+
+/// Embedding files are already downloaded, or the host they come from answers (not asked when `offline`)
+fn check_model_host(model_dir: Option<&Path>, offline: bool) -> (CheckStatus, String) {
+    if let Some(dir) = model_dir {
+        if embedding_files_status(dir) == (true, true) {
+            return (CheckStatus::Pass, format!("embedding files present in {}", dir.display()));
+        }
+    }
+    if offline {
+        return (
+            CheckStatus::Warn,
+            format!("embedding files missing; {} not checked (offline)", MODEL_HOST_URL),
+        );
+    }
+    let reachable = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .and_then(|client| client.head(MODEL_HOST_URL).send());
+    match reachable {
+        Ok(_) => (
+            CheckStatus::Pass,
+            format!("{} reachable; `tag` downloads the model on first run", MODEL_HOST_URL),
+        ),
+        Err(e) => (
+            CheckStatus::Warn,
+            format!("cannot reach {} ({}); `tag` falls back to keyword matching", MODEL_HOST_URL, e),
+        ),
+    }
+}
+
+/// Free space where repos are cloned, compared with `FULL_CLONE_ESTIMATE_BYTES`
+fn check_disk_space(govbot_dir: Option<&Path>) -> (CheckStatus, String) {
+    // Measure the govbot dir, or the nearest existing ancestor if it hasn't been created yet
+    let target = govbot_dir
+        .and_then(|dir| dir.ancestors().find(|p| p.is_dir()))
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::current_dir().ok());
+    let Some(available) = target.as_deref().and_then(available_disk_bytes) else {
+        return (CheckStatus::Warn, "could not determine free disk space".to_string());
+    };
+    let detail = format!(
+        "{} free; a full `clone all` needs about {}",
+        git::format_size(available),
+        git::format_size(FULL_CLONE_ESTIMATE_BYTES)
+    );
+    if available >= FULL_CLONE_ESTIMATE_BYTES {
+        (CheckStatus::Pass, detail)
+    } else {
+        (CheckStatus::Warn, detail)
+    }
+}
+
+/// Bytes available on the filesystem holding `path`, from `df -Pk`
+fn available_disk_bytes(path: &Path) -> Option<u64> {
+    let output = ProcessCommand::new("df").arg("-Pk").arg(path).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let kilobytes: u64 = stdout.lines().nth(1)?.split_whitespace().nth(3)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// govbot.yml parses, if there is one
+fn check_config() -> (CheckStatus, String) {
+    let path = match config_path() {
+        Ok(path) => path,
+        Err(e) => return (CheckStatus::Fail, e.to_string()),
+    };
+    if !path.exists() {
+        return (
+            CheckStatus::Warn,
+            format!("{} not found (run `govbot init` to create one)", path.display()),
+        );
+    }
+    match load_config(&path) {
+        Ok(config) => {
+            let tags = config.get("tags").and_then(|t| t.as_object()).map_or(0, |t| t.len());
+            (CheckStatus::Pass, format!("{} parses ({} tags)", path.display(), tags))
+        }
+        Err(e) => (CheckStatus::Fail, format!("{:#}", e)),
+    }
+}
+
+/// Sibling path of `path` used for intermediate output, e.g. `out.parquet` -> `.out.parquet.ndjson`
+fn tempfile_path(path: &Path, extension: &str) -> PathBuf {
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!(".{}.{}", name, extension))
+}
+
+/// Split an optional comma-separated flag value into trimmed, non-empty items
+fn split_comma_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or("")
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Ensure embedding model and tokenizer exist and pass integrity checks;
+/// if missing or corrupt, download them from Hugging Face (unless `allow_download` is false).
 /// Returns true if files are present/ready, false otherwise.
-fn ensure_embedding_files(model_dir: &std::path::Path) -> bool {
+fn ensure_embedding_files(model_dir: &std::path::Path, allow_download: bool) -> bool {
     let model_path = model_dir.join("model.onnx");
     let tokenizer_path = model_dir.join("tokenizer.json");
-    let _vocab_path = model_dir.join("vocab.txt");
+    let (model_ok, tokenizer_ok) = embedding_files_status(model_dir);
 
-    if model_path.exists() && tokenizer_path.exists() {
+    if model_ok && tokenizer_ok {
         return true;
     }
 
-    eprintln!("Embedding files not found. Downloading all-MiniLM-L6-v2 (ONNX) to {}...", model_dir.display());
+    if !allow_download {
+        eprintln!(
+            "Embedding files not found or incomplete in {} and --no-download is set; skipping the download.",
+            model_dir.display()
+        );
+        return false;
+    }
+
+    eprintln!("Embedding files not found or incomplete. Downloading all-MiniLM-L6-v2 (ONNX) to {}...", model_dir.display());
 
     // Use Xenova ONNX exports
-    let onnx_url = "https://huggingface.co/Xenova/all-MiniLM-L6-v2/resolve/main/onnx/model.onnx";
-    let tokenizer_url = "https://huggingface.co/Xenova/all-MiniLM-L6-v2/resolve/main/tokenizer.json";
+    let onnx_url = format!("{}/Xenova/all-MiniLM-L6-v2/resolve/main/onnx/model.onnx", MODEL_HOST_URL);
+    let tokenizer_url = format!("{}/Xenova/all-MiniLM-L6-v2/resolve/main/tokenizer.json", MODEL_HOST_URL);
 
     // Download tokenizer.json
-    if !tokenizer_path.exists() {
-        if let Err(e) = download_file(tokenizer_url, &tokenizer_path) {
+    if !tokenizer_ok {
+        if let Err(e) = govbot::embeddings::download_verified(&tokenizer_url, &tokenizer_path, |p| govbot::embeddings::validate_tokenizer_file(p)) {
             eprintln!("Failed to download tokenizer.json: {}", e);
             return false;
         }
     }
 
     // Download ONNX model
-    if !model_path.exists() {
-        if let Err(e) = download_file(onnx_url, &model_path) {
+    if !model_ok {
+        if let Err(e) = govbot::embeddings::download_verified(&onnx_url, &model_path, |p| govbot::embeddings::validate_model_file(p)) {
             eprintln!("Failed to download ONNX model: {}", e);
             return false;
         }
     }
 
-    if !model_path.exists() || !tokenizer_path.exists() {
-        eprintln!(
-            "Download completed but model.onnx or tokenizer.json not found in {}",
-            model_dir.display()
-        );
-        return false;
+    eprintln!("{} Successfully downloaded embedding files!", icon("✅", "ok"));
+    true
+}
+
+/// Host the embedding model and tokenizer are downloaded from
+const MODEL_HOST_URL: &str = "https://huggingface.co";
+
+/// Whether `model.onnx` and `tokenizer.json` in `model_dir` are present and valid. Existing but
+/// corrupt files (e.g. from an interrupted download) count as missing.
+fn embedding_files_status(model_dir: &Path) -> (bool, bool) {
+    let model_path = model_dir.join("model.onnx");
+    let tokenizer_path = model_dir.join("tokenizer.json");
+    (
+        model_path.exists() && govbot::embeddings::validate_model_file(&model_path).is_ok(),
+        tokenizer_path.exists() && govbot::embeddings::validate_tokenizer_file(&tokenizer_path).is_ok(),
+    )
+}
+
+/// Periodic `tag` progress line; with a known line count it includes a percentage and ETA
+fn read_progress(read: usize, total: Option<usize>, elapsed: std::time::Duration, processed: usize, skipped: usize) -> String {
+    let Some(total) = total.filter(|&t| t > 0) else {
+        return format!("Read {} lines (processed {}, skipped {})...", read, processed, skipped);
+    };
+    let read = read.min(total);
+    let percent = read * 100 / total;
+    let eta = if read == 0 {
+        String::from("unknown")
+    } else {
+        let remaining = elapsed.as_secs_f64() / read as f64 * (total - read) as f64;
+        let secs = remaining.round() as u64;
+        format!("{}:{:02}", secs / 60, secs % 60)
+    };
+    format!(
+        "Read {}/{} lines ({}%, ETA {}; processed {}, skipped {})...",
+        read, total, percent, eta, processed, skipped
+    )
+}
+
+/// Tag result structure: (tag_key, score_breakdown)
+type TagResult = (String, govbot::ScoreBreakdown);
+
+/// Entries read per batch for each `tag --jobs` worker
+const TAG_BATCH_PER_JOB: usize = 16;
+
+/// Bill id and session `tags/` directory for a `tag` input entry.
+/// The session comes from `sources.log` (default selector format), falling back to
+/// `us/unknown/unknown`; the bill id from the entry (normalized with `--normalize-identifiers`),
+/// falling back to a hash of its text.
+fn tag_entry_location(
+    json_value: &serde_json::Value,
+    bill_text: &str,
+    base_output_dir: &Path,
+    normalize_identifiers: bool,
+) -> (String, PathBuf) {
+    let (country, state, session_id) = json_value
+        .get("sources")
+        .and_then(|sources| sources.get("log"))
+        .and_then(|path| path.as_str())
+        .and_then(extract_path_info)
+        .unwrap_or_else(|| ("us".to_string(), "unknown".to_string(), "unknown".to_string()));
+
+    let bill_id = govbot::types::extract_bill_identifier(json_value)
+        .map(|id| if normalize_identifiers { govbot::types::normalize_bill_identifier(&id) } else { id })
+        .unwrap_or_else(|| {
+        let text_hash = hash_text(bill_text);
+        format!("entry_{}", &text_hash[..8])
+    });
+
+    let tags_dir = base_output_dir
+        .join(format!("country:{}", country))
+        .join(format!("state:{}", state))
+        .join("sessions")
+        .join(&session_id)
+        .join("tags");
+    (bill_id, tags_dir)
+}
+
+/// Score a batch of `tag` input lines across `matchers` (one thread each) and add the results to
+/// `score_cache`, so the serial pass that writes tag files finds them there.
+/// Lines that won't be re-tagged (already tagged without `--overwrite`) or whose text is already
+/// cached are skipped; lines that fail to parse or score are left for the serial pass to report.
+fn prescore_tag_batch(
+    lines: &[String],
+    matchers: &[&TagMatcher],
+    text_extraction: &govbot::selectors::TextExtractionConfig,
+    entry_location: &dyn Fn(&serde_json::Value, &str) -> (String, PathBuf),
+    overwrite: bool,
+    tag_name: Option<&str>,
+    score_cache: &mut HashMap<u64, Vec<TagResult>>,
+) {
+    let mut pending: Vec<(u64, serde_json::Value)> = Vec::new();
+    for line in lines {
+        let Ok(json_value) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+            continue;
+        };
+        let bill_text = ocd_files_select(&json_value, text_extraction);
+        let text_key = hash_text_fast(&bill_text);
+        if score_cache.contains_key(&text_key) || pending.iter().any(|(key, _)| *key == text_key) {
+            continue;
+        }
+        if !overwrite {
+            let (bill_id, tags_dir) = entry_location(&json_value, &bill_text);
+            if check_existing_tags(&tags_dir, &bill_id, tag_name).is_ok_and(|tags| !tags.is_empty()) {
+                continue;
+            }
+        }
+        pending.push((text_key, json_value));
     }
 
-    eprintln!("✅ Successfully downloaded embedding files!");
-    true
+    let chunk_size = pending.len().div_ceil(matchers.len()).max(1);
+    let scored: Vec<(u64, Vec<TagResult>)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = pending
+            .chunks(chunk_size)
+            .zip(matchers)
+            .map(|(chunk, matcher)| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .filter_map(|(key, value)| matcher.match_json_value(value).ok().map(|tags| (*key, tags)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap_or_default()).collect()
+    });
+    score_cache.extend(scored);
 }
 
-/// Tag result structure: (tag_key, score_breakdown)
-type TagResult = (String, govbot::ScoreBreakdown);
-
 /// Check if a bill is already tagged in tag file(s) for the given session
 /// If tag_name is Some, only checks that specific tag file
 /// Returns a list of tag names that contain this bill
@@ -1506,16 +3299,10 @@ fn check_existing_tags(
     if let Some(requested_tag) = tag_name {
         let tag_path = tags_dir.join(format!("{}.tag.json", requested_tag));
         if tag_path.exists() {
-            match fs::read_to_string(&tag_path) {
-                Ok(contents) => {
-                    if let Ok(tag_file) = serde_json::from_str::<TagFile>(&contents) {
-                        if tag_file.bills.contains_key(bill_id) {
-                            matched_tags.push(requested_tag.to_string());
-                        }
-                    }
-                }
-                Err(_) => {
-                    // Tag file exists but can't be read - return empty
+            // Unreadable tag files count as empty; older ones are migrated on read
+            if let Ok(tag_file) = govbot::embeddings::load_tag_file(&tag_path) {
+                if tag_file.bills.contains_key(bill_id) {
+                    matched_tags.push(requested_tag.to_string());
                 }
             }
         }
@@ -1533,18 +3320,11 @@ fn check_existing_tags(
                     // Remove .tag suffix if present (e.g., "budget.tag" -> "budget")
                     let tag_name = stem.strip_suffix(".tag").unwrap_or(stem);
                     
-                    match fs::read_to_string(&path) {
-                        Ok(contents) => {
-                            if let Ok(tag_file) = serde_json::from_str::<TagFile>(&contents) {
-                                // Check if bill_id exists in bills map
-                                if tag_file.bills.contains_key(bill_id) {
-                                    matched_tags.push(tag_name.to_string());
-                                }
-                            }
-                        }
-                        Err(_) => {
-                            // Skip files that can't be read
-                            continue;
+                    // Skip files that can't be read; older ones are migrated on read
+                    if let Ok(tag_file) = govbot::embeddings::load_tag_file(&path) {
+                        // Check if bill_id exists in bills map
+                        if tag_file.bills.contains_key(bill_id) {
+                            matched_tags.push(tag_name.to_string());
                         }
                     }
                 }
@@ -1561,39 +3341,73 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
         output_dir,
         govbot_dir,
         overwrite,
+        show_text,
+        input,
+        no_download,
+        threshold,
+        min_score,
+        jobs,
+        exclude_keywords,
+        normalize_identifiers,
     } = cmd else {
         unreachable!()
     };
 
-    // Check if govbot.yml exists in current directory
+    let jobs = jobs.unwrap_or(1);
+    if jobs == 0 {
+        return Err(anyhow::anyhow!("--jobs must be at least 1"));
+    }
+
+    if let Some(threshold) = threshold {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(anyhow::anyhow!("--threshold must be between 0.0 and 1.0, got {}", threshold));
+        }
+    }
+    if let Some(min_score) = min_score {
+        if !(0.0..=1.0).contains(&min_score) {
+            return Err(anyhow::anyhow!("--min-score must be between 0.0 and 1.0, got {}", min_score));
+        }
+        eprintln!("Only recording matches scoring at least {} (--min-score)", min_score);
+    }
+    let min_score = min_score.unwrap_or(0.0);
+
     let current_dir = std::env::current_dir()?;
-    let default_tags_cfg = current_dir.join("govbot.yml");
 
     // Model/tokenizer directory: prefer user-specified govbot-dir or env GOVBOT_DIR, else default .govbot
-    let model_dir: PathBuf = if let Some(ref dir) = govbot_dir {
-        PathBuf::from(dir)
-    } else if let Ok(dir) = std::env::var("GOVBOT_DIR") {
-        PathBuf::from(dir)
-    } else {
-        current_dir.join(".govbot")
-    };
+    let model_dir = GovbotEnv::resolve(govbot_dir.as_deref(), None, None)?.govbot_dir;
     fs::create_dir_all(&model_dir)?;
+    let (status, detail) = check_govbot_dir(&model_dir);
+    if status == CheckStatus::Fail {
+        return Err(anyhow::anyhow!("{}", detail));
+    }
     let model_path = model_dir.join("model.onnx");
     let tokenizer_path = model_dir.join("tokenizer.json");
     
     // Require govbot.yml
-    if !default_tags_cfg.exists() {
-        return Err(anyhow::anyhow!(
-            "govbot.yml not found in current directory"
-        ));
-    }
+    let default_tags_cfg = require_config_path()?;
 
     // Load tag definitions (needed for both embedding and keyword fallback)
     let tag_defs = govbot::embeddings::load_tags_config(&default_tags_cfg)
         .map_err(|e| anyhow::anyhow!("Failed to parse govbot.yml: {}", e))?;
+    // --threshold only changes matching; tag files keep recording the configured definitions
+    let keyword_defs: Vec<govbot::TagDefinition> = match threshold {
+        Some(threshold) => {
+            eprintln!("Using threshold {} for every tag (--threshold)", threshold);
+            tag_defs
+                .iter()
+                .cloned()
+                .map(|tag_def| govbot::TagDefinition { threshold, ..tag_def })
+                .collect()
+        }
+        None => tag_defs.clone(),
+    };
+    if !exclude_keywords.is_empty() {
+        eprintln!("Dropping matches for entries containing: {} (--exclude-keyword)", exclude_keywords.join(", "));
+    }
+    let text_extraction = govbot::embeddings::load_text_extraction_config(&default_tags_cfg)?;
 
     // Try embedding mode first
-    let embedding_matcher = if ensure_embedding_files(&model_dir) {
+    let embedding_matcher = if ensure_embedding_files(&model_dir, !no_download) {
         let tags_path = default_tags_cfg.clone();
 
         eprintln!("Using embedding mode:");
@@ -1602,7 +3416,13 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
         eprintln!("  Tags config: {}", tags_path.display());
 
         match TagMatcher::from_files(&model_path, &tokenizer_path, &tags_path) {
-            Ok(matcher) => Some(matcher),
+            Ok(matcher) => {
+                let matcher = matcher.with_text_extraction(text_extraction.clone());
+                Some(match threshold {
+                    Some(threshold) => matcher.with_threshold(threshold),
+                    None => matcher,
+                })
+            }
             Err(e) => {
                 eprintln!("Warning: Failed to initialize embedding matcher: {}", e);
                 eprintln!("Falling back to keyword-based matching.");
@@ -1614,6 +3434,19 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
         eprintln!("  Tags config: {}", default_tags_cfg.display());
         None
     };
+
+    // Extra matchers for --jobs: each opens its own session but reuses the tag embeddings
+    let mut workers: Vec<TagMatcher> = Vec::new();
+    if let Some(matcher) = embedding_matcher.as_ref().filter(|_| jobs > 1) {
+        for _ in 1..jobs {
+            workers.push(
+                matcher
+                    .clone_shared()
+                    .map_err(|e| anyhow::anyhow!("Failed to open an embedding session for --jobs: {}", e))?,
+            );
+        }
+        eprintln!("  Jobs: {}", jobs);
+    }
     
     // Determine output directory
     // If govbot.yml exists, use its directory as the base output directory
@@ -1624,75 +3457,74 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
             .to_path_buf()
     } else if let Some(ref dir) = output_dir {
         PathBuf::from(dir)
-    } else if let Some(ref dir) = govbot_dir {
-        PathBuf::from(dir)
-    } else if let Ok(dir) = std::env::var("GOVBOT_DIR") {
-        PathBuf::from(dir)
     } else {
-        // Default to current directory
-        current_dir
+        model_dir.clone()
     };
     
-    // Read JSON lines from stdin
-    let stdin = io::stdin();
-    let reader = BufReader::new(stdin.lock());
+    // Read JSON lines from --input (counted up front for progress) or stdin
+    let (reader, total_lines): (Box<dyn BufRead>, Option<usize>) = match &input {
+        Some(path) => {
+            let open = || fs::File::open(path).map_err(|e| anyhow::anyhow!("Failed to open {}: {}", path.display(), e));
+            let total = BufReader::new(open()?).split(b'\n').count();
+            (Box::new(BufReader::new(open()?)), Some(total))
+        }
+        None => (Box::new(BufReader::new(io::stdin().lock())), None),
+    };
+    let started = std::time::Instant::now();
     
     let mut processed_count = 0;
     let mut skipped_count = 0;
     let mut read_count: usize = 0;
+    // Scores depend only on the extracted text, so identical texts are matched once per run
+    let mut score_cache: HashMap<u64, Vec<TagResult>> = HashMap::new();
     
-    eprintln!("Reading JSON lines from stdin...");
+    match (&input, total_lines) {
+        (Some(path), Some(total)) => eprintln!("Reading {} JSON lines from {}...", total, path.display()),
+        _ => eprintln!("Reading JSON lines from stdin..."),
+    }
     
-    for line_result in reader.lines() {
-        let line = line_result?;
-        let line = line.trim();
-        if line.is_empty() {
-            read_count += 1;
-            if read_count % 100 == 0 {
-                eprintln!("Read {} lines (processed {}, skipped {})...", read_count, processed_count, skipped_count);
-            }
-            continue;
+    let entry_location = |json_value: &serde_json::Value, bill_text: &str| {
+        tag_entry_location(json_value, bill_text, &base_output_dir, normalize_identifiers)
+    };
+
+    // With --jobs, lines are read in batches whose entries are scored in parallel up front
+    let batch_size = if workers.is_empty() { 1 } else { jobs * TAG_BATCH_PER_JOB };
+    let mut lines = reader.lines();
+    loop {
+        let batch = lines.by_ref().take(batch_size).collect::<io::Result<Vec<String>>>()?;
+        if batch.is_empty() {
+            break;
         }
+        if let Some(matcher) = embedding_matcher.as_ref().filter(|_| !workers.is_empty()) {
+            let matchers: Vec<&TagMatcher> = std::iter::once(matcher).chain(&workers).collect();
+            prescore_tag_batch(
+                &batch,
+                &matchers,
+                &text_extraction,
+                &entry_location,
+                overwrite,
+                tag_name.as_deref(),
+                &mut score_cache,
+            );
+        }
+        for line in &batch {
+            let line = line.trim();
+            if line.is_empty() {
+                read_count += 1;
+                if read_count.is_multiple_of(100) {
+                    eprintln!("{}", read_progress(read_count, total_lines, started.elapsed(), processed_count, skipped_count));
+                }
+                continue;
+            }
         
-        read_count += 1;
-        // Parse JSON line (assumes default selector format)
-        match serde_json::from_str::<serde_json::Value>(line) {
-            Ok(json_value) => {
-                // Extract bill_id from top-level "id" field (default selector format)
-                let bill_id_opt = json_value
-                    .get("id")
-                    .and_then(|id| id.as_str());
-
-                // Extract text from JSON for embedding comparison
-                let bill_text = ocd_files_select_default(&json_value);
+            read_count += 1;
+            // Parse JSON line (assumes default selector format)
+            match serde_json::from_str::<serde_json::Value>(line) {
+                Ok(json_value) => {
+                    // Extract text from JSON for embedding comparison
+                    let bill_text = ocd_files_select(&json_value, &text_extraction);
+                    let (bill_id, tags_dir) = entry_location(&json_value, &bill_text);
                 
-                // Extract path info from sources.log (default selector format)
-                let path_info = json_value
-                    .get("sources")
-                    .and_then(|sources| sources.get("log"))
-                    .and_then(|path| path.as_str())
-                    .and_then(|log_path| extract_path_info(log_path))
-                    .or_else(|| {
-                        // Fallback: use default values if we can't determine
-                        Some(("us".to_string(), "unknown".to_string(), "unknown".to_string()))
-                    });
-
-                // Process if we have path info (from sources.log in default selector format)
-                if let Some((country, state, session_id)) = path_info {
-                    // Get bill_id - use "id" from default selector, or generate from text hash if missing
-                    let bill_id = bill_id_opt.map(|s| s.to_string()).unwrap_or_else(|| {
-                        let text_hash = hash_text(&bill_text);
-                        format!("entry_{}", &text_hash[..8])
-                    });
-                    
-                    // Determine tags directory
-                    let tags_dir = base_output_dir
-                        .join(&format!("country:{}", country))
-                        .join(&format!("state:{}", state))
-                        .join("sessions")
-                        .join(&session_id)
-                        .join("tags");
-                    
                     // Validate tag_name if provided
                     if let Some(ref requested_tag) = tag_name {
                         if !tag_defs.iter().any(|td| td.name == *requested_tag) {
@@ -1703,11 +3535,11 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                             ));
                         }
                     }
-                    
+                
                     // Fast path: check if bill is already tagged (unless overwrite is set)
                     let mut matched_tags: Vec<String> = Vec::new();
                     let mut should_run_tagging = overwrite;
-                    
+                
                     if !overwrite {
                         match check_existing_tags(&tags_dir, &bill_id, tag_name.as_deref()) {
                             Ok(existing_tags) => {
@@ -1727,37 +3559,58 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                             }
                         }
                     }
-                    
+                
                     // Run tagging logic if needed
                     if should_run_tagging {
                         // Choose strategy based on mode
-                        let mut tags: Vec<TagResult> = if let Some(matcher) = embedding_matcher.as_ref() {
-                            match matcher.match_json_value(&json_value) {
-                                Ok(results) => results,
+                        let text_key = hash_text_fast(&bill_text);
+                        let mut tags: Vec<TagResult> = if let Some(cached) = score_cache.get(&text_key) {
+                            if show_text {
+                                eprintln!("[{}] {}", bill_id, bill_text);
+                            }
+                            cached.clone()
+                        } else if let Some(matcher) = embedding_matcher.as_ref() {
+                            match matcher.match_json_value_verbose(&json_value) {
+                                Ok((text, mut results)) => {
+                                    if show_text {
+                                        eprintln!("[{}] {}", bill_id, text);
+                                    }
+                                    results.retain(|(_, score)| score.final_score >= min_score);
+                                    results
+                                }
                                 Err(e) => {
                                     eprintln!("Error running embedding matcher for bill {}: {}", bill_id, e);
                                     eprintln!("Falling back to keyword-based matching for this entry.");
                                     // Fall back to keyword matching for this entry
-                                    govbot::embeddings::match_tags_keywords(&tag_defs, &json_value)
+                                    govbot::embeddings::match_tags_keywords_with_threshold(&keyword_defs, &json_value, &text_extraction, min_score)
                                 }
                             }
                         } else {
+                            if show_text {
+                                eprintln!("[{}] {}", bill_id, bill_text);
+                            }
                             // Use keyword-based fallback matcher
-                            govbot::embeddings::match_tags_keywords(&tag_defs, &json_value)
+                            govbot::embeddings::match_tags_keywords_with_threshold(&keyword_defs, &json_value, &text_extraction, min_score)
                         };
-                        
+                        score_cache.entry(text_key).or_insert_with(|| tags.clone());
+
+                        // --exclude-keyword vetoes the entry for every tag, after per-tag scoring
+                        if govbot::embeddings::matches_keywords(&bill_text, &exclude_keywords) {
+                            tags.clear();
+                        }
+                    
                         // Filter to specific tag if requested
                         if let Some(ref requested_tag) = tag_name {
                             tags.retain(|(tag, _)| tag == requested_tag);
                         }
-                        
+                    
                         // Extract tag names from results
                         matched_tags = tags.iter().map(|(tag_name, _)| tag_name.clone()).collect();
-                        
+                    
                         // Save tags to files if we found matches
                         if !tags.is_empty() {
                             let text_hash = hash_text(&bill_text);
-                            
+                        
                             // Write per-tag files immediately
                             fs::create_dir_all(&tags_dir)?;
 
@@ -1791,11 +3644,12 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                                                             negative_examples: Vec::new(),
                                                             threshold: 0.5,
                                                         });
-                                                    
+                                                
                                                     let tag_config_hash = hash_text(&serde_json::to_string(&tag_def).unwrap_or_default());
-                                                    
+                                                
                                                     TagFile {
                                                         metadata: TagFileMetadata {
+                                                            version: govbot::TAG_FILE_VERSION,
                                                             last_run: now.clone(),
                                                             model: model_path_str.clone(),
                                                             tag_config_hash,
@@ -1821,11 +3675,12 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                                                         negative_examples: Vec::new(),
                                                         threshold: 0.5,
                                                     });
-                                                
+                                            
                                                 let tag_config_hash = hash_text(&serde_json::to_string(&tag_def)?);
-                                                
+                                            
                                                 TagFile {
                                                     metadata: TagFileMetadata {
+                                                        version: govbot::TAG_FILE_VERSION,
                                                         last_run: now.clone(),
                                                         model: model_path_str.clone(),
                                                         tag_config_hash,
@@ -1851,11 +3706,12 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                                                 negative_examples: Vec::new(),
                                                 threshold: 0.5,
                                             });
-                                        
+                                    
                                         let tag_config_hash = hash_text(&serde_json::to_string(&tag_def)?);
-                                        
+                                    
                                         TagFile {
                                             metadata: TagFileMetadata {
+                                                version: govbot::TAG_FILE_VERSION,
                                                 last_run: now.clone(),
                                                 model: model_path_str.clone(),
                                                 tag_config_hash,
@@ -1866,28 +3722,31 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                                         }
                                     };
 
+                                // Upgrade files from older schema versions before updating them
+                                tag_file.migrate();
+
                                 // Update metadata
                                 tag_file.metadata.last_run = now.clone();
                                 tag_file.metadata.model = model_path_str.clone();
-                                
+                            
                                 // Update tag config if it changed
                                 let current_tag_def = tag_defs
                                     .iter()
                                     .find(|td| td.name == tag_key)
                                     .cloned()
                                     .unwrap_or_else(|| tag_file.tag_config.clone());
-                                
+                            
                                 let current_config_hash = hash_text(&serde_json::to_string(&current_tag_def)?);
                                 if current_config_hash != tag_file.metadata.tag_config_hash {
                                     tag_file.tag_config = current_tag_def;
                                     tag_file.metadata.tag_config_hash = current_config_hash;
                                 }
-                                
+                            
                                 // Add text to cache if not present
                                 if !tag_file.text_cache.contains_key(&text_hash) {
                                     tag_file.text_cache.insert(text_hash.clone(), bill_text.clone());
                                 }
-                                
+                            
                                 // Add/update bill result
                                 tag_file.bills.insert(bill_id.to_string(), BillTagResult {
                                     text_hash: text_hash.clone(),
@@ -1900,7 +3759,7 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                             }
                         }
                     }
-                    
+                
                     // Output the line if it matches tags (filter mode)
                     // If a specific tag was requested, only output if that tag matches
                     // Otherwise, output if any tag matches
@@ -1909,50 +3768,39 @@ async fn run_tag_command(cmd: Command) -> anyhow::Result<()> {
                     } else {
                         !matched_tags.is_empty()
                     };
-                    
+                
                     if should_output {
                         write_json_line(line)?;
                     }
-                    
+                
                     processed_count += 1;
                     if processed_count % 50 == 0 {
                         eprintln!("Processed {} entries (matched: {} tags)...", processed_count, matched_tags.len());
                     }
-                } else {
-                    // No path info - skip this entry (default selector should always provide sources.log)
+                }
+                Err(_e) => {
+                    // Skip malformed/empty lines quietly
                     skipped_count += 1;
                 }
             }
-            Err(_e) => {
-                // Skip malformed/empty lines quietly
-                skipped_count += 1;
+
+            if read_count.is_multiple_of(100) {
+                eprintln!("{}", read_progress(read_count, total_lines, started.elapsed(), processed_count, skipped_count));
             }
         }
+    }
 
-        if read_count % 100 == 0 {
-            eprintln!("Read {} lines (processed {}, skipped {})...", read_count, processed_count, skipped_count);
-        }
+    if total_lines.is_some() {
+        eprintln!("{}", read_progress(read_count, total_lines, started.elapsed(), processed_count, skipped_count));
     }
-    
     eprintln!("\nProcessed: {}, Skipped: {}", processed_count, skipped_count);
-    eprintln!("\n✅ Tagging complete!");
+    eprintln!("\n{} Tagging complete!", icon("✅", "ok"));
     
     Ok(())
 }
 
-async fn run_init_command(cmd: Command) -> anyhow::Result<()> {
-    let Command::Init { force } = cmd else {
-        unreachable!()
-    };
-    
-    let cwd = std::env::current_dir()?;
-    
-    // Create govbot.yml
-    let govbot_yml_path = cwd.join("govbot.yml");
-    if govbot_yml_path.exists() && !force {
-        eprintln!("⚠️  govbot.yml already exists. Use --force to overwrite.");
-    } else {
-        let govbot_yml_content = r#"# Govbot Configuration
+/// Header shared by all govbot.yml templates
+const GOVBOT_YML_HEADER: &str = r#"# Govbot Configuration
 # Schema: https://raw.githubusercontent.com/windy-civi/toolkit/main/schemas/govbot.schema.json
 $schema: https://raw.githubusercontent.com/windy-civi/toolkit/main/schemas/govbot.schema.json
 
@@ -1960,7 +3808,10 @@ repos:
   - all
 
 tags:
-  education:
+"#;
+
+/// Example tag for the `default` template (embedding mode: description + examples)
+const GOVBOT_YML_DEFAULT_TAGS: &str = r#"  education:
     description: |
       Legislation related to schools, education funding, curriculum standards, and educational policy, including:
       - K-12 public school funding, budgets, and resource allocation
@@ -1979,7 +3830,29 @@ tags:
       - "Increases per-pupil funding for public schools and establishes minimum teacher salary requirements"
       - "Mandates comprehensive sex education curriculum in all public schools"
       - "Expands eligibility for state financial aid programs to include part-time students"
+"#;
 
+/// Example tag for the `keyword` template (keyword-only matching)
+const GOVBOT_YML_KEYWORD_TAGS: &str = r#"  education:
+    description: "Legislation related to schools and education policy"
+    # A bill matches when any include keyword appears; more keyword hits score higher
+    include_keywords:
+      - school
+      - education
+      - teacher
+      - curriculum
+    # Any exclude keyword vetoes the tag
+    exclude_keywords: []
+    threshold: 0.5
+"#;
+
+/// Example tag for the `minimal` template
+const GOVBOT_YML_MINIMAL_TAGS: &str = r#"  education:
+    description: "Legislation related to schools and education policy"
+"#;
+
+/// Build/publish settings shared by all govbot.yml templates
+const GOVBOT_YML_BUILD: &str = r#"
 build:
   base_url: "https://yourusername.github.io/your-repo-name"
   output_dir: "docs"
@@ -1987,8 +3860,59 @@ build:
   # Optional: limit number of entries (default: 100, use "none" for all)
   # limit: 100
 "#;
+
+/// Render govbot.yml for `govbot init` from a template name and optional tag names to scaffold
+fn render_govbot_yml(template: &str, tag_names: &[String]) -> String {
+    let mut content = GOVBOT_YML_HEADER.to_string();
+
+    if tag_names.is_empty() {
+        content.push_str(match template {
+            "keyword" => GOVBOT_YML_KEYWORD_TAGS,
+            "minimal" => GOVBOT_YML_MINIMAL_TAGS,
+            _ => GOVBOT_YML_DEFAULT_TAGS,
+        });
+    } else {
+        for name in tag_names {
+            // Serialized as a YAML scalar so names like `a: b` or `#x` are quoted as needed
+            let key = serde_yaml::to_string(name).unwrap_or_else(|_| format!("{:?}", name));
+            content.push_str(&format!("  {}:\n    description: \"\"\n", key.trim_end()));
+            match template {
+                "keyword" => {
+                    content.push_str("    include_keywords: []  # e.g. [\"keyword\", \"multi word phrase\"]\n");
+                    content.push_str("    exclude_keywords: []\n");
+                }
+                "minimal" => {}
+                _ => content.push_str("    examples: []\n"),
+            }
+        }
+    }
+
+    content.push_str(GOVBOT_YML_BUILD);
+    content
+}
+
+async fn run_init_command(cmd: Command) -> anyhow::Result<()> {
+    let Command::Init { force, template, tags } = cmd else {
+        unreachable!()
+    };
+    
+    let cwd = std::env::current_dir()?;
+    
+    // Create govbot.yml
+    let govbot_yml_path = cwd.join("govbot.yml");
+    if govbot_yml_path.exists() && !force {
+        eprintln!("{}  govbot.yml already exists. Use --force to overwrite.", icon("⚠️", "warning:"));
+    } else {
+        let tag_names: Vec<String> = tags
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        let govbot_yml_content = render_govbot_yml(&template, &tag_names);
         fs::write(&govbot_yml_path, govbot_yml_content)?;
-        println!("✓ Created govbot.yml");
+        println!("{} Created govbot.yml", icon("✓", "ok"));
     }
     
     // Create or update .gitignore
@@ -1998,7 +3922,7 @@ build:
     if gitignore_path.exists() {
         let mut content = fs::read_to_string(&gitignore_path)?;
         if content.contains(".govbot") {
-            println!("✓ .gitignore already contains .govbot");
+            println!("{} .gitignore already contains .govbot", icon("✓", "ok"));
         } else {
             // Add .govbot if not present
             if !content.ends_with('\n') {
@@ -2006,11 +3930,11 @@ build:
             }
             content.push_str(gitignore_entry);
             fs::write(&gitignore_path, content)?;
-            println!("✓ Updated .gitignore to include .govbot");
+            println!("{} Updated .gitignore to include .govbot", icon("✓", "ok"));
         }
     } else {
         fs::write(&gitignore_path, gitignore_entry)?;
-        println!("✓ Created .gitignore with .govbot");
+        println!("{} Created .gitignore with .govbot", icon("✓", "ok"));
     }
     
     // Create GitHub Actions workflow
@@ -2019,7 +3943,7 @@ build:
     
     let workflow_path = workflows_dir.join("build.yml");
     if workflow_path.exists() && !force {
-        eprintln!("⚠️  .github/workflows/build.yml already exists. Use --force to overwrite.");
+        eprintln!("{}  .github/workflows/build.yml already exists. Use --force to overwrite.", icon("⚠️", "warning:"));
     } else {
         let workflow_content = r#"# Run Govbot
 # Runs govbot to clone repos, tag bills, and build RSS feeds and HTML index.
@@ -2059,10 +3983,10 @@ jobs:
           limit: ${{ inputs.limit }}
 "#;
         fs::write(&workflow_path, workflow_content)?;
-        println!("✓ Created .github/workflows/build.yml");
+        println!("{} Created .github/workflows/build.yml", icon("✓", "ok"));
     }
     
-    println!("\n✅ Govbot project initialized!");
+    println!("\n{} Govbot project initialized!", icon("✅", "ok"));
     println!("\nNext steps:");
     println!("  1. Edit govbot.yml to customize tags and build settings");
     println!("  2. Update the base_url in govbot.yml to match your feed URL");
@@ -2075,27 +3999,120 @@ jobs:
 }
 
 async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
+    let Command::Build { watch, .. } = cmd else {
+        unreachable!()
+    };
+    if watch {
+        watch_and_build(cmd).await
+    } else {
+        build_feeds(cmd).await
+    }
+}
+
+/// Quiet period after a change before rebuilding, so a burst of saves triggers one build
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// `build --watch`: build once, then rebuild on changes to govbot.yml or any tag file until Ctrl-C
+async fn watch_and_build(cmd: Command) -> anyhow::Result<()> {
+    use notify::{RecursiveMode, Watcher};
+
+    let config_path = require_config_path()?.canonicalize()?;
+    let config_dir = config_path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            let _ = tx.send(event.paths);
+        }
+    })?;
+    // Tag files live under `country:*/` next to govbot.yml; watching those trees rather than the
+    // whole directory keeps the output dir and cloned repos out of the watch
+    watcher.watch(&config_dir, RecursiveMode::NonRecursive)?;
+    for entry in fs::read_dir(&config_dir)?.flatten() {
+        if entry.path().is_dir() && entry.file_name().to_string_lossy().starts_with("country:") {
+            watcher.watch(&entry.path(), RecursiveMode::Recursive)?;
+        }
+    }
+
+    rebuild_feeds(&cmd).await;
+    eprintln!("Watching {} and tag files for changes (Ctrl-C to stop)...", config_path.display());
+
+    let mut interrupt = std::pin::pin!(tokio::signal::ctrl_c());
+    loop {
+        tokio::select! {
+            _ = &mut interrupt => {
+                eprintln!("\nStopped watching.");
+                return Ok(());
+            }
+            Some(paths) = rx.recv() => {
+                if !paths.iter().any(|p| govbot::publish::is_watch_trigger(p, &config_path)) {
+                    continue;
+                }
+                while let Ok(Some(_)) = tokio::time::timeout(WATCH_DEBOUNCE, rx.recv()).await {}
+                rebuild_feeds(&cmd).await;
+            }
+        }
+    }
+}
+
+/// One watch-mode build; failures are reported and the watch keeps running
+async fn rebuild_feeds(cmd: &Command) {
+    eprintln!("[{}] Regenerating feed...", chrono::Local::now().format("%H:%M:%S"));
+    if let Err(e) = build_feeds(cmd.clone()).await {
+        eprintln!("{} Build failed: {}", icon("✗", "error"), e);
+    }
+}
+
+async fn build_feeds(cmd: Command) -> anyhow::Result<()> {
     let Command::Build {
         tags,
+        exclude_tags,
+        min_score,
+        dedup,
+        normalize_identifiers,
+        after,
+        sort,
         limit,
         output_dir,
         output_file,
+        base_url,
+        title,
+        description,
+        validate_feed,
+        merge,
+        feed_per_repo,
+        paginate,
+        language,
+        watch: _,
+        preview,
         govbot_dir,
     } = cmd else {
         unreachable!()
     };
-    
-    // Check if govbot.yml exists in current directory
-    let current_dir = std::env::current_dir()?;
-    let config_path = current_dir.join("govbot.yml");
-    
-    if !config_path.exists() {
-        return Err(anyhow::anyhow!("govbot.yml not found in current directory"));
+
+    if let Some(min_score) = min_score {
+        if !(0.0..=1.0).contains(&min_score) {
+            return Err(anyhow::anyhow!("--min-score must be between 0.0 and 1.0, got {}", min_score));
+        }
     }
     
+    // Require govbot.yml
+    let config_path = require_config_path()?;
+    
     // Load configuration
     let config = load_config(&config_path)?;
     
+    // Feed language: --language, then build.language, then en-us
+    let language = language
+        .or_else(|| get_feed_language_from_config(&config))
+        .unwrap_or_else(|| "en-us".to_string());
+    if !rss::is_valid_language_tag(&language) {
+        return Err(anyhow::anyhow!(
+            "Invalid feed language '{}': expected a language tag like 'en-us' or 'fr-ca'",
+            language
+        ));
+    }
+    
     // Get tags configuration
     let tags_config = config.get("tags")
         .and_then(|t| t.as_object())
@@ -2120,12 +4137,15 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
     };
     
     // Validate tags exist
-    for tag in &tags_to_use {
+    for tag in tags_to_use.iter().chain(exclude_tags.iter()) {
         if !tags_config.contains_key(tag) {
             return Err(anyhow::anyhow!("Tag '{}' not found in configuration", tag));
         }
     }
     
+    // Entries matched only by excluded tags are dropped later by filter_by_tags
+    let tags_to_use = exclude_tags_from(tags_to_use, &exclude_tags);
+    
     if tags_to_use.is_empty() {
         return Err(anyhow::anyhow!("No valid tags to process"));
     }
@@ -2133,7 +4153,7 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
     // Get build configuration
     let build_config = config.get("build").and_then(|p| p.as_object());
     
-    // Get output directory
+    // Get output directory; a configured one is relative to govbot.yml
     let output_dir_path = if let Some(dir) = output_dir {
         PathBuf::from(dir)
     } else {
@@ -2141,7 +4161,7 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
             .and_then(|p| p.get("output_dir"))
             .and_then(|d| d.as_str())
             .unwrap_or("docs");
-        PathBuf::from(dir_str)
+        config_path.parent().map(|p| p.join(dir_str)).unwrap_or_else(|| PathBuf::from(dir_str))
     };
     
     // Get output filename
@@ -2156,10 +4176,13 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
     };
     
     // Get feed metadata
-    let feed_title = build_config
-        .and_then(|p| p.get("title"))
-        .and_then(|t| t.as_str())
-        .map(|s| s.to_string())
+    let title_override = title.filter(|s| !s.trim().is_empty());
+    let feed_title = title_override
+        .clone()
+        .or_else(|| build_config
+            .and_then(|p| p.get("title"))
+            .and_then(|t| t.as_str())
+            .map(|s| s.to_string()))
         .unwrap_or_else(|| {
             format!("{} Legislation", tags_to_use.iter()
                 .map(|t| t.replace('_', " ").split_whitespace()
@@ -2176,10 +4199,11 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
                 .join(" & "))
         });
     
-    let feed_description = build_config
-        .and_then(|p| p.get("description"))
-        .and_then(|d| d.as_str())
-        .map(|s| s.to_string())
+    let feed_description = description
+        .or_else(|| build_config
+            .and_then(|p| p.get("description"))
+            .and_then(|d| d.as_str())
+            .map(|s| s.to_string()))
         .unwrap_or_else(|| {
             let mut descs = Vec::new();
             for tag_name in &tags_to_use {
@@ -2206,15 +4230,20 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
             }
         });
     
-    let feed_link = build_config
-        .and_then(|p| p.get("base_url"))
-        .and_then(|u| u.as_str())
-        .unwrap_or("https://example.com");
+    let feed_link = if let Some(ref url) = base_url {
+        url.as_str()
+    } else {
+        build_config
+            .and_then(|p| p.get("base_url"))
+            .and_then(|u| u.as_str())
+            .unwrap_or("https://example.com")
+    };
     
     let base_url = Some(feed_link);
     
-    // Get repos
-    let repos = get_repos_from_config(&config);
+    // Get repos, accepting locales added by a repo manifest in the govbot dir
+    let manifest = load_repo_manifest(None, &get_govbot_dir(govbot_dir.clone())?)?;
+    let repos = get_repos_from_config_with_locales(&config, &known_locales(manifest.as_ref()))?;
     
     // Get repos to process
     let repos_to_process: Vec<String> = if repos == vec!["all".to_string()] {
@@ -2231,11 +4260,7 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
             .and_then(|l| {
                 if let Some(s) = l.as_str() {
                     Some(s.to_string())
-                } else if let Some(n) = l.as_u64() {
-                    Some(n.to_string())
-                } else {
-                    None
-                }
+                } else { l.as_u64().map(|n| n.to_string()) }
             })
     });
     
@@ -2255,18 +4280,10 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
     
     // Get the base govbot directory (not the repos subdirectory)
     // The logs command expects the base directory and will append /repos itself
-    let base_govbot_dir = if let Some(ref gd) = govbot_dir {
-        gd.clone()
-    } else if let Ok(gd) = std::env::var("GOVBOT_DIR") {
-        gd
-    } else {
-        // Default: $CWD/.govbot
-        std::env::current_dir()
-            .unwrap_or_else(|_| PathBuf::from("."))
-            .join(".govbot")
-            .to_string_lossy()
-            .to_string()
-    };
+    let base_govbot_dir = GovbotEnv::resolve(govbot_dir.as_deref(), None, None)?
+        .govbot_dir
+        .to_string_lossy()
+        .to_string();
     
     // Call logs command as subprocess and parse JSON output
     // Use current executable (govbot binary)
@@ -2282,7 +4299,9 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
         .arg("--filter")
         .arg("default")
         .arg("--sort")
-        .arg("DESC");
+        .arg("DESC")
+        .arg("--config")
+        .arg(&config_path);
     
     // Only add --govbot-dir if it's not the default
     if !base_govbot_dir.is_empty() && base_govbot_dir != ".govbot" {
@@ -2316,6 +4335,7 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
         let filtered_stderr: Vec<&str> = stderr_str
             .lines()
             .filter(|line| !line.contains("Compiling") && !line.contains("Finished"))
+            .filter(|line| !line.starts_with(LOGS_SUMMARY_PREFIX))
             .collect();
         if !filtered_stderr.is_empty() {
             eprintln!("Warning from logs command: {}", filtered_stderr.join("\n"));
@@ -2339,7 +4359,11 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
         match serde_json::from_str::<serde_json::Value>(line) {
             Ok(entry) => {
                 total_entries += 1;
-                if filter_by_tags(&entry, &tags_to_use) {
+                let keep = match min_score {
+                    Some(min_score) => filter_by_tags_with_min_score(&entry, &tags_to_use, min_score),
+                    None => filter_by_tags(&entry, &tags_to_use),
+                };
+                if keep {
                     entries.push(entry);
                     filtered_entries += 1;
                 }
@@ -2360,12 +4384,35 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
     }
     
     // Deduplicate and sort
-    entries = deduplicate_entries(entries);
+    entries = deduplicate_entries_with_ids(entries, DedupMode::from(dedup.as_str()), normalize_identifiers);
     entries = sort_by_timestamp(entries);
     
-    // Apply limit (default is 100)
+    // Drop entries already published according to the cursor
+    let cursor_dir = PathBuf::from(&base_govbot_dir);
+    let cursor = match after.as_deref() {
+        Some(LAST_CURSOR) => read_cursor(&cursor_dir)?,
+        Some(value) => Some(value.to_string()),
+        None => None,
+    };
+    if let Some(ref cursor) = cursor {
+        let before = entries.len();
+        entries = entries_after(entries, cursor);
+        eprintln!("Skipped {} entries at or before cursor {}", before - entries.len(), cursor);
+    }
+    
+    // Order the feed; the limit below keeps the newest entries for DESC and the oldest for ASC
+    entries = sort_by_timestamp_order(entries, govbot::SortOrder::from(sort.as_str()));
+    
+    // Per-repo feeds get their own limit, so group before truncating the combined feed
+    let repo_groups = if feed_per_repo {
+        rss::group_by_repo(&entries)
+    } else {
+        Vec::new()
+    };
+    
+    // Apply limit (default is 100); paginated feeds keep every entry, the page size bounds each file
     let original_count = entries.len();
-    if let Some(lim) = limit_value {
+    if let (Some(lim), None) = (limit_value, paginate) {
         entries.truncate(lim);
         if original_count > lim {
             eprintln!("Limited feed to {} entries (RSS standard). Use --limit none to include all {} entries.", lim, original_count);
@@ -2373,58 +4420,226 @@ async fn run_build_command(cmd: Command) -> anyhow::Result<()> {
     }
     
     // Create output directory
-    fs::create_dir_all(&output_dir_path)?;
+    if !preview {
+        fs::create_dir_all(&output_dir_path)?;
+    }
     
     // Generate RSS
     eprintln!("Generating RSS feed with {} entries...", entries.len());
-    let rss_xml = rss::json_to_rss(
-        entries.clone(),
-        &feed_title,
-        &feed_description,
-        feed_link,
-        base_url.as_deref(),
-        "en-us",
-    );
+    let mut validated = 0;
+    let feed_options = get_feed_options_from_config(&config);
+    let rss_xml = if let Some(page_size) = paginate {
+        // Archive pages are written here; the newest page becomes the feed file below
+        let pages = paginate_entries(&entries, page_size as usize, govbot::SortOrder::from(sort.as_str()));
+        let total = pages.len();
+        let mut current = String::new();
+        for (idx, page_entries) in pages.into_iter().enumerate() {
+            let page = rss::FeedPage {
+                number: idx + 1,
+                total,
+                base_url: feed_link.to_string(),
+                file_name: output_filename.clone(),
+            };
+            let page_options = rss::FeedOptions {
+                page: Some(page.clone()),
+                ..feed_options.clone()
+            };
+            let page_xml = rss::json_to_rss_with_options(
+                page_entries,
+                &feed_title,
+                &feed_description,
+                feed_link,
+                base_url,
+                &language,
+                &page_options,
+            );
+            if page.is_archive() {
+                let archive_path = output_dir_path.join(page.file_name_of(page.number));
+                if validate_feed {
+                    check_feed(&archive_path, &page_xml)?;
+                    validated += 1;
+                }
+                write_build_output(&archive_path, &page_xml, "archive page", preview)?;
+            } else {
+                remove_stale_archive_pages(&output_dir_path, &page, preview)?;
+                current = page_xml;
+            }
+        }
+        current
+    } else {
+        rss::json_to_rss_with_options(
+            entries.clone(),
+            &feed_title,
+            &feed_description,
+            feed_link,
+            base_url,
+            &language,
+            &feed_options,
+        )
+    };
     
-    // Write RSS feed
+    // Write RSS feed, folding in the previous feed's items with --merge
     let rss_output_path = output_dir_path.join(&output_filename);
-    fs::write(&rss_output_path, rss_xml)?;
-    eprintln!("✓ Generated RSS feed: {}", rss_output_path.display());
+    let rss_xml = if merge && rss_output_path.exists() {
+        let existing = fs::read_to_string(&rss_output_path)?;
+        let merged = rss::merge_feeds(&rss_xml, &existing, govbot::SortOrder::from(sort.as_str()), limit_value)
+            .map_err(|e| anyhow::anyhow!("Failed to merge with existing feed {}: {}", rss_output_path.display(), e))?;
+        eprintln!("Merged with existing feed: {}", rss_output_path.display());
+        merged
+    } else {
+        rss_xml
+    };
+    write_build_output(&rss_output_path, &rss_xml, "RSS feed", preview)?;
+    if preview {
+        print_preview(&rss_output_path, &rss_xml, true)?;
+    }
+    
+    if validate_feed {
+        check_feed(&rss_output_path, &rss_xml)?;
+        validated += 1;
+    }
+    
+    for (repo, mut repo_entries) in repo_groups {
+        if let Some(lim) = limit_value {
+            repo_entries.truncate(lim);
+        }
+        let repo_xml = rss::json_to_rss_with_options(
+            repo_entries,
+            &format!("{} ({})", feed_title, repo),
+            &feed_description,
+            feed_link,
+            base_url,
+            &language,
+            &feed_options,
+        );
+        let repo_path = output_dir_path.join(format!("{}.xml", repo));
+        if validate_feed {
+            check_feed(&repo_path, &repo_xml)?;
+            validated += 1;
+        }
+        write_build_output(&repo_path, &repo_xml, "repo feed", preview)?;
+    }
+    if validate_feed {
+        eprintln!("{} Validated {} feed file(s)", icon("✓", "ok"), validated);
+    }
+    
+    // Remember the newest entry so the next build can resume with --after @last
+    if after.is_some() && !preview {
+        write_cursor(&cursor_dir, &entries)?;
+    }
     
     // Generate HTML
     eprintln!("Generating HTML index with {} entries...", entries.len());
     // Only pass title if it was explicitly set in config (not auto-generated)
-    let html_title = build_config
+    let html_title = title_override.as_deref().or_else(|| build_config
         .and_then(|p| p.get("title"))
         .and_then(|t| t.as_str())
-        .filter(|s| !s.trim().is_empty());
-    let html_content = rss::json_to_html(
+        .filter(|s| !s.trim().is_empty()));
+    let html_content = rss::json_to_html_with_options(
         entries,
         html_title,
         feed_link,
-        base_url.as_deref(),
+        base_url,
+        Some(&output_filename),
+        &language,
+        &feed_options,
     );
     
     // Write HTML index
     let html_output_path = output_dir_path.join("index.html");
-    fs::write(&html_output_path, html_content)?;
-    eprintln!("✓ Generated HTML index: {}", html_output_path.display());
+    write_build_output(&html_output_path, &html_content, "HTML index", preview)?;
+    if preview {
+        print_preview(&html_output_path, &html_content, false)?;
+    }
     eprintln!("  Tags included: {}", tags_to_use.join(", "));
     
     Ok(())
 }
 
+/// `--validate-feed` check of one generated feed file, listing each problem found
+fn check_feed(path: &Path, xml: &str) -> anyhow::Result<()> {
+    if let Err(problems) = rss::validate_feed(xml) {
+        for problem in &problems {
+            eprintln!("  {} {}", icon("✗", "-"), problem);
+        }
+        return Err(anyhow::anyhow!(
+            "Feed validation failed for {} ({} problem(s))",
+            path.display(),
+            problems.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Remove archive pages left over from a build that had more pages than this one
+fn remove_stale_archive_pages(output_dir: &Path, current: &rss::FeedPage, preview: bool) -> anyhow::Result<()> {
+    let Ok(dir_entries) = fs::read_dir(output_dir) else {
+        return Ok(());
+    };
+    let feed_file = Path::new(&current.file_name);
+    let stem = feed_file.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let extension = feed_file.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    let mut stale: Vec<PathBuf> = dir_entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix(&stem)?.strip_prefix('-')?.strip_suffix(extension.as_str()))
+                .and_then(|number| number.parse::<usize>().ok())
+                .is_some_and(|number| number >= current.number)
+        })
+        .collect();
+    stale.sort();
+    for path in stale {
+        if preview {
+            eprintln!("Preview: would remove stale archive page {}", path.display());
+        } else {
+            fs::remove_file(&path)?;
+            eprintln!("Removed stale archive page: {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Print a previewed file to stdout under a `==> path <==` header (relative to the working
+/// directory), laid out like `tail -n +1` prints several files so the preview can be diffed
+/// against the files on disk
+fn print_preview(path: &Path, contents: &str, first: bool) -> anyhow::Result<()> {
+    let cwd = std::env::current_dir()?;
+    let shown = path.strip_prefix(&cwd).unwrap_or(path);
+    let mut stdout = io::stdout().lock();
+    if !first {
+        writeln!(stdout)?;
+    }
+    writeln!(stdout, "==> {} <==", shown.display())?;
+    stdout.write_all(contents.as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Write one generated build file, or with `--preview` only say what would be written
+fn write_build_output(path: &Path, contents: &str, label: &str, preview: bool) -> anyhow::Result<()> {
+    if preview {
+        eprintln!("Preview: would write {} {} ({} bytes)", label, path.display(), contents.len());
+    } else {
+        fs::write(path, contents)?;
+        eprintln!("{} Generated {}: {}", icon("✓", "ok"), label, path.display());
+    }
+    Ok(())
+}
+
 async fn run_update_command() -> anyhow::Result<()> {
     let install_script_url = "https://raw.githubusercontent.com/windy-civi/toolkit/main/actions/govbot/scripts/install-nightly.sh";
     
-    eprintln!("🔄 Updating govbot to latest nightly version...");
+    eprintln!("{} Updating govbot to latest nightly version...", icon("🔄", "update"));
     eprintln!("Downloading and running install script from: {}", install_script_url);
     
     // Execute the install script by piping curl directly to sh
     // This avoids issues with shebang lines being interpreted as commands
     let mut cmd = ProcessCommand::new("sh");
     cmd.arg("-c");
-    cmd.arg(&format!("curl -fsSL {} | sh", install_script_url));
+    cmd.arg(format!("curl -fsSL {} | sh", install_script_url));
     
     // Inherit stdin/stdout/stderr so the install script can interact with the user
     cmd.stdin(std::process::Stdio::inherit());
@@ -2434,7 +4649,7 @@ async fn run_update_command() -> anyhow::Result<()> {
     let status = cmd.status()?;
     
     if status.success() {
-        eprintln!("\n✅ Update completed successfully!");
+        eprintln!("\n{} Update completed successfully!", icon("✅", "ok"));
         eprintln!("You may need to restart your terminal or run 'source ~/.zshrc' (or your shell profile) to use the updated version.");
     } else {
         return Err(anyhow::anyhow!("Update failed with exit code: {}", status.code().unwrap_or(-1)));
@@ -2446,6 +4661,9 @@ async fn run_update_command() -> anyhow::Result<()> {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    let env_set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+    let _ = PLAIN_OUTPUT.set(args.no_emoji || env_set("NO_COLOR") || env_set("GOVBOT_PLAIN"));
+    let _ = CONFIG_PATH.set(args.config.clone());
 
     match args.command {
         Some(cmd @ Command::Clone { .. }) => {
@@ -2457,6 +4675,9 @@ async fn main() -> anyhow::Result<()> {
         Some(cmd @ Command::Logs { .. }) => {
             run_logs_command(cmd).await
         }
+        Some(cmd @ Command::Diff { .. }) => {
+            run_diff_command(cmd).await
+        }
         Some(cmd @ Command::Load { .. }) => {
             run_load_command(cmd).await
         }
@@ -2472,6 +4693,15 @@ async fn main() -> anyhow::Result<()> {
         Some(cmd @ Command::Init { .. }) => {
             run_init_command(cmd).await
         }
+        Some(cmd @ Command::Export { .. }) => {
+            run_export_command(cmd)
+        }
+        Some(cmd @ Command::ListTags { .. }) => {
+            run_list_tags_command(cmd)
+        }
+        Some(cmd @ Command::Doctor { .. }) => {
+            run_doctor_command(cmd).await
+        }
         None => {
             print_available_commands();
             Ok(())
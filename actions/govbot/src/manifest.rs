@@ -0,0 +1,159 @@
+//! Repo manifests: a project-provided list of locales that extends (or replaces)
+//! the built-in `WorkingLocale` set used by `clone`, `delete`, and `logs`.
+//!
+//! `repos.yml`:
+//!
+//! ```yaml
+//! replace: false        # true drops the built-in locales from `all`
+//! repos:
+//!   - wy                # uses GOVBOT_REPO_URL_TEMPLATE as usual
+//!   - locale: acme
+//!     repo: acme-pipeline   # repo name under the template's org, or a full clone URL
+//!     name: Acme County     # display name shown by `clone --status`
+//! ```
+//!
+//! `repos.txt` lists one `locale [repo]` pair per line; `#` starts a comment.
+
+use crate::error::{Error, Result};
+use crate::locale::WorkingLocale;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Manifest file names looked for in the govbot directory, in order
+pub const MANIFEST_FILES: [&str; 2] = ["repos.yml", "repos.txt"];
+
+/// A locale listed in a manifest
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "ManifestEntry")]
+pub struct ManifestRepo {
+    pub locale: String,
+    /// Repo name or full clone URL; `None` builds both from the URL template
+    pub repo: Option<String>,
+    /// Display name; `None` falls back to the built-in locale's name
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestEntry {
+    Locale(String),
+    Full {
+        locale: String,
+        repo: Option<String>,
+        #[serde(default)]
+        name: Option<String>,
+    },
+}
+
+impl From<ManifestEntry> for ManifestRepo {
+    fn from(entry: ManifestEntry) -> Self {
+        let (locale, repo, name) = match entry {
+            ManifestEntry::Locale(locale) => (locale, None, None),
+            ManifestEntry::Full { locale, repo, name } => (locale, repo, name),
+        };
+        Self {
+            locale: locale.trim().to_lowercase(),
+            repo: repo.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+            name: name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()),
+        }
+    }
+}
+
+/// Locales and repos loaded from `repos.yml` / `repos.txt`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct RepoManifest {
+    /// Replace the built-in locale set instead of extending it
+    #[serde(default)]
+    pub replace: bool,
+    #[serde(default)]
+    pub repos: Vec<ManifestRepo>,
+}
+
+impl RepoManifest {
+    /// Load a manifest; `.yml`/`.yaml` files are parsed as YAML, anything else as a plain list
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let is_yaml = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yml") | Some("yaml")
+        );
+        if is_yaml {
+            serde_yaml::from_str(&contents)
+                .map_err(|e| Error::Config(format!("Invalid manifest {}: {}", path.display(), e)))
+        } else {
+            Ok(Self::parse_list(&contents))
+        }
+    }
+
+    /// Parse the `repos.txt` format
+    pub fn parse_list(contents: &str) -> Self {
+        let repos = contents
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let mut parts = line.split_whitespace();
+                ManifestRepo::from(ManifestEntry::Full {
+                    locale: parts.next().unwrap_or_default().to_string(),
+                    repo: parts.next().map(str::to_string),
+                    name: None,
+                })
+            })
+            .collect();
+        Self {
+            replace: false,
+            repos,
+        }
+    }
+
+    /// Find a manifest in `govbot_dir` (the directory holding `repos/`)
+    pub fn discover(govbot_dir: &Path) -> Option<PathBuf> {
+        MANIFEST_FILES
+            .iter()
+            .map(|name| govbot_dir.join(name))
+            .find(|path| path.is_file())
+    }
+
+    /// Locale codes `all` expands to: manifest locales plus (unless `replace`) the built-ins
+    pub fn locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = if self.replace {
+            Vec::new()
+        } else {
+            WorkingLocale::all()
+                .iter()
+                .map(|l| l.as_lowercase().to_string())
+                .collect()
+        };
+        for repo in &self.repos {
+            if !locales.contains(&repo.locale) {
+                locales.push(repo.locale.clone());
+            }
+        }
+        locales
+    }
+
+    /// Whether the manifest lists `locale`
+    pub fn contains(&self, locale: &str) -> bool {
+        let locale = locale.to_lowercase();
+        self.repos.iter().any(|r| r.locale == locale)
+    }
+
+    /// Display name a manifest entry gives `locale`
+    pub fn display_name(&self, locale: &str) -> Option<&str> {
+        let locale = locale.to_lowercase();
+        self.repos
+            .iter()
+            .find(|r| r.locale == locale)
+            .and_then(|r| r.name.as_deref())
+    }
+
+    /// Per-locale repo overrides, in the form `git::set_repo_overrides` expects
+    pub fn repo_overrides(&self) -> HashMap<String, String> {
+        self.repos
+            .iter()
+            .filter_map(|r| r.repo.clone().map(|repo| (r.locale.clone(), repo)))
+            .collect()
+    }
+}
@@ -1,25 +1,186 @@
-use crate::config::Config;
+use crate::config::{Config, JoinOption};
 use crate::error::{Error, Result};
 use crate::git;
 use crate::types::{
-    FileWithTimestamp, LogContent, LogEntry, Metadata,
+    FileWithTimestamp, LogContent, LogEntry,
     VoteEventResult,
 };
 use async_stream::stream;
 use futures::Stream;
 use jwalk::WalkDir;
 use regex::Regex;
+use serde::Deserialize;
 use std::path::Path;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// JSON Schema that regular (non vote-event) log entries must satisfy when `Config::validate` is set;
+/// the same action log schema the pipeline's formatted output is published with
+const LOG_ENTRY_SCHEMA: &str = include_str!("../../../schemas/ocdfiles.bill.log.action.schema.json");
+
+/// Compiled log entry schema, built on first use
+fn log_entry_validator() -> &'static jsonschema::Validator {
+    static VALIDATOR: OnceLock<jsonschema::Validator> = OnceLock::new();
+    VALIDATOR.get_or_init(|| {
+        let schema: serde_json::Value =
+            serde_json::from_str(LOG_ENTRY_SCHEMA).expect("embedded log entry schema is valid JSON");
+        jsonschema::validator_for(&schema).expect("embedded log entry schema compiles")
+    })
+}
+
+/// jwalk parallelism for an optional thread count: `None` keeps jwalk's default
+/// (a rayon pool across all cores), `1` walks serially on the calling thread, and larger
+/// counts share one rayon pool per size for the life of the process
+pub fn walk_parallelism(threads: Option<usize>) -> jwalk::Parallelism {
+    let default = jwalk::Parallelism::RayonDefaultPool {
+        busy_timeout: std::time::Duration::from_secs(1),
+    };
+    match threads {
+        None => default,
+        Some(0) | Some(1) => jwalk::Parallelism::Serial,
+        Some(n) => match walk_pool(n) {
+            Some(pool) => jwalk::Parallelism::RayonExistingPool {
+                pool,
+                busy_timeout: Some(std::time::Duration::from_secs(1)),
+            },
+            None => default,
+        },
+    }
+}
+
+/// The shared `n`-thread walk pool, built on first use (None if rayon can't start it)
+fn walk_pool(n: usize) -> Option<Arc<jwalk::rayon::ThreadPool>> {
+    static POOLS: OnceLock<Mutex<HashMap<usize, Arc<jwalk::rayon::ThreadPool>>>> = OnceLock::new();
+    let mut pools = POOLS.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(pool) = pools.get(&n) {
+        return Some(pool.clone());
+    }
+    let pool = Arc::new(jwalk::rayon::ThreadPoolBuilder::new().num_threads(n).build().ok()?);
+    pools.insert(n, pool.clone());
+    Some(pool)
+}
+
+/// Whether `path` has a log file extension: plain `.json`, or gzip-compressed `.json.gz`
+/// (any `.gz` is accepted, since callers only look inside `logs/` directories)
+pub fn is_log_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "json" || ext == "gz")
+}
+
+/// Open a log file for reading, transparently decompressing `.gz` files
+pub fn open_log_reader(path: &Path) -> std::io::Result<Box<dyn std::io::Read + Send>> {
+    let file = std::io::BufReader::new(std::fs::File::open(path)?);
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        Ok(Box::new(flate2::read::GzDecoder::new(file)))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+/// Read a log file to a string, transparently decompressing `.gz` files
+pub fn read_log_to_string(path: &Path) -> std::io::Result<String> {
+    let mut contents = String::new();
+    std::io::Read::read_to_string(&mut open_log_reader(path)?, &mut contents)?;
+    Ok(contents)
+}
+
+/// Async `read_log_to_string`, for use inside the processing stream
+async fn read_log_to_string_async(path: &Path) -> Result<String> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || read_log_to_string(&path))
+        .await
+        .map_err(|e| Error::Io(std::io::Error::other(format!("Task join error: {}", e))))?
+        .map_err(Error::Io)
+}
+
+/// Size of `path` when it exceeds `max_file_size` and the file should be skipped unread
+/// (`None` when it fits or there is no limit). Shared by the pipeline and `govbot logs`.
+pub fn oversized_file(path: &Path, max_file_size: Option<u64>) -> std::io::Result<Option<u64>> {
+    let Some(max) = max_file_size else {
+        return Ok(None);
+    };
+    let size = std::fs::metadata(path)?.len();
+    Ok((size > max).then_some(size))
+}
+
+/// Session directories (`country:*/state:*/sessions/*` under `repo_path`) with no `*.json`
+/// file inside any `logs/` directory, in path order. Discovery skips these silently, so this
+/// is how coverage gaps are found.
+pub fn find_empty_sessions(repo_path: &Path) -> Vec<std::path::PathBuf> {
+    let is_session_dir = |path: &Path| {
+        let component = |p: Option<&Path>, prefix: &str| {
+            p.and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(prefix))
+        };
+        let sessions = path.parent();
+        let state = sessions.and_then(Path::parent);
+        let country = state.and_then(Path::parent);
+        sessions.and_then(|p| p.file_name()).is_some_and(|n| n == "sessions")
+            && component(state, "state:")
+            && component(country, "country:")
+    };
+    let has_logs = |session: &Path| {
+        WalkDir::new(session).into_iter().flatten().any(|entry| {
+            let path = entry.path();
+            entry.file_type().is_file()
+                && is_log_file(&path)
+                && path.parent().and_then(|p| p.file_name()).is_some_and(|n| n == "logs")
+        })
+    };
+
+    WalkDir::new(repo_path)
+        .sort(true)
+        .min_depth(4)
+        .max_depth(4)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_dir())
+        .map(|entry| entry.path())
+        .filter(|path| is_session_dir(path) && !has_logs(path))
+        .collect()
+}
+
+/// Log fields kept by `Config::streaming_parse`; serde skips everything else while reading
+#[derive(Deserialize)]
+struct LogFields {
+    action: Option<serde_json::Value>,
+    bill_id: Option<serde_json::Value>,
+    bill_identifier: Option<serde_json::Value>,
+    timestamp: Option<serde_json::Value>,
+}
+
+/// Per-entry hook applied by `PipelineProcessor::with_transform`
+pub type EntryTransform = Arc<dyn Fn(&mut LogEntry) + Send + Sync>;
 
 /// Main processor for pipeline log files
 pub struct PipelineProcessor {
     config: Config,
+    transform: Option<EntryTransform>,
+    /// Files skipped by `Config::max_file_size` across this processor's runs
+    oversized: Arc<AtomicUsize>,
 }
 
 impl PipelineProcessor {
     /// Create a new processor with the given configuration
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            transform: None,
+            oversized: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Number of log files skipped so far for exceeding `Config::max_file_size`
+    pub fn oversized_skipped(&self) -> usize {
+        self.oversized.load(Ordering::Relaxed)
+    }
+
+    /// Run `transform` on every entry after joins are applied and before it is yielded,
+    /// e.g. to add derived fields or redact content
+    pub fn with_transform(mut self, transform: impl Fn(&mut LogEntry) + Send + Sync + 'static) -> Self {
+        self.transform = Some(Arc::new(transform));
+        self
     }
 
     /// Process log files and return a reactive stream of log entries
@@ -27,6 +188,8 @@ impl PipelineProcessor {
     pub fn process(&self) -> impl Stream<Item = Result<LogEntry>> {
         let config = self.config.clone();
         let config_for_discovery = config.clone();
+        let transform = self.transform.clone();
+        let oversized = self.oversized.clone();
         Box::pin(stream! {
             // Step 1: Discover files (run in blocking thread pool for async compatibility)
             // jwalk is fast but synchronous, so we run it in spawn_blocking
@@ -39,8 +202,7 @@ impl PipelineProcessor {
                     return;
                 }
                 Err(e) => {
-                    yield Err(Error::Io(std::io::Error::new(
-                        std::io::ErrorKind::Other,
+                    yield Err(Error::Io(std::io::Error::other(
                         format!("Task join error: {}", e)
                     )));
                     return;
@@ -55,8 +217,24 @@ impl PipelineProcessor {
 
             // Step 4: Process each file and yield log entries
             for file in limited_files {
+                match Self::is_oversized(&config, &file) {
+                    Ok(true) => {
+                        oversized.fetch_add(1, Ordering::Relaxed);
+                        continue;
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        yield Err(e);
+                        continue;
+                    }
+                }
                 match Self::process_file_internal(&config, &file).await {
-                    Ok(Some(entry)) => yield Ok(entry),
+                    Ok(Some(mut entry)) => {
+                        if let Some(transform) = &transform {
+                            transform(&mut entry);
+                        }
+                        yield Ok(entry)
+                    }
                     Ok(None) => continue,
                     Err(e) => yield Err(e),
                 }
@@ -64,7 +242,7 @@ impl PipelineProcessor {
         })
     }
 
-    /// Discover all JSON files with 'logs/' in their path
+    /// Discover all JSON (or gzipped JSON) files with 'logs/' in their path
     /// Uses jwalk for fast parallel filesystem traversal
     fn discover_files_internal(config: &Config) -> Result<Vec<FileWithTimestamp>> {
         let timestamp_regex = Regex::new(r"/logs/(\d{8}T\d{6}Z)_")?;
@@ -91,6 +269,8 @@ impl PipelineProcessor {
             // Use jwalk for fast parallel traversal
             // jwalk uses rayon internally for parallel processing
             for entry_result in WalkDir::new(&search_path)
+                .parallelism(walk_parallelism(config.walk_threads))
+                .max_depth(config.max_depth.unwrap_or(usize::MAX))
                 .process_read_dir(|_depth, _path, _read_dir_state, _children| {
                     // Optional: customize directory reading behavior
                 })
@@ -106,25 +286,23 @@ impl PipelineProcessor {
                 }
 
                 let path = entry.path();
-                if let Some(ext) = path.extension() {
-                    if ext == "json" {
-                        let path_str = path.to_string_lossy();
-                        if path_str.contains("/logs/") {
-                            // Extract timestamp
-                            let timestamp = timestamp_regex
-                                .captures(&path_str)
-                                .and_then(|caps| caps.get(1))
-                                .map(|m| m.as_str().to_string());
-
-                            // Calculate relative path
-                            let relative_path = Self::calculate_relative_path(&path, search_dir)?;
-
-                            files.push(FileWithTimestamp {
-                                path: path.to_path_buf(),
-                                timestamp,
-                                relative_path,
-                            });
-                        }
+                if is_log_file(&path) {
+                    let path_str = path.to_string_lossy();
+                    if path_str.contains("/logs/") {
+                        // Extract timestamp
+                        let timestamp = timestamp_regex
+                            .captures(&path_str)
+                            .and_then(|caps| caps.get(1))
+                            .map(|m| m.as_str().to_string());
+
+                        // Calculate relative path
+                        let relative_path = Self::calculate_relative_path(&path, search_dir)?;
+
+                        files.push(FileWithTimestamp {
+                            path: path.to_path_buf(),
+                            timestamp,
+                            relative_path,
+                        });
                     }
                 }
             }
@@ -158,7 +336,7 @@ impl PipelineProcessor {
                     continue;
                 }
 
-                if path.extension().map(|e| e == "json").unwrap_or(false) {
+                if is_log_file(path) {
                     let path_str_lossy = path.to_string_lossy();
                     if path_str_lossy.contains("/logs/") {
                         let timestamp = timestamp_regex
@@ -192,6 +370,14 @@ impl PipelineProcessor {
 
             // Process each file
             for file in limited_files {
+                match Self::is_oversized(&config, &file) {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(e) => {
+                        yield Err(e);
+                        continue;
+                    }
+                }
                 match Self::process_file_internal(&config, &file).await {
                     Ok(Some(entry)) => yield Ok(entry),
                     Ok(None) => continue,
@@ -279,6 +465,22 @@ impl PipelineProcessor {
         }
     }
 
+    /// Whether `file` exceeds `config.max_file_size`, warning that it is skipped
+    fn is_oversized(config: &Config, file: &FileWithTimestamp) -> Result<bool> {
+        match oversized_file(&file.path, config.max_file_size)? {
+            Some(size) => {
+                eprintln!(
+                    "Warning: Skipping {} ({} bytes exceeds max file size of {} bytes)",
+                    file.relative_path,
+                    size,
+                    config.max_file_size.unwrap_or_default()
+                );
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Process a single file and return a log entry
     async fn process_file_internal(config: &Config, file: &FileWithTimestamp) -> Result<Option<LogEntry>> {
         // Check if it's a vote event file
@@ -292,7 +494,7 @@ impl PipelineProcessor {
     }
 
     /// Process a vote event file
-    async fn process_vote_event_file_internal(_config: &Config, file: &FileWithTimestamp) -> Result<Option<LogEntry>> {
+    async fn process_vote_event_file_internal(config: &Config, file: &FileWithTimestamp) -> Result<Option<LogEntry>> {
         // Extract vote event result from filename
         let vote_event_regex = Regex::new(r"\.vote_event\.([^.]+)\.")?;
         let result = vote_event_regex
@@ -301,38 +503,85 @@ impl PipelineProcessor {
             .map(|m| VoteEventResult::from(m.as_str()))
             .unwrap_or(VoteEventResult::Unknown);
 
-        let log_content = LogContent::VoteEvent { result };
+        let detail = if config.vote_event_detail {
+            let json_content = read_log_to_string_async(&file.path).await?;
+            serde_json::from_str(&json_content)?
+        } else {
+            serde_json::Value::Null
+        };
+
+        let log_content = LogContent::VoteEvent { result, detail };
 
         let entry = LogEntry {
             log: log_content,
             filename: file.relative_path.clone(),
+            metadata: Self::load_metadata_if_needed(config, &file.path).await?,
         };
 
         Ok(Some(entry))
     }
 
     /// Process a regular (non-vote-event) file
-    async fn process_regular_file_internal(_config: &Config, file: &FileWithTimestamp) -> Result<Option<LogEntry>> {
+    async fn process_regular_file_internal(config: &Config, file: &FileWithTimestamp) -> Result<Option<LogEntry>> {
         // Read and parse JSON content
-        let json_content = tokio::fs::read_to_string(&file.path).await?;
-        let log_value: serde_json::Value = serde_json::from_str(&json_content)?;
+        let log_value = if config.streaming_parse {
+            let path = file.path.clone();
+            tokio::task::spawn_blocking(move || Self::read_log_fields(&path))
+                .await
+                .map_err(|e| Error::Io(std::io::Error::other(format!("Task join error: {}", e))))??
+        } else {
+            let json_content = read_log_to_string_async(&file.path).await?;
+            serde_json::from_str(&json_content)?
+        };
+
+        if config.validate {
+            let errors: Vec<String> = log_entry_validator()
+                .iter_errors(&log_value)
+                .map(|e| e.to_string())
+                .collect();
+            if !errors.is_empty() {
+                return Err(Error::Validation(format!(
+                    "{}: {}",
+                    file.relative_path,
+                    errors.join("; ")
+                )));
+            }
+        }
 
         let log_content = LogContent::Full(log_value);
 
         let entry = LogEntry {
             log: log_content,
             filename: file.relative_path.clone(),
+            metadata: Self::load_metadata_if_needed(config, &file.path).await?,
         };
 
         Ok(Some(entry))
     }
 
+    /// Stream a log file through a reader, keeping only the fields a log entry needs; other
+    /// fields (e.g. embedded documents) are skipped without being materialized
+    fn read_log_fields(path: &Path) -> Result<serde_json::Value> {
+        let reader = open_log_reader(path)?;
+        let fields: LogFields = serde_json::from_reader(reader)?;
+        let mut log = serde_json::Map::new();
+        for (key, value) in [
+            ("action", fields.action),
+            ("bill_id", fields.bill_id),
+            ("bill_identifier", fields.bill_identifier),
+            ("timestamp", fields.timestamp),
+        ] {
+            if let Some(value) = value {
+                log.insert(key.to_string(), value);
+            }
+        }
+        Ok(serde_json::Value::Object(log))
+    }
+
     /// Load metadata from metadata.json if it exists and join options require it
-    /// Note: Currently not used since join_options are empty, but kept for potential future use
-    #[allow(dead_code)]
-    async fn load_metadata_if_needed(config: &Config, log_path: &Path) -> Result<Option<Metadata>> {
+    async fn load_metadata_if_needed(config: &Config, log_path: &Path) -> Result<Option<serde_json::Value>> {
         // Check if we need metadata at all
-        if config.join_options.is_empty() {
+        if !config.join_options.contains(&JoinOption::FullMetadata) {
             return Ok(None);
         }
 
@@ -353,7 +602,7 @@ impl PipelineProcessor {
 
         // Read and parse metadata
         let metadata_content = tokio::fs::read_to_string(&metadata_path).await?;
-        let metadata: Metadata = serde_json::from_str(&metadata_content)?;
+        let metadata: serde_json::Value = serde_json::from_str(&metadata_content)?;
 
         Ok(Some(metadata))
     }
@@ -1,7 +1,10 @@
+use crate::config::SortOrder;
 use crate::rss;
+use crate::types::{action_date, action_description, extract_bill_identifier, extract_path_info, normalize_bill_identifier};
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
@@ -13,19 +16,162 @@ pub fn load_config(config_path: &Path) -> Result<Value> {
         .with_context(|| format!("Failed to parse YAML: {}", config_path.display()))
 }
 
-/// Get repos list from config, handling 'all' special case
-pub fn get_repos_from_config(config: &Value) -> Vec<String> {
-    if let Some(repos) = config.get("repos") {
-        if let Some(arr) = repos.as_array() {
-            return arr
-                .iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                .collect();
-        } else if let Some(s) = repos.as_str() {
-            return vec![s.to_string()];
+/// Get repos list from config, handling 'all' special case.
+/// Names defined under `repo_groups:` expand to their member locales (deduplicated, in order).
+/// Names are lowercased, and any that isn't a group, `all`, or a built-in locale is an error.
+pub fn get_repos_from_config(config: &Value) -> Result<Vec<String>> {
+    let builtin: Vec<String> = crate::locale::WorkingLocale::all()
+        .iter()
+        .map(|l| l.as_lowercase().to_string())
+        .collect();
+    get_repos_from_config_with_locales(config, &builtin)
+}
+
+/// `get_repos_from_config`, accepting the locales in `known_locales` (e.g. the built-in set
+/// extended by a repo manifest) instead of only the built-in ones
+pub fn get_repos_from_config_with_locales(config: &Value, known_locales: &[String]) -> Result<Vec<String>> {
+    let names: Vec<String> = match config.get("repos") {
+        Some(Value::Array(arr)) => arr
+            .iter()
+            .filter_map(|v| v.as_str().map(|s| s.to_string()))
+            .collect(),
+        Some(Value::String(s)) => vec![s.to_string()],
+        _ => return Ok(vec!["all".to_string()]),
+    };
+    let groups = config.get("repo_groups").and_then(|g| g.as_object());
+    let is_known = |code: &str| known_locales.iter().any(|known| known.eq_ignore_ascii_case(code));
+
+    let mut repos: Vec<String> = Vec::new();
+    let mut push = |repo: String| {
+        if !repos.contains(&repo) {
+            repos.push(repo);
+        }
+    };
+    for name in names {
+        let key = name.trim().to_lowercase();
+        if let Some((group_name, group)) = groups.and_then(|groups| groups.iter().find(|(g, _)| g.to_lowercase() == key)) {
+            let members = group
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("repo_groups.{} must be a list of locales", group_name))?;
+            for member in members.iter().filter_map(|m| m.as_str()) {
+                let member = member.trim().to_lowercase();
+                if !is_known(&member) {
+                    return Err(anyhow::anyhow!("Unknown locale '{}' in repo_groups.{}", member, group_name));
+                }
+                push(member);
+            }
+        } else if key == "all" || is_known(&key) {
+            push(key);
+        } else if let Some(groups) = groups {
+            let mut defined: Vec<&str> = groups.keys().map(|k| k.as_str()).collect();
+            defined.sort();
+            return Err(anyhow::anyhow!(
+                "Unknown repo group '{}' in repos (defined groups: {})",
+                name,
+                defined.join(", ")
+            ));
+        } else {
+            return Err(anyhow::anyhow!("Unknown locale '{}' in repos", name));
         }
     }
-    vec!["all".to_string()]
+    Ok(repos)
+}
+
+/// Feed options from `build.ttl` (minutes), `build.skip_hours` (0-23), `build.skip_days`
+/// (day names), `build.strip_html` / `build.strip_html_index` (default on),
+/// `build.link_template`, and `build.category_domain`, plus each tag's `author` and `label`
+/// under `tags:`. Out-of-range hours and unknown days are dropped.
+pub fn get_feed_options_from_config(config: &Value) -> rss::FeedOptions {
+    const DAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+    let tag_authors = get_tag_strings_from_config(config, "author");
+    let tag_labels = get_tag_strings_from_config(config, "label");
+    let Some(build) = config.get("build") else {
+        return rss::FeedOptions {
+            tag_authors,
+            tag_labels,
+            ..rss::FeedOptions::default()
+        };
+    };
+    let list = |key: &str| build.get(key).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let text = |key: &str| {
+        build
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+    };
+
+    rss::FeedOptions {
+        ttl_minutes: build
+            .get("ttl")
+            .and_then(|v| v.as_u64())
+            .and_then(|m| u32::try_from(m).ok()),
+        skip_hours: list("skip_hours")
+            .iter()
+            .filter_map(|h| h.as_u64())
+            .filter(|h| *h < 24)
+            .map(|h| h as u32)
+            .collect(),
+        skip_days: list("skip_days")
+            .iter()
+            .filter_map(|d| d.as_str())
+            .filter_map(|d| DAYS.iter().find(|day| day.eq_ignore_ascii_case(d.trim())))
+            .map(|d| d.to_string())
+            .collect(),
+        strip_html: build.get("strip_html").and_then(|v| v.as_bool()).unwrap_or(true),
+        strip_html_index: build
+            .get("strip_html_index")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        page: None,
+        link_template: text("link_template"),
+        tag_authors,
+        category_domain: text("category_domain"),
+        tag_labels,
+    }
+}
+
+/// Non-empty string `key` (e.g. `author`) of each tag under `tags:`, keyed by tag name
+fn get_tag_strings_from_config(config: &Value, key: &str) -> BTreeMap<String, String> {
+    let Some(tags) = config.get("tags").and_then(|t| t.as_object()) else {
+        return BTreeMap::new();
+    };
+    tags.iter()
+        .filter_map(|(name, tag)| {
+            let value = tag.get(key)?.as_str()?.trim();
+            (!value.is_empty()).then(|| (name.clone(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Whether a changed `path` should trigger a `build --watch` rebuild: the config file itself
+/// or a JSON file inside a `tags/` directory
+pub fn is_watch_trigger(path: &Path, config_path: &Path) -> bool {
+    if path == config_path {
+        return true;
+    }
+    path.extension().is_some_and(|ext| ext == "json")
+        && path.parent().and_then(|p| p.file_name()).is_some_and(|name| name == "tags")
+}
+
+/// Feed language from `build.language` (e.g. `fr-ca`), if set
+pub fn get_feed_language_from_config(config: &Value) -> Option<String> {
+    config
+        .get("build")?
+        .get("language")?
+        .as_str()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+}
+
+/// Default per-repo limit for `logs` from the `logs.limit` key (a number or `none`)
+pub fn get_logs_limit_from_config(config: &Value) -> Option<String> {
+    match config.get("logs")?.get("limit")? {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
 }
 
 /// Filter entries by tags
@@ -63,6 +209,28 @@ pub fn filter_by_tags(entry: &Value, tag_names: &[String]) -> bool {
     false
 }
 
+/// Like `filter_by_tags`, but a tag only counts when its `final_score` is at least `min_score`
+/// (tags without a score never count)
+pub fn filter_by_tags_with_min_score(entry: &Value, tag_names: &[String], min_score: f64) -> bool {
+    if !filter_by_tags(entry, tag_names) {
+        return false;
+    }
+    let Some(tags) = entry.get("tags").and_then(|t| t.as_object()) else {
+        return false;
+    };
+    tags.iter()
+        .filter(|(name, _)| tag_names.is_empty() || tag_names.contains(name))
+        .filter_map(|(_, score)| score.get("final_score").and_then(|s| s.as_f64()))
+        .any(|score| score >= min_score)
+}
+
+/// Remove excluded tags from the list of tags to publish
+pub fn exclude_tags_from(tags: Vec<String>, excluded: &[String]) -> Vec<String> {
+    tags.into_iter()
+        .filter(|tag| !excluded.contains(tag))
+        .collect()
+}
+
 /// Deduplicate entries by GUID
 pub fn deduplicate_entries(entries: Vec<Value>) -> Vec<Value> {
     let mut seen = HashSet::new();
@@ -79,12 +247,183 @@ pub fn deduplicate_entries(entries: Vec<Value>) -> Vec<Value> {
     result
 }
 
-/// Sort entries by timestamp (newest first)
+/// How `publish` recognizes duplicate entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupMode {
+    /// Same GUID (log source path)
+    Guid,
+    /// Same GUID, or same bill, action description, and action date under different log paths
+    Content,
+}
+
+impl From<&str> for DedupMode {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "content" => DedupMode::Content,
+            _ => DedupMode::Guid,
+        }
+    }
+}
+
+/// Deduplicate entries by `mode`. Content duplicates (e.g. a re-scraped action saved under a
+/// new filename) collapse to the newest entry; entries missing a jurisdiction, bill id, action
+/// description, or action date are only deduplicated by GUID.
+pub fn deduplicate_entries_by(entries: Vec<Value>, mode: DedupMode) -> Vec<Value> {
+    deduplicate_entries_with_ids(entries, mode, false)
+}
+
+/// `deduplicate_entries_by`, optionally comparing bill ids by `normalize_bill_identifier`
+/// so `HB 1` and `hb-1` count as the same bill; entries keep their raw ids
+pub fn deduplicate_entries_with_ids(entries: Vec<Value>, mode: DedupMode, normalize_identifiers: bool) -> Vec<Value> {
+    let entries = deduplicate_entries(entries);
+    if mode == DedupMode::Guid {
+        return entries;
+    }
+
+    let mut kept: HashMap<ContentKey, usize> = HashMap::new();
+    let mut result: Vec<Value> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let Some(key) = content_key(&entry, normalize_identifiers) else {
+            result.push(entry);
+            continue;
+        };
+        match kept.get(&key) {
+            Some(&i) => {
+                if entry_time(&entry) > entry_time(&result[i]) {
+                    result[i] = entry;
+                }
+            }
+            None => {
+                kept.insert(key, result.len());
+                result.push(entry);
+            }
+        }
+    }
+    result
+}
+
+/// Jurisdiction (country, state, session), bill id, action description, and action date
+type ContentKey = ((String, String, String), String, String, String);
+
+/// Key for content deduplication. Bill ids repeat across states and sessions, so the key
+/// includes the jurisdiction from `sources.log`; entries without one, or without an action
+/// date, have no key.
+fn content_key(entry: &Value, normalize_identifiers: bool) -> Option<ContentKey> {
+    let log = entry.get("log")?;
+    let jurisdiction = entry
+        .get("sources")
+        .and_then(|sources| sources.get("log"))
+        .and_then(|path| path.as_str())
+        .and_then(extract_path_info)?;
+    let bill_id = extract_bill_identifier(entry)?;
+    let bill_id = if normalize_identifiers { normalize_bill_identifier(&bill_id) } else { bill_id };
+    let description = action_description(log)?;
+    let date = action_date(log)?;
+    Some((jurisdiction, bill_id, description.trim().to_string(), date.to_string()))
+}
+
+/// An entry's `timestamp` (compact or RFC 3339) as a point in time; missing or unparseable
+/// timestamps count as the earliest possible time
+fn entry_time(entry: &Value) -> DateTime<Utc> {
+    entry
+        .get("timestamp")
+        .and_then(|t| t.as_str())
+        .and_then(rss::parse_timestamp)
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+/// Sort entries by timestamp (newest first). Timestamps are compared as times, so compact and
+/// RFC 3339 values interleave correctly; ties fall back to the raw strings.
 pub fn sort_by_timestamp(mut entries: Vec<Value>) -> Vec<Value> {
     entries.sort_by(|a, b| {
         let ts_a = a.get("timestamp").and_then(|t| t.as_str()).unwrap_or("");
         let ts_b = b.get("timestamp").and_then(|t| t.as_str()).unwrap_or("");
-        ts_b.cmp(ts_a) // Reverse order (newest first)
+        // Reverse order (newest first)
+        entry_time(b).cmp(&entry_time(a)).then_with(|| ts_b.cmp(ts_a))
     });
     entries
 }
+
+/// Sort entries by timestamp in the given order (Descending = newest first, Ascending = oldest first)
+pub fn sort_by_timestamp_order(entries: Vec<Value>, order: SortOrder) -> Vec<Value> {
+    let mut entries = sort_by_timestamp(entries);
+    if order == SortOrder::Ascending {
+        entries.reverse();
+    }
+    entries
+}
+
+/// Split entries into feed pages of `page_size`, counted from the oldest entry so archive
+/// pages keep their contents as new entries arrive; the last page (the subscription feed)
+/// holds the newest remainder. Each page is ordered by `order`. Always returns at least one page.
+pub fn paginate_entries(entries: &[Value], page_size: usize, order: SortOrder) -> Vec<Vec<Value>> {
+    let oldest_first = sort_by_timestamp_order(entries.to_vec(), SortOrder::Ascending);
+    let mut pages: Vec<Vec<Value>> = oldest_first
+        .chunks(page_size.max(1))
+        .map(|page| sort_by_timestamp_order(page.to_vec(), order))
+        .collect();
+    if pages.is_empty() {
+        pages.push(Vec::new());
+    }
+    pages
+}
+
+/// File (inside the govbot directory) holding the newest published GUID
+pub const CURSOR_FILE: &str = "publish-cursor";
+
+/// `--after` value that resumes from the stored cursor
+pub const LAST_CURSOR: &str = "@last";
+
+/// Read the stored publish cursor, if a previous build wrote one
+pub fn read_cursor(govbot_dir: &Path) -> Result<Option<String>> {
+    let cursor_path = govbot_dir.join(CURSOR_FILE);
+    if !cursor_path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(&cursor_path)
+        .with_context(|| format!("Failed to read cursor file: {}", cursor_path.display()))?;
+    let cursor = contents.trim();
+    Ok((!cursor.is_empty()).then(|| cursor.to_string()))
+}
+
+/// Store the GUID of the newest entry as the cursor for the next build
+pub fn write_cursor(govbot_dir: &Path, entries: &[Value]) -> Result<()> {
+    // Entries may be in either order; the first of the latest timestamps wins
+    let newest = entries
+        .iter()
+        .reduce(|best, e| if entry_time(e) > entry_time(best) { e } else { best });
+    if let Some(newest) = newest {
+        fs::create_dir_all(govbot_dir)?;
+        let cursor_path = govbot_dir.join(CURSOR_FILE);
+        fs::write(&cursor_path, rss::extract_guid(newest))
+            .with_context(|| format!("Failed to write cursor file: {}", cursor_path.display()))?;
+    }
+    Ok(())
+}
+
+/// Drop entries at or before the cursor from a newest-first list
+/// The cursor is matched against entry GUIDs first, then compared as a timestamp
+/// (either given directly or taken from a `.../logs/<timestamp>_...` GUID that has since aged out)
+pub fn entries_after(entries: Vec<Value>, cursor: &str) -> Vec<Value> {
+    if let Some(pos) = entries.iter().position(|e| rss::extract_guid(e) == cursor) {
+        let mut entries = entries;
+        entries.truncate(pos);
+        return entries;
+    }
+
+    let cursor_time = rss::parse_timestamp(cursor).or_else(|| {
+        cursor
+            .split("/logs/")
+            .nth(1)
+            .and_then(|name| name.get(..16))
+            .and_then(rss::parse_timestamp)
+    });
+
+    match cursor_time {
+        Some(time) => entries.into_iter().filter(|e| entry_time(e) > time).collect(),
+        None => {
+            eprintln!("Warning: cursor '{}' matched no entry GUID or timestamp; publishing all entries", cursor);
+            entries
+        }
+    }
+}
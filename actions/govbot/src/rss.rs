@@ -1,13 +1,19 @@
 use chrono::{DateTime, TimeZone, Utc};
 use rss::{ChannelBuilder, ItemBuilder};
 use serde_json::Value;
-use std::collections::HashSet;
 
-/// Parse timestamp string in format YYYYMMDDTHHMMSSZ to DateTime
+use crate::config::SortOrder;
+use crate::types::{action_classification, action_date, action_description, extract_bill_identifier, extract_path_info};
+use std::collections::{BTreeMap, HashSet};
+
+/// Parse a timestamp in the compact YYYYMMDDTHHMMSSZ format used in log filenames, or RFC 3339
+/// (e.g. `2025-04-28T04:00:00-05:00`), to DateTime
 pub fn parse_timestamp(timestamp_str: &str) -> Option<DateTime<Utc>> {
     // Format: 20250428T040000Z (Z indicates UTC)
     if timestamp_str.len() != 16 || !timestamp_str.ends_with('Z') {
-        return None;
+        return DateTime::parse_from_rfc3339(timestamp_str)
+            .ok()
+            .map(|t| t.with_timezone(&Utc));
     }
 
     let date_part = &timestamp_str[0..8]; // YYYYMMDD
@@ -47,14 +53,21 @@ fn extract_repo_name(entry: &Value) -> String {
     "unknown".to_string()
 }
 
+/// Names of the tags on an entry, in key order
+fn entry_tag_names(entry: &Value) -> Vec<String> {
+    entry
+        .get("tags")
+        .and_then(|t| t.as_object())
+        .map(|tags| tags.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
 /// Extract tag name(s) from entry
 /// Returns the first tag, or comma-separated list if multiple
 fn extract_tag_name(entry: &Value) -> String {
-    if let Some(tags) = entry.get("tags").and_then(|t| t.as_object()) {
-        let tag_names: Vec<String> = tags.keys().cloned().collect();
-        if !tag_names.is_empty() {
-            return tag_names.join(", ");
-        }
+    let tag_names = entry_tag_names(entry);
+    if !tag_names.is_empty() {
+        return tag_names.join(", ");
     }
     "untagged".to_string()
 }
@@ -65,39 +78,16 @@ pub fn extract_title(entry: &Value) -> String {
     let tag = extract_tag_name(entry);
     let repo = extract_repo_name(entry);
 
-    // Try bill title first
-    let title = if let Some(bill) = entry.get("bill").and_then(|b| b.as_object()) {
-        if let Some(bill_title) = bill.get("title").and_then(|t| t.as_str()) {
-            let trimmed = bill_title.trim();
-            if !trimmed.is_empty() {
-                trimmed.to_string()
-            } else {
-                // Fall back to bill identifier
-                entry
-                    .get("id")
-                    .or_else(|| entry.get("log").and_then(|l| l.get("bill_id")))
-                    .and_then(|id| id.as_str())
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|| "Legislative Update".to_string())
-            }
-        } else {
-            // Fall back to bill identifier
-            entry
-                .get("id")
-                .or_else(|| entry.get("log").and_then(|l| l.get("bill_id")))
-                .and_then(|id| id.as_str())
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "Legislative Update".to_string())
-        }
-    } else {
-        // Fall back to bill identifier
-        entry
-            .get("id")
-            .or_else(|| entry.get("log").and_then(|l| l.get("bill_id")))
-            .and_then(|id| id.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| "Legislative Update".to_string())
-    };
+    // Try bill title first, falling back to the bill identifier
+    let title = entry
+        .get("bill")
+        .and_then(|b| b.get("title"))
+        .and_then(|t| t.as_str())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .or_else(|| extract_bill_identifier(entry))
+        .unwrap_or_else(|| "Legislative Update".to_string());
 
     format!("{} - {} - {}", tag, repo, title)
 }
@@ -111,7 +101,7 @@ fn format_json_value(value: &Value) -> String {
         Value::Null => "null".to_string(),
         Value::Array(arr) => arr
             .iter()
-            .map(|v| format_json_value(v))
+            .map(format_json_value)
             .collect::<Vec<_>>()
             .join(", "),
         Value::Object(_) => "[object]".to_string(),
@@ -131,32 +121,22 @@ pub fn extract_description(entry: &Value) -> String {
             let mut action_parts = Vec::new();
 
             // Action description (required, most prominent)
-            if let Some(desc) = action.get("description").and_then(|d| d.as_str()) {
+            if let Some(desc) = action_description(log) {
                 action_parts.push(format!("description: {}", desc));
             }
 
             // Action date
-            if let Some(date) = action.get("date").and_then(|d| d.as_str()) {
+            if let Some(date) = action_date(log) {
                 action_parts.push(format!("date: {}", date));
             }
 
-            // Action classification (if array)
-            if let Some(classification) = action.get("classification") {
-                if let Some(class_arr) = classification.as_array() {
-                    if !class_arr.is_empty() {
-                        let classes: Vec<String> = class_arr
-                            .iter()
-                            .filter_map(|v| v.as_str())
-                            .map(|s| s.to_string())
-                            .collect();
-                        if !classes.is_empty() {
-                            action_parts.push(format!(
-                                "classification:\n      - {}",
-                                classes.join("\n      - ")
-                            ));
-                        }
-                    }
-                }
+            // Action classification
+            let classes = action_classification(log);
+            if !classes.is_empty() {
+                action_parts.push(format!(
+                    "classification:\n      - {}",
+                    classes.join("\n      - ")
+                ));
             }
 
             // Organization ID
@@ -247,6 +227,50 @@ pub fn extract_description(entry: &Value) -> String {
     }
 }
 
+/// Whether `tag` looks like a BCP 47 language tag as used in `<language>` (e.g. `en-us`, `fr-CA`):
+/// a 2-3 letter primary language followed by optional 1-8 character alphanumeric subtags
+pub fn is_valid_language_tag(tag: &str) -> bool {
+    let mut parts = tag.split('-');
+    let primary = parts.next().unwrap_or_default();
+    (2..=3).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_alphabetic())
+        && parts.all(|p| (1..=8).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Remove HTML tags and comments from source text and decode common entities, so markup
+/// embedded in scraped abstracts and action descriptions reads as plain prose.
+/// A `<` that doesn't start a tag (e.g. `a < b`) is kept.
+pub fn strip_html(text: &str) -> String {
+    static TAG: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    static BREAK: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let tag = TAG.get_or_init(|| regex::Regex::new(r"(?s)<!--.*?-->|</?[A-Za-z][^<>]*>").unwrap());
+    let block = BREAK.get_or_init(|| {
+        regex::Regex::new(r"(?i)<(br|/?p|/?div|/?li|/?tr|/?h[1-6])\b[^<>]*>").unwrap()
+    });
+    if !text.contains('<') && !text.contains('&') {
+        return text.to_string();
+    }
+
+    // Block-level tags separate words; everything else is dropped
+    let spaced = block.replace_all(text, " ");
+    let stripped = tag.replace_all(&spaced, "");
+    let decoded = stripped
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+
+    // Collapse the runs of spaces left behind, keeping line structure
+    decoded
+        .lines()
+        .map(|line| line.split(' ').filter(|w| !w.is_empty()).collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Extract or construct link from log entry
 pub fn extract_link(entry: &Value, base_url: Option<&str>) -> Option<String> {
     // Try to get source URL from bill metadata
@@ -260,19 +284,57 @@ pub fn extract_link(entry: &Value, base_url: Option<&str>) -> Option<String> {
     }
 
     // Try to get URL from bill sources
-    if let Some(bill_sources) = entry
+    entry
         .get("bill")
-        .and_then(|b| b.get("sources"))
-        .and_then(|s| s.as_array())
-    {
-        if let Some(first_source) = bill_sources.first().and_then(|s| s.as_object()) {
-            if let Some(url) = first_source.get("url").and_then(|u| u.as_str()) {
-                return Some(url.to_string());
-            }
+        .and_then(crate::types::primary_source_url)
+        .map(str::to_string)
+}
+
+/// Render a per-entry link from a template such as `https://site/bill/{state}/{session}/{bill_id}`.
+/// `{country}`, `{state}`, and `{session}` come from the entry's `sources.log` (or `sources.bill`)
+/// path and `{bill_id}` from its identifier; values are percent-encoded. Returns `None` when the
+/// template uses a value the entry doesn't have.
+pub fn render_link_template(template: &str, entry: &Value) -> Option<String> {
+    let sources = entry.get("sources");
+    let path_info = ["log", "bill"]
+        .iter()
+        .filter_map(|key| sources.and_then(|s| s.get(key)).and_then(|p| p.as_str()))
+        .find_map(extract_path_info);
+    let bill_id = extract_bill_identifier(entry);
+
+    let mut link = template.to_string();
+    let values = [
+        ("{country}", path_info.as_ref().map(|(country, _, _)| country.as_str())),
+        ("{state}", path_info.as_ref().map(|(_, state, _)| state.as_str())),
+        ("{session}", path_info.as_ref().map(|(_, _, session)| session.as_str())),
+        ("{bill_id}", bill_id.as_deref()),
+    ];
+    for (placeholder, value) in values {
+        if link.contains(placeholder) {
+            link = link.replace(placeholder, &percent_encode(value?));
         }
     }
+    Some(link)
+}
 
-    None
+/// Percent-encode everything but RFC 3986 unreserved characters
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Item link: the configured `link_template` when it renders, else `extract_link`
+fn item_link(entry: &Value, base_url: &str, options: &FeedOptions) -> Option<String> {
+    options
+        .link_template
+        .as_deref()
+        .and_then(|template| render_link_template(template, entry))
+        .or_else(|| extract_link(entry, Some(base_url)))
 }
 
 /// Extract or generate a unique GUID for the entry
@@ -289,15 +351,143 @@ pub fn extract_guid(entry: &Value) -> String {
         .get("timestamp")
         .and_then(|t| t.as_str())
         .unwrap_or("");
-    let bill_id = entry
-        .get("id")
-        .or_else(|| entry.get("log").and_then(|l| l.get("bill_id")))
-        .and_then(|id| id.as_str())
-        .unwrap_or("");
+    let bill_id = extract_bill_identifier(entry).unwrap_or_default();
 
     format!("{}_{}", timestamp, bill_id)
 }
 
+/// Build the item GUID. When the item has a resolvable link, the GUID is that URL
+/// (a permalink) with a per-log fragment, so several log entries for the same bill
+/// stay distinct; otherwise it is the opaque `extract_guid` value.
+pub fn item_guid(entry: &Value, item_link: Option<&str>) -> rss::Guid {
+    let id = extract_guid(entry);
+    match item_link.filter(|l| l.starts_with("http://") || l.starts_with("https://")) {
+        Some(link) => {
+            let fragment = std::path::Path::new(&id)
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| id.clone())
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("-");
+            rss::Guid {
+                value: format!("{}#{}", link.split('#').next().unwrap_or(link), fragment),
+                permalink: true,
+            }
+        }
+        None => rss::Guid {
+            value: id,
+            permalink: false,
+        },
+    }
+}
+
+/// Feed rendering options: polling hints for aggregators (`<ttl>`, `<skipHours>`, `<skipDays>`;
+/// empty settings are omitted from the channel) and HTML stripping of item text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedOptions {
+    /// Minutes a feed may be cached before refreshing
+    pub ttl_minutes: Option<u32>,
+    /// Hours (0-23, GMT) when aggregators should not poll
+    pub skip_hours: Vec<u32>,
+    /// Days (e.g. `Saturday`) when aggregators should not poll
+    pub skip_days: Vec<String>,
+    /// Strip markup from RSS item descriptions (on by default)
+    pub strip_html: bool,
+    /// Strip markup from HTML index entries (on by default; off shows it escaped, as written)
+    pub strip_html_index: bool,
+    /// Position in a paged feed, for RFC 5005 archive links (`None` for a single feed)
+    pub page: Option<FeedPage>,
+    /// Item link template (see `render_link_template`); replaces `extract_link` when set
+    pub link_template: Option<String>,
+    /// Curator per tag name (first of the entry's tags by name wins): written as the item `<author>`
+    /// when it has an email address, as RSS 2.0 requires, and as `<dc:creator>` when it's just a name
+    pub tag_authors: BTreeMap<String, String>,
+    /// `domain` attribute of every item `<category>` (e.g. a taxonomy page URL)
+    pub category_domain: Option<String>,
+    /// Display label per tag name, written as the `<category>` text instead of the tag key
+    pub tag_labels: BTreeMap<String, String>,
+}
+
+/// Namespace of RFC 5005 feed history elements (`<fh:archive/>`)
+pub const FEED_HISTORY_NAMESPACE: &str = "http://purl.org/syndication/history/1.0";
+
+/// One page of a feed split per RFC 5005. Page 1 holds the oldest entries; the last page is the
+/// subscription feed (`file_name`), and earlier pages are archives named `<stem>-<number>.xml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedPage {
+    /// 1-based page number
+    pub number: usize,
+    /// Total number of pages
+    pub total: usize,
+    /// URL the feed files are published under
+    pub base_url: String,
+    /// Subscription feed file name, e.g. `feed.xml`
+    pub file_name: String,
+}
+
+impl FeedPage {
+    /// Whether this page is an archive rather than the subscription feed
+    pub fn is_archive(&self) -> bool {
+        self.number < self.total
+    }
+
+    /// File name of page `number`: the subscription feed for the last page, else `<stem>-<number>.<ext>`
+    pub fn file_name_of(&self, number: usize) -> String {
+        if number >= self.total {
+            return self.file_name.clone();
+        }
+        let path = std::path::Path::new(&self.file_name);
+        let stem = path.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+        match path.extension() {
+            Some(ext) => format!("{}-{}.{}", stem, number, ext.to_string_lossy()),
+            None => format!("{}-{}", stem, number),
+        }
+    }
+
+    fn url_of(&self, number: usize) -> String {
+        format!("{}/{}", self.base_url.trim_end_matches('/'), self.file_name_of(number))
+    }
+
+    /// `current`, `prev-archive`, and `next-archive` links for this page
+    fn archive_links(&self) -> Vec<rss::extension::atom::Link> {
+        let link = |rel: &str, number: usize| rss::extension::atom::Link {
+            href: self.url_of(number),
+            rel: rel.to_string(),
+            ..Default::default()
+        };
+        let mut links = Vec::new();
+        if self.is_archive() {
+            links.push(link("current", self.total));
+        }
+        if self.number > 1 {
+            links.push(link("prev-archive", self.number - 1));
+        }
+        // Archives link forward to the next newer archive; the subscription feed has none
+        if self.number + 1 < self.total {
+            links.push(link("next-archive", self.number + 1));
+        }
+        links
+    }
+}
+
+impl Default for FeedOptions {
+    fn default() -> Self {
+        Self {
+            ttl_minutes: None,
+            skip_hours: Vec::new(),
+            skip_days: Vec::new(),
+            strip_html: true,
+            strip_html_index: true,
+            page: None,
+            link_template: None,
+            tag_authors: BTreeMap::new(),
+            category_domain: None,
+            tag_labels: BTreeMap::new(),
+        }
+    }
+}
+
 /// Convert JSON Lines entries to RSS feed
 pub fn json_to_rss(
     entries: Vec<Value>,
@@ -306,6 +496,19 @@ pub fn json_to_rss(
     link: &str,
     base_url: Option<&str>,
     language: &str,
+) -> String {
+    json_to_rss_with_options(entries, title, description, link, base_url, language, &FeedOptions::default())
+}
+
+/// `json_to_rss` rendered according to `options`
+pub fn json_to_rss_with_options(
+    entries: Vec<Value>,
+    title: &str,
+    description: &str,
+    link: &str,
+    base_url: Option<&str>,
+    language: &str,
+    options: &FeedOptions,
 ) -> String {
     let base_url = base_url.unwrap_or(link);
 
@@ -319,19 +522,22 @@ pub fn json_to_rss(
         if seen_guids.contains(&guid) {
             continue;
         }
-        seen_guids.insert(guid.clone());
+        seen_guids.insert(guid);
 
         let mut item_builder = ItemBuilder::default();
 
         // Set title
-        item_builder.title(extract_title(&entry));
+        item_builder.title(sanitize_xml_text(&extract_title(&entry)));
 
         // Set description
-        item_builder.description(extract_description(&entry));
+        let description = extract_description(&entry);
+        let description = if options.strip_html { strip_html(&description) } else { description };
+        item_builder.description(sanitize_xml_text(&description));
 
         // Set link
-        if let Some(item_link) = extract_link(&entry, Some(base_url)) {
-            item_builder.link(item_link);
+        let item_link = item_link(&entry, base_url, options);
+        if let Some(ref item_link) = item_link {
+            item_builder.link(item_link.clone());
         }
 
         // Set publication date
@@ -341,39 +547,190 @@ pub fn json_to_rss(
             }
         }
 
-        // Set GUID
-        item_builder.guid(rss::Guid {
-            value: guid,
-            permalink: false,
-        });
+        // Set GUID (deduplication above still keys on the log identity)
+        item_builder.guid(item_guid(&entry, item_link.as_deref()));
 
-        // Only add categories from entry tags (not all feed tags)
-        // Each entry should only show tags that are actually on that entry
-        if let Some(tags) = entry.get("tags").and_then(|t| t.as_object()) {
-            for tag_name in tags.keys() {
-                item_builder.category(rss::Category {
-                    name: tag_name.clone(),
-                    domain: None,
+        let tag_names = entry_tag_names(&entry);
+        if let Some(author) = tag_names.iter().find_map(|name| options.tag_authors.get(name)) {
+            let author = sanitize_xml_text(author);
+            if author.contains('@') {
+                item_builder.author(author);
+            } else {
+                item_builder.dublin_core_ext(rss::extension::dublincore::DublinCoreExtension {
+                    creators: vec![author],
+                    ..Default::default()
                 });
             }
         }
 
+        // Only add categories from entry tags (not all feed tags)
+        // Each entry should only show tags that are actually on that entry
+        for tag_name in tag_names {
+            let name = match options.tag_labels.get(&tag_name) {
+                Some(label) => sanitize_xml_text(label),
+                None => tag_name,
+            };
+            item_builder.category(rss::Category {
+                name,
+                domain: options.category_domain.clone(),
+            });
+        }
+
         items.push(item_builder.build());
     }
 
     // Build channel
-    let channel = ChannelBuilder::default()
-        .title(title)
+    let mut channel = ChannelBuilder::default()
+        .title(sanitize_xml_text(title))
         .link(link)
-        .description(description)
+        .description(sanitize_xml_text(description))
         .language(Some(language.to_string()))
         .last_build_date(Some(Utc::now().to_rfc2822()))
+        .ttl(options.ttl_minutes.map(|m| m.to_string()))
+        .skip_hours(options.skip_hours.iter().map(|h| h.to_string()).collect::<Vec<_>>())
+        .skip_days(options.skip_days.clone())
         .items(items)
         .build();
 
+    if let Some(page) = &options.page {
+        channel.set_atom_ext(rss::extension::atom::AtomExtension {
+            links: page.archive_links(),
+        });
+        if page.is_archive() {
+            let archive = rss::extension::Extension {
+                name: "fh:archive".to_string(),
+                ..Default::default()
+            };
+            channel
+                .extensions
+                .entry("fh".to_string())
+                .or_default()
+                .insert("archive".to_string(), vec![archive]);
+            channel.namespaces.insert("fh".to_string(), FEED_HISTORY_NAMESPACE.to_string());
+        }
+    }
+
     channel.to_string()
 }
 
+/// Group entries by source repo (e.g. `wy-legislation`), keeping each repo's entries in order.
+/// Repos are returned sorted by name.
+pub fn group_by_repo(entries: &[Value]) -> Vec<(String, Vec<Value>)> {
+    let mut groups: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for entry in entries {
+        groups.entry(extract_repo_name(entry)).or_default().push(entry.clone());
+    }
+    groups.into_iter().collect()
+}
+
+/// Union the items of `existing_xml` into `new_xml`, so items whose source data has rotated
+/// out of the repos stay in the feed. Items are deduplicated by GUID (the new feed's copy wins),
+/// ordered by `pubDate` (undated items last), and truncated to `limit`.
+/// The channel metadata comes from `new_xml`.
+pub fn merge_feeds(
+    new_xml: &str,
+    existing_xml: &str,
+    order: SortOrder,
+    limit: Option<usize>,
+) -> Result<String, rss::Error> {
+    let mut channel = rss::Channel::read_from(new_xml.as_bytes())?;
+    let existing = rss::Channel::read_from(existing_xml.as_bytes())?;
+
+    let item_key = |item: &rss::Item| {
+        item.guid()
+            .map(|g| g.value().to_string())
+            .or_else(|| item.link().map(str::to_string))
+            .unwrap_or_default()
+    };
+
+    let mut seen = HashSet::new();
+    let mut items = Vec::new();
+    for item in channel.items().iter().chain(existing.items()) {
+        if seen.insert(item_key(item)) {
+            items.push(item.clone());
+        }
+    }
+
+    let published = |item: &rss::Item| item.pub_date().and_then(|d| DateTime::parse_from_rfc2822(d).ok());
+    items.sort_by(|a, b| match (published(a), published(b)) {
+        (Some(da), Some(db)) => match order {
+            SortOrder::Ascending => da.cmp(&db),
+            SortOrder::Descending => db.cmp(&da),
+        },
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    if let Some(limit) = limit {
+        items.truncate(limit);
+    }
+
+    channel.set_items(items);
+    Ok(channel.to_string())
+}
+
+/// Check whether a character is allowed in an XML 1.0 document
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..='\u{10FFFF}')
+}
+
+/// Drop characters that XML 1.0 does not allow (scraped bill text sometimes contains
+/// form feeds, vertical tabs, backspaces, etc.)
+pub fn sanitize_xml_text(text: &str) -> String {
+    text.chars().filter(|c| is_xml_char(*c)).collect()
+}
+
+/// Re-parse a generated RSS document and check that it is well-formed and complete
+/// Returns the list of problems found (channel/item required elements, dates, illegal characters)
+pub fn validate_feed(xml: &str) -> Result<(), Vec<String>> {
+    let mut problems = Vec::new();
+
+    if let Some((offset, c)) = xml.char_indices().find(|(_, c)| !is_xml_char(*c)) {
+        problems.push(format!(
+            "illegal XML character U+{:04X} at byte {}",
+            c as u32, offset
+        ));
+    }
+
+    let channel = match rss::Channel::read_from(xml.as_bytes()) {
+        Ok(channel) => channel,
+        Err(e) => {
+            problems.push(format!("feed does not parse as RSS: {}", e));
+            return Err(problems);
+        }
+    };
+
+    if channel.title().trim().is_empty() {
+        problems.push("channel is missing <title>".to_string());
+    }
+    if channel.link().trim().is_empty() {
+        problems.push("channel is missing <link>".to_string());
+    }
+    if channel.description().trim().is_empty() {
+        problems.push("channel is missing <description>".to_string());
+    }
+
+    for (i, item) in channel.items().iter().enumerate() {
+        if item.title().is_none() && item.description().is_none() {
+            problems.push(format!("item {} has neither <title> nor <description>", i));
+        }
+        if item.guid().is_none() {
+            problems.push(format!("item {} is missing <guid>", i));
+        }
+        if let Some(pub_date) = item.pub_date() {
+            if DateTime::parse_from_rfc2822(pub_date).is_err() {
+                problems.push(format!("item {} has unparseable <pubDate> '{}'", i, pub_date));
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
+
 /// Format date and time for HTML display
 fn format_datetime_html(dt: &DateTime<Utc>) -> String {
     dt.format("%B %d, %Y at %I:%M %p UTC").to_string()
@@ -419,7 +776,7 @@ fn description_to_html(desc: &str) -> String {
         prev_indent = indent_level;
 
         // Check if this is a list item (starts with "- ")
-        if trimmed.starts_with("- ") {
+        if let Some(list_value) = trimmed.strip_prefix("- ") {
             if !in_list {
                 html.push_str(&format!(
                     "<ul class=\"json-list\" style=\"margin-left: {}em; margin-top: 0.25em;\">",
@@ -427,7 +784,6 @@ fn description_to_html(desc: &str) -> String {
                 ));
                 in_list = true;
             }
-            let list_value = &trimmed[2..];
             html.push_str(&format!(
                 "<li style=\"margin-bottom: 0.25em;\">{}</li>",
                 escape_html(list_value)
@@ -483,14 +839,31 @@ fn description_to_html(desc: &str) -> String {
 
 /// Convert JSON Lines entries to HTML index page
 /// title: If None or empty, header will not be shown
+/// feed_path: RSS file the page links to, relative to base_url (default: `feed.xml`)
 pub fn json_to_html(
     entries: Vec<Value>,
     title: Option<&str>,
     link: &str,
     base_url: Option<&str>,
+    feed_path: Option<&str>,
+) -> String {
+    json_to_html_with_options(entries, title, link, base_url, feed_path, "en", &FeedOptions::default())
+}
+
+/// `json_to_html` for a page in `language`, with entry markup stripped or kept per
+/// `options.strip_html_index`
+pub fn json_to_html_with_options(
+    entries: Vec<Value>,
+    title: Option<&str>,
+    link: &str,
+    base_url: Option<&str>,
+    feed_path: Option<&str>,
+    language: &str,
+    options: &FeedOptions,
 ) -> String {
     let base_url = base_url.unwrap_or(link);
-    let rss_link = format!("{}/feed.xml", base_url.trim_end_matches('/'));
+    let feed_path = feed_path.unwrap_or("feed.xml").trim_start_matches('/');
+    let rss_link = format!("{}/{}", base_url.trim_end_matches('/'), feed_path);
 
     // Only show header if title is provided
     let show_header = title.is_some() && !title.unwrap_or("").trim().is_empty();
@@ -510,7 +883,12 @@ pub fn json_to_html(
 
         let entry_title = extract_title(&entry);
         let entry_description = extract_description(&entry);
-        let entry_link = extract_link(&entry, Some(base_url));
+        let entry_description = if options.strip_html_index {
+            strip_html(&entry_description)
+        } else {
+            entry_description
+        };
+        let entry_link = item_link(&entry, base_url, options);
 
         // Format date
         let date_html = if let Some(timestamp) = entry.get("timestamp").and_then(|t| t.as_str()) {
@@ -524,12 +902,7 @@ pub fn json_to_html(
         };
 
         // Get tags - only from the entry itself (not all feed tags)
-        let mut tags = Vec::new();
-        if let Some(entry_tags) = entry.get("tags").and_then(|t| t.as_object()) {
-            for tag_name in entry_tags.keys() {
-                tags.push(tag_name.clone());
-            }
-        }
+        let tags = entry_tag_names(&entry);
 
         let tags_html = if tags.is_empty() {
             String::new()
@@ -572,13 +945,13 @@ pub fn json_to_html(
                 .and_then(|t| t.as_str())
                 .unwrap_or(""),
             date_html,
-            if !date_html.is_empty() { "" } else { "" }
+            ""
         ));
     }
 
     format!(
         r#"<!DOCTYPE html>
-<html lang="en">
+<html lang="{}">
 <head>
   <meta charset="UTF-8">
   <meta name="viewport" content="width=device-width, initial-scale=1.0">
@@ -818,6 +1191,7 @@ pub fn json_to_html(
   </footer>
 </body>
 </html>"#,
+        escape_html(language),  // <html lang>
         escape_html(title_str), // <title> tag
         escape_html(title_str), // RSS link title
         escape_html(&rss_link), // RSS link href
@@ -1,3 +1,124 @@
+use serde::Deserialize;
+
+use crate::types::action_description;
+
+/// Which fields feed the text used for embedding and keyword matching.
+/// The default (nothing included or excluded) is exactly `ocd_files_select_default`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct TextExtractionConfig {
+    /// Extra dotted paths (e.g. `bill.classification`) whose values are appended to the text;
+    /// arrays along the path are searched element by element
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Keys removed at any depth before extraction (e.g. `from_organization`)
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Extract text for matching, applying `config`'s extra paths and excluded keys
+pub fn ocd_files_select(value: &serde_json::Value, config: &TextExtractionConfig) -> String {
+    if config.include.is_empty() && config.exclude.is_empty() {
+        return ocd_files_select_default(value);
+    }
+
+    let pruned = without_keys(value, &config.exclude);
+    let mut texts = vec![ocd_files_select_default(&pruned)];
+    for path in &config.include {
+        let mut found = Vec::new();
+        collect_path(&pruned, &path.split('.').collect::<Vec<_>>(), &mut found);
+        texts.extend(found.into_iter().map(scalar_text));
+    }
+    texts.retain(|t| !t.is_empty());
+    texts.join(" ")
+}
+
+/// Copy of `value` with every object key in `keys` removed
+fn without_keys(value: &serde_json::Value, keys: &[String]) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .filter(|(k, _)| !keys.iter().any(|x| x == *k))
+                .map(|(k, v)| (k.clone(), without_keys(v, keys)))
+                .collect(),
+        ),
+        serde_json::Value::Array(arr) => {
+            serde_json::Value::Array(arr.iter().map(|v| without_keys(v, keys)).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Collect the values at a dotted path, fanning out over arrays
+fn collect_path<'a>(value: &'a serde_json::Value, path: &[&str], found: &mut Vec<&'a serde_json::Value>) {
+    match (value, path.split_first()) {
+        (serde_json::Value::Array(arr), _) => {
+            for item in arr {
+                collect_path(item, path, found);
+            }
+        }
+        (_, None) => found.push(value),
+        (serde_json::Value::Object(map), Some((key, rest))) => {
+            if let Some(child) = map.get(*key) {
+                collect_path(child, rest, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extract a value from JSON using a field path (e.g., ["title"] or ["sponsorships", "0", "name"]).
+/// Segments are object keys or array indices; `*` on an array maps the rest of the path over every
+/// element and returns the results as an array, skipping elements where the rest isn't found.
+pub fn extract_json_field(value: &serde_json::Value, field_path: &[String]) -> Option<serde_json::Value> {
+    let Some((field, rest)) = field_path.split_first() else {
+        return Some(value.clone());
+    };
+    match value {
+        serde_json::Value::Object(map) => extract_json_field(map.get(field)?, rest),
+        serde_json::Value::Array(arr) if field == "*" => Some(serde_json::Value::Array(
+            arr.iter().filter_map(|item| extract_json_field(item, rest)).collect(),
+        )),
+        serde_json::Value::Array(arr) => extract_json_field(arr.get(field.parse::<usize>().ok()?)?, rest),
+        _ => None,
+    }
+}
+
+/// Keep only `fields` (dotted paths, `*` over arrays as in `extract_json_field`) from `value`,
+/// keyed by the path itself (e.g. `"bill.title"`); paths that aren't found are left out
+pub fn project_fields(value: &serde_json::Value, fields: &[String]) -> serde_json::Value {
+    let projected = fields
+        .iter()
+        .filter_map(|field| {
+            let path: Vec<String> = field.split('.').map(str::to_string).collect();
+            extract_json_field(value, &path).map(|v| (field.clone(), v))
+        })
+        .collect();
+    serde_json::Value::Object(projected)
+}
+
+/// Parse a `--fields-file`: a YAML list of dotted paths, or one path per line (blank lines and
+/// `#` comments ignored)
+pub fn parse_fields_file(contents: &str) -> Vec<String> {
+    if let Ok(fields) = serde_yaml::from_str::<Vec<String>>(contents) {
+        return fields.into_iter().map(|f| f.trim().to_string()).filter(|f| !f.is_empty()).collect();
+    }
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Text for an included value; unlike the default selector, numbers and booleans count
+fn scalar_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        other => ocd_files_select_default(other),
+    }
+}
+
 /// Default selector for OCDFiles-style JSON structures.
 /// Extracts human-readable text content from a JSON value, focusing on bill and log content.
 pub fn ocd_files_select_default(value: &serde_json::Value) -> String {
@@ -27,36 +148,19 @@ pub fn ocd_files_select_default(value: &serde_json::Value) -> String {
 
             // Extract from log object (if present)
             if let Some(log) = map.get("log") {
-                if let Some(action) = log.get("action") {
-                    // Extract description from action object
-                    if let Some(desc) = action.get("description").and_then(|v| v.as_str()) {
-                        texts.push(desc.to_string());
-                    }
-                    // Or if action is directly a string
-                    if let Some(desc_str) = action.as_str() {
-                        texts.push(desc_str.to_string());
-                    }
+                // Action description, whether `action` is an object or a plain string
+                if let Some(desc) = action_description(log) {
+                    texts.push(desc.to_string());
                 }
                 // Also check for bill_id in log
-                if let Some(bill_id) = log
-                    .get("bill_id")
-                    .or_else(|| log.get("bill_identifier"))
-                    .and_then(|v| v.as_str())
-                {
-                    texts.push(bill_id.to_string());
+                if let Some(bill_id) = crate::types::extract_bill_identifier(log) {
+                    texts.push(bill_id);
                 }
             }
 
             // Extract from action object directly (if present at top level, e.g., when processing log object)
-            if let Some(action) = map.get("action") {
-                // Extract description from action object
-                if let Some(desc) = action.get("description").and_then(|v| v.as_str()) {
-                    texts.push(desc.to_string());
-                }
-                // Or if action is directly a string
-                if let Some(desc_str) = action.as_str() {
-                    texts.push(desc_str.to_string());
-                }
+            if let Some(desc) = action_description(value) {
+                texts.push(desc.to_string());
             }
 
             // Fallback: extract from all other text fields (excluding metadata)
@@ -0,0 +1,136 @@
+//! SQLite output for `govbot load --format sqlite`: a `bills` table with one column per
+//! top-level metadata key (nested values stored as JSON text) plus the `bills_summary` view,
+//! mirroring what the DuckDB load creates.
+
+use anyhow::{Context, Result};
+use jwalk::WalkDir;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params_from_iter, Connection};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Columns `bills_summary` reads; always created so the view is valid for any metadata
+const SUMMARY_COLUMNS: [&str; 6] = [
+    "identifier",
+    "title",
+    "legislative_session",
+    "jurisdiction",
+    "actions",
+    "sponsorships",
+];
+
+const SUMMARY_VIEW: &str = "CREATE VIEW IF NOT EXISTS bills_summary AS
+SELECT
+    identifier,
+    title,
+    legislative_session,
+    json_extract(jurisdiction, '$.id') AS jurisdiction_id,
+    json_extract(jurisdiction, '$.name') AS jurisdiction_name,
+    json_array_length(actions) AS action_count,
+    json_array_length(sponsorships) AS sponsor_count,
+    source_file
+FROM bills";
+
+/// Every `bills/<id>/metadata.json` under `repos_dir`, the files the DuckDB load globs
+pub fn find_metadata_files(repos_dir: &Path) -> Vec<PathBuf> {
+    WalkDir::new(repos_dir)
+        .sort(true)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name().is_some_and(|n| n == "metadata.json")
+                && path
+                    .parent()
+                    .and_then(|p| p.parent())
+                    .and_then(|p| p.file_name())
+                    .is_some_and(|n| n == "bills")
+        })
+        .collect()
+}
+
+/// Load `files` into the `bills` table of the SQLite database at `db_path`, creating it
+/// (and `bills_summary`) if needed. Rows already loaded from the same files are replaced,
+/// and keys not yet in the table become new columns. Files are read and inserted one at a
+/// time. Returns the table's row count.
+pub fn load_bills(db_path: &Path, files: &[PathBuf]) -> Result<usize> {
+    load_bills_with_deletions(db_path, files, &[])
+}
+
+/// [`load_bills`], also removing the rows loaded from `deleted` files
+pub fn load_bills_with_deletions(db_path: &Path, files: &[PathBuf], deleted: &[PathBuf]) -> Result<usize> {
+    let mut conn = Connection::open(db_path).with_context(|| format!("Failed to open {}", db_path.display()))?;
+    let tx = conn.transaction()?;
+    tx.execute("CREATE TABLE IF NOT EXISTS bills (source_file TEXT)", [])?;
+    // SQLite column names are case-insensitive, so track them lowercased
+    let mut columns: HashSet<String> = tx
+        .prepare("SELECT name FROM pragma_table_info('bills')")?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .map(|name| name.map(|n| n.to_lowercase()))
+        .collect::<rusqlite::Result<_>>()?;
+    for column in SUMMARY_COLUMNS {
+        add_column(&tx, &mut columns, column)?;
+    }
+    tx.execute(SUMMARY_VIEW, [])?;
+
+    for file in deleted {
+        tx.execute("DELETE FROM bills WHERE source_file = ?1", [file.to_string_lossy().as_ref()])?;
+    }
+    for file in files {
+        let contents = fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+        let value: Value = serde_json::from_str(&contents).with_context(|| format!("Invalid JSON in {}", file.display()))?;
+        let Value::Object(map) = value else {
+            anyhow::bail!("{} is not a JSON object", file.display());
+        };
+        let source_file = file.to_string_lossy();
+        tx.execute("DELETE FROM bills WHERE source_file = ?1", [source_file.as_ref()])?;
+
+        // Keys differing only in case share a column; the first one wins
+        let mut seen: HashSet<String> = HashSet::from(["source_file".to_string()]);
+        let mut names = vec!["source_file".to_string()];
+        let mut values = vec![SqlValue::Text(source_file.to_string())];
+        for (key, value) in &map {
+            if !seen.insert(key.to_lowercase()) {
+                continue;
+            }
+            add_column(&tx, &mut columns, key)?;
+            names.push(quote_ident(key));
+            values.push(sql_value(value));
+        }
+        let placeholders = (1..=names.len()).map(|i| format!("?{}", i)).collect::<Vec<_>>().join(", ");
+        tx.prepare_cached(&format!("INSERT INTO bills ({}) VALUES ({})", names.join(", "), placeholders))?
+            .execute(params_from_iter(values))?;
+    }
+
+    let count: i64 = tx.query_row("SELECT COUNT(*) FROM bills", [], |row| row.get(0))?;
+    tx.commit()?;
+    Ok(count as usize)
+}
+
+/// Add `column` to `bills` unless a column with that name, in any case, already exists
+fn add_column(conn: &Connection, columns: &mut HashSet<String>, column: &str) -> Result<()> {
+    if columns.insert(column.to_lowercase()) {
+        conn.execute(&format!("ALTER TABLE bills ADD COLUMN {}", quote_ident(column)), [])?;
+    }
+    Ok(())
+}
+
+/// Scalars map to SQLite's own types; arrays and objects are stored as JSON text
+fn sql_value(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(*b as i64),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+        Value::Array(_) | Value::Object(_) => SqlValue::Text(value.to_string()),
+    }
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
@@ -8,6 +8,9 @@ pub struct LogEntry {
     pub log: LogContent,
     /// The relative filename path
     pub filename: String,
+    /// Complete metadata.json for the bill (only with `JoinOption::FullMetadata`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
 }
 
 /// Log content can be either a full JSON value or a vote event result
@@ -17,42 +20,255 @@ pub enum LogContent {
     /// Full JSON content (for non-vote-event files)
     Full(serde_json::Value),
     /// Vote event result (for vote_event files)
-    VoteEvent { result: VoteEventResult },
+    VoteEvent {
+        /// Outcome parsed from the filename (authoritative)
+        result: VoteEventResult,
+        /// File body with counts and individual votes (only with `Config::vote_event_detail`)
+        #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+        detail: serde_json::Value,
+    },
+}
+
+impl LogContent {
+    /// `action.description`, or `action` itself when the log stores it as a plain string
+    pub fn action_description(&self) -> Option<&str> {
+        self.full().and_then(action_description)
+    }
+
+    /// `action.date` as written in the log
+    pub fn action_date(&self) -> Option<&str> {
+        self.full().and_then(action_date)
+    }
+
+    /// `action.classification` entries (empty for vote events and logs without one)
+    pub fn action_classification(&self) -> Vec<&str> {
+        self.full().map(action_classification).unwrap_or_default()
+    }
+
+    fn full(&self) -> Option<&serde_json::Value> {
+        match self {
+            LogContent::Full(value) => Some(value),
+            LogContent::VoteEvent { .. } => None,
+        }
+    }
+}
+
+impl LogEntry {
+    /// See [`LogContent::action_description`]
+    pub fn action_description(&self) -> Option<&str> {
+        self.log.action_description()
+    }
+
+    /// See [`LogContent::action_date`]
+    pub fn action_date(&self) -> Option<&str> {
+        self.log.action_date()
+    }
+
+    /// See [`LogContent::action_classification`]
+    pub fn action_classification(&self) -> Vec<&str> {
+        self.log.action_classification()
+    }
+}
+
+/// `action.description` of a raw log body, or `action` itself when it's a plain string
+pub fn action_description(log: &serde_json::Value) -> Option<&str> {
+    let action = log.get("action")?;
+    action
+        .as_str()
+        .or_else(|| action.get("description").and_then(|d| d.as_str()))
+}
+
+/// `action.date` of a raw log body
+pub fn action_date(log: &serde_json::Value) -> Option<&str> {
+    log.get("action")?.get("date")?.as_str()
+}
+
+/// `action.classification` of a raw log body; a single string counts as one entry
+pub fn action_classification(log: &serde_json::Value) -> Vec<&str> {
+    match log.get("action").and_then(|a| a.get("classification")) {
+        Some(serde_json::Value::Array(classes)) => classes.iter().filter_map(|c| c.as_str()).collect(),
+        Some(serde_json::Value::String(class)) => vec![class.as_str()],
+        _ => Vec::new(),
+    }
 }
 
 /// Vote event result type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum VoteEventResult {
     Pass,
     Fail,
+    Tie,
+    Withdrawn,
+    Tabled,
+    NoQuorum,
     Unknown,
 }
 
+impl VoteEventResult {
+    /// Filename token (and serialized form) for this result
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoteEventResult::Pass => "pass",
+            VoteEventResult::Fail => "fail",
+            VoteEventResult::Tie => "tie",
+            VoteEventResult::Withdrawn => "withdrawn",
+            VoteEventResult::Tabled => "tabled",
+            VoteEventResult::NoQuorum => "no_quorum",
+            VoteEventResult::Unknown => "unknown",
+        }
+    }
+}
+
 impl From<&str> for VoteEventResult {
     fn from(s: &str) -> Self {
-        match s.to_lowercase().as_str() {
+        match s.to_lowercase().replace('-', "_").as_str() {
             "pass" => VoteEventResult::Pass,
             "fail" => VoteEventResult::Fail,
+            "tie" => VoteEventResult::Tie,
+            "withdrawn" => VoteEventResult::Withdrawn,
+            "tabled" => VoteEventResult::Tabled,
+            "no_quorum" => VoteEventResult::NoQuorum,
             _ => VoteEventResult::Unknown,
         }
     }
 }
 
+impl std::fmt::Display for VoteEventResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Source information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Source {
     pub url: String,
+    #[serde(default)]
     pub note: String,
 }
 
+/// Jurisdiction the bill belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Jurisdiction {
+    pub id: String,
+    pub name: Option<String>,
+    pub classification: Option<String>,
+    pub division_id: Option<String>,
+}
+
+/// A bill sponsorship entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sponsorship {
+    #[serde(default)]
+    pub name: String,
+    pub classification: Option<String>,
+    pub primary: Option<bool>,
+}
+
 /// Complete metadata structure from metadata.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Metadata {
     pub title: Option<String>,
     pub description: Option<String>,
     pub sources: Option<Vec<Source>>,
-    // Note: sponsors field removed - use the new join system instead
+    #[serde(default)]
+    pub jurisdiction: Option<Jurisdiction>,
+    #[serde(default)]
+    pub sponsorships: Option<Vec<Sponsorship>>,
+}
+
+impl Metadata {
+    /// URL of the first non-empty source
+    pub fn primary_source_url(&self) -> Option<&str> {
+        first_source_url(self.sources.as_ref()?.iter().map(|s| s.url.as_str()))
+    }
+
+    /// OCD jurisdiction id (e.g. `ocd-jurisdiction/country:us/state:wy/government`)
+    pub fn jurisdiction_id(&self) -> Option<&str> {
+        self.jurisdiction.as_ref().map(|j| j.id.as_str())
+    }
+
+    /// Name of the primary sponsor, falling back to the first sponsorship classified as primary
+    pub fn primary_sponsor(&self) -> Option<&str> {
+        let sponsorships = self.sponsorships.as_ref()?;
+        sponsorships
+            .iter()
+            .find(|s| s.primary == Some(true))
+            .or_else(|| {
+                sponsorships
+                    .iter()
+                    .find(|s| s.classification.as_deref() == Some("primary"))
+            })
+            .map(|s| s.name.as_str())
+    }
+}
+
+/// URL of the first non-empty source in a raw `metadata.json` body (the `bill` of a log entry)
+pub fn primary_source_url(bill: &serde_json::Value) -> Option<&str> {
+    first_source_url(
+        bill.get("sources")?
+            .as_array()?
+            .iter()
+            .filter_map(|source| source.get("url").and_then(|u| u.as_str())),
+    )
+}
+
+fn first_source_url<'a>(mut urls: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    urls.find(|url| !url.trim().is_empty())
+}
+
+/// Bill identifier for a log entry in any of the shapes govbot handles.
+///
+/// Works on raw log files, `logs` output (with or without `--select default`), and
+/// previously tagged entries. Fallback order:
+/// `bill_id`, `bill_identifier`, `id`, `log.bill_id`, `log.bill_identifier`, `bill.identifier`.
+pub fn extract_bill_identifier(value: &serde_json::Value) -> Option<String> {
+    let log = value.get("log");
+    [
+        value.get("bill_id"),
+        value.get("bill_identifier"),
+        value.get("id"),
+        log.and_then(|l| l.get("bill_id")),
+        log.and_then(|l| l.get("bill_identifier")),
+        value.get("bill").and_then(|b| b.get("identifier")),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|v| v.as_str())
+    .map(str::trim)
+    .find(|id| !id.is_empty())
+    .map(str::to_string)
+}
+
+/// Canonical form of a bill identifier for use as a key: uppercased, with spaces and hyphens
+/// removed, so `HB 1`, `HB1`, and `hb-1` all become `HB1`
+pub fn normalize_bill_identifier(id: &str) -> String {
+    id.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Extract country, state, and session_id from a log path
+/// Path format: .../country:us/state:il/sessions/104th/bills/...
+pub fn extract_path_info(path: &str) -> Option<(String, String, String)> {
+    // Find country: pattern
+    let country_start = path.find("country:")?;
+    let country_end = path[country_start + 8..].find('/').unwrap_or(path.len() - country_start - 8);
+    let country = path[country_start + 8..country_start + 8 + country_end].to_string();
+
+    // Find state: pattern
+    let state_start = path.find("/state:")?;
+    let state_end = path[state_start + 7..].find('/').unwrap_or(path.len() - state_start - 7);
+    let state = path[state_start + 7..state_start + 7 + state_end].to_string();
+
+    // Find sessions/ pattern
+    let sessions_start = path.find("/sessions/")?;
+    let session_end = path[sessions_start + 10..].find('/').unwrap_or(path.len() - sessions_start - 10);
+    let session_id = path[sessions_start + 10..sessions_start + 10 + session_end].to_string();
+
+    Some((country, state, session_id))
 }
 
 /// Internal representation of a file with its timestamp
@@ -66,8 +66,10 @@ async fn test_log_entry_structure() {
     let entry = LogEntry {
         log: LogContent::VoteEvent {
             result: VoteEventResult::Pass,
+            detail: serde_json::Value::Null,
         },
         filename: "test/path/to/logs/20240101T120000Z_vote_event.pass.json".to_string(),
+        metadata: None,
     };
 
     // Use assert_json_snapshot! for structured data
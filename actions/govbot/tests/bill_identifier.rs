@@ -0,0 +1,55 @@
+use govbot::types::{extract_bill_identifier, normalize_bill_identifier};
+use serde_json::json;
+
+#[test]
+fn test_raw_log_variants() {
+    assert_eq!(extract_bill_identifier(&json!({"bill_id": "HB0001"})).as_deref(), Some("HB0001"));
+    assert_eq!(extract_bill_identifier(&json!({"bill_identifier": "SB 2"})).as_deref(), Some("SB 2"));
+}
+
+#[test]
+fn test_selected_output_uses_top_level_id() {
+    let entry = json!({"id": "HB0003", "log": {"action": {"description": "Introduced"}}});
+    assert_eq!(extract_bill_identifier(&entry).as_deref(), Some("HB0003"));
+}
+
+#[test]
+fn test_nested_log_variants() {
+    assert_eq!(extract_bill_identifier(&json!({"log": {"bill_id": "HB0004"}})).as_deref(), Some("HB0004"));
+    assert_eq!(extract_bill_identifier(&json!({"log": {"bill_identifier": "HB0005"}})).as_deref(), Some("HB0005"));
+    assert_eq!(extract_bill_identifier(&json!({"bill": {"identifier": "HB0006"}})).as_deref(), Some("HB0006"));
+}
+
+#[test]
+fn test_precedence_order() {
+    let entry = json!({
+        "bill_id": "A",
+        "bill_identifier": "B",
+        "id": "C",
+        "log": {"bill_id": "D", "bill_identifier": "E"},
+        "bill": {"identifier": "F"}
+    });
+    assert_eq!(extract_bill_identifier(&entry).as_deref(), Some("A"));
+
+    let entry = json!({"id": "C", "log": {"bill_id": "D"}, "bill": {"identifier": "F"}});
+    assert_eq!(extract_bill_identifier(&entry).as_deref(), Some("C"));
+
+    let entry = json!({"log": {"bill_id": "D", "bill_identifier": "E"}, "bill": {"identifier": "F"}});
+    assert_eq!(extract_bill_identifier(&entry).as_deref(), Some("D"));
+}
+
+#[test]
+fn test_empty_and_non_string_ids_are_skipped() {
+    let entry = json!({"bill_id": "  ", "id": 42, "log": {"bill_id": "HB0007"}});
+    assert_eq!(extract_bill_identifier(&entry).as_deref(), Some("HB0007"));
+    assert_eq!(extract_bill_identifier(&json!({"log": {}})), None);
+}
+
+#[test]
+fn test_normalized_identifier_variants_share_a_key() {
+    for variant in ["HB 1", "HB1", "hb-1", " hb - 1 "] {
+        assert_eq!(normalize_bill_identifier(variant), "HB1", "{:?}", variant);
+    }
+    assert_ne!(normalize_bill_identifier("HB 1"), normalize_bill_identifier("HB 10"));
+    assert_eq!(normalize_bill_identifier("SJR-0002"), "SJR0002");
+}
@@ -0,0 +1,369 @@
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const CONFIG: &str = r#"repos:
+  - wy
+tags:
+  animals:
+    description: "Bills about animals"
+build:
+  base_url: "https://config.example.com"
+  output_dir: "docs"
+"#;
+
+fn mocks_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("mocks/.govbot")
+}
+
+/// Create a project directory with govbot.yml and a tag file marking HB0003 as `animals`
+fn setup_project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("govbot.yml"), CONFIG).unwrap();
+
+    let tags_dir = dir.path().join("country:us/state:wy/sessions/2025/tags");
+    fs::create_dir_all(&tags_dir).unwrap();
+    let tag_file = json!({
+        "metadata": { "last_run": "2025-01-01T00:00:00Z", "model": "keyword", "tag_config_hash": "" },
+        "tag_config": { "name": "animals", "description": "Bills about animals" },
+        "bills": {
+            "HB0003": {
+                "text_hash": "",
+                "score": {
+                    "final_score": 0.9,
+                    "base_embedding": null,
+                    "example_similarity": null,
+                    "negative_penalty": 0.0
+                }
+            }
+        }
+    });
+    fs::write(tags_dir.join("animals.tag.json"), tag_file.to_string()).unwrap();
+    dir
+}
+
+fn run_build(project: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .arg("build")
+        .args(args)
+        .current_dir(project)
+        .env("GOVBOT_DIR", mocks_dir())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_base_url_override_changes_item_links() {
+    let project = setup_project();
+
+    let output = run_build(
+        project.path(),
+        &["--base-url", "https://staging.example.com", "--title", "Staging Feed"],
+    );
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<link>https://staging.example.com/wy-legislation/"));
+    assert!(!feed.contains("https://config.example.com"));
+    assert!(feed.contains("<title>Staging Feed</title>"));
+
+    // Config file is untouched
+    assert_eq!(fs::read_to_string(project.path().join("govbot.yml")).unwrap(), CONFIG);
+
+    // Without the override the config value is used
+    let output = run_build(project.path(), &[]);
+    assert!(output.status.success());
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<link>https://config.example.com/wy-legislation/"));
+}
+
+#[test]
+fn test_merge_keeps_items_only_in_old_feed() {
+    let project = setup_project();
+    let docs = project.path().join("docs");
+    fs::create_dir_all(&docs).unwrap();
+    fs::write(
+        docs.join("feed.xml"),
+        r#"<?xml version="1.0" encoding="utf-8"?><rss version="2.0"><channel><title>Old</title><link>https://config.example.com</link><description>Old feed</description><item><title>Rotated out</title><guid isPermaLink="false">old-item-guid</guid><pubDate>Mon, 01 Jan 2024 00:00:00 +0000</pubDate></item></channel></rss>"#,
+    )
+    .unwrap();
+
+    let output = run_build(project.path(), &["--merge"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let feed = fs::read_to_string(docs.join("feed.xml")).unwrap();
+    assert!(feed.contains("old-item-guid"), "{}", feed);
+    assert!(feed.contains("HB0003"));
+    assert!(!feed.contains("<title>Old</title>"), "channel metadata comes from the new build");
+
+    // Merging again doesn't duplicate anything
+    let output = run_build(project.path(), &["--merge"]);
+    assert!(output.status.success());
+    let remerged = fs::read_to_string(docs.join("feed.xml")).unwrap();
+    assert_eq!(remerged.matches("<item>").count(), feed.matches("<item>").count());
+
+    // Without --merge the old item is gone
+    let output = run_build(project.path(), &[]);
+    assert!(output.status.success());
+    assert!(!fs::read_to_string(docs.join("feed.xml")).unwrap().contains("old-item-guid"));
+}
+
+#[test]
+fn test_config_flag_reads_govbot_yml_from_another_directory() {
+    let project = setup_project();
+    let work = tempfile::tempdir_in(project.path().parent().unwrap()).unwrap();
+    // Relative to the working directory, e.g. ../<project>/govbot.yml
+    let config = Path::new("..")
+        .join(project.path().file_name().unwrap())
+        .join("govbot.yml");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .arg("build")
+        .arg("--config")
+        .arg(&config)
+        .current_dir(work.path())
+        .env("GOVBOT_DIR", mocks_dir())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    // Tag definitions, tag files, and output_dir all resolve relative to the config file
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<link>https://config.example.com/wy-legislation/"));
+    assert!(!work.path().join("docs").exists());
+
+    // Without --config the working directory has no govbot.yml
+    let output = run_build(work.path(), &[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("govbot.yml not found"));
+}
+
+#[test]
+fn test_missing_config_path_is_reported() {
+    let work = tempfile::tempdir().unwrap();
+    let output = run_build(work.path(), &["--config", "../other/govbot.yml"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Config file not found") && stderr.contains("other/govbot.yml"), "{}", stderr);
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        } else {
+            fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_feed_per_repo_splits_items_by_repo() {
+    let project = setup_project();
+    fs::write(
+        project.path().join("govbot.yml"),
+        CONFIG.replace("  - wy\n", "  - wy\n  - il\n"),
+    )
+    .unwrap();
+    let wy_tags = project.path().join("country:us/state:wy/sessions/2025/tags");
+    let il_tags = project.path().join("country:us/state:il/sessions/2025/tags");
+    copy_dir(&wy_tags, &il_tags);
+
+    // Two repos serving the same mock bill
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let bill = "sessions/2025/bills/HB0003";
+    for state in ["wy", "il"] {
+        copy_dir(
+            &mocks_dir().join("repos/wy-legislation/country:us/state:wy").join(bill),
+            &govbot_dir
+                .path()
+                .join(format!("repos/{}-legislation/country:us/state:{}", state, state))
+                .join(bill),
+        );
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["build", "--feed-per-repo", "--limit", "1"])
+        .current_dir(project.path())
+        .env("GOVBOT_DIR", govbot_dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let docs = project.path().join("docs");
+    assert_eq!(fs::read_to_string(docs.join("feed.xml")).unwrap().matches("<item>").count(), 1);
+    for (repo, other) in [("wy-legislation", "il-legislation"), ("il-legislation", "wy-legislation")] {
+        let feed = fs::read_to_string(docs.join(format!("{}.xml", repo))).unwrap();
+        // The limit applies per repo, not to the combined feed
+        assert_eq!(feed.matches("<item>").count(), 1, "{}", feed);
+        assert!(feed.contains(&format!("/{}/", repo)));
+        assert!(!feed.contains(other), "{} feed has {} items", repo, other);
+        assert!(feed.contains(&format!("({})</title>", repo)));
+    }
+}
+
+#[test]
+fn test_configured_language_is_written_to_feed() {
+    let project = setup_project();
+    let config = CONFIG.replace("build:\n", "build:\n  language: \"fr-ca\"\n");
+    fs::write(project.path().join("govbot.yml"), config).unwrap();
+
+    let output = run_build(project.path(), &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<language>fr-ca</language>"), "{}", feed);
+    let index = fs::read_to_string(project.path().join("docs/index.html")).unwrap();
+    assert!(index.contains(r#"<html lang="fr-ca">"#));
+
+    // The flag wins over the config
+    let output = run_build(project.path(), &["--language", "es"]);
+    assert!(output.status.success());
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<language>es</language>"));
+
+    let output = run_build(project.path(), &["--language", "not a language"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid feed language"));
+}
+
+#[test]
+fn test_paginate_writes_linked_archive_pages() {
+    let project = setup_project();
+    fs::write(
+        project.path().join("govbot.yml"),
+        CONFIG.replace("  - wy\n", "  - wy\n  - il\n"),
+    )
+    .unwrap();
+    copy_dir(
+        &project.path().join("country:us/state:wy/sessions/2025/tags"),
+        &project.path().join("country:us/state:il/sessions/2025/tags"),
+    );
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let bill = "sessions/2025/bills/HB0003";
+    for state in ["wy", "il"] {
+        copy_dir(
+            &mocks_dir().join("repos/wy-legislation/country:us/state:wy").join(bill),
+            &govbot_dir
+                .path()
+                .join(format!("repos/{}-legislation/country:us/state:{}", state, state))
+                .join(bill),
+        );
+    }
+
+    let build = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["build", "--paginate", "1"])
+            .args(args)
+            .current_dir(project.path())
+            .env("GOVBOT_DIR", govbot_dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(output.status.success(), "{}", stderr);
+        stderr
+    };
+    let docs = project.path().join("docs");
+    let count_archives = || {
+        fs::read_dir(&docs)
+            .unwrap()
+            .filter(|e| e.as_ref().unwrap().file_name().to_string_lossy().starts_with("feed-"))
+            .count()
+    };
+
+    // A page left over from an earlier, longer build
+    fs::create_dir_all(&docs).unwrap();
+    fs::write(docs.join("feed-99.xml"), "<rss/>").unwrap();
+    build(&[]);
+    assert!(!docs.join("feed-99.xml").exists());
+
+    // One entry per page: every older entry gets an archive page
+    let current = fs::read_to_string(docs.join("feed.xml")).unwrap();
+    let archives = count_archives();
+    assert!(archives >= 1);
+    // The limit doesn't cut the paged history short
+    build(&["--limit", "1"]);
+    assert_eq!(count_archives(), archives);
+    assert_eq!(current.matches("<item>").count(), 1);
+    assert!(
+        current.contains(&format!(r#"href="https://config.example.com/feed-{}.xml" rel="prev-archive""#, archives)),
+        "{}",
+        current
+    );
+
+    let oldest = fs::read_to_string(docs.join("feed-1.xml")).unwrap();
+    assert_eq!(oldest.matches("<item>").count(), 1);
+    assert!(oldest.contains(r#"href="https://config.example.com/feed.xml" rel="current""#), "{}", oldest);
+    assert!(!oldest.contains("prev-archive"));
+
+    // --validate-feed covers the archive pages and per-repo feeds, not just feed.xml
+    let stderr = build(&["--feed-per-repo", "--validate-feed"]);
+    assert!(stderr.contains(&format!("Validated {} feed file(s)", archives + 3)), "{}", stderr);
+}
+
+#[test]
+fn test_rebuild_after_config_change_updates_feed() {
+    // `build --watch` reruns the same build on each change; drive that build directly
+    let project = setup_project();
+    let output = run_build(project.path(), &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<link>https://config.example.com/wy-legislation/"));
+
+    fs::write(
+        project.path().join("govbot.yml"),
+        CONFIG.replace("https://config.example.com", "https://edited.example.com"),
+    )
+    .unwrap();
+    let output = run_build(project.path(), &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let feed = fs::read_to_string(project.path().join("docs/feed.xml")).unwrap();
+    assert!(feed.contains("<link>https://edited.example.com/wy-legislation/"), "{}", feed);
+    assert!(!feed.contains("https://config.example.com"));
+}
+
+/// Every path under `dir`, relative and sorted
+fn list_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = walkdir_paths(dir).into_iter().map(|p| p.strip_prefix(dir).unwrap().to_path_buf()).collect();
+    files.sort();
+    files
+}
+
+fn walkdir_paths(dir: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            paths.extend(walkdir_paths(&path));
+        }
+        paths.push(path);
+    }
+    paths
+}
+
+#[test]
+fn test_preview_prints_feed_without_writing_files() {
+    let project = setup_project();
+    let before = list_files(project.path());
+
+    let output = run_build(project.path(), &["--preview", "--validate-feed"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let (feed, html) = stdout.split_once("\n==> docs/index.html <==\n").expect("HTML index section");
+    let feed = feed.strip_prefix("==> docs/feed.xml <==\n").expect("feed section first");
+    assert!(feed.starts_with("<?xml") && feed.contains("<rss"), "{}", feed);
+    assert!(feed.contains("<link>https://config.example.com/wy-legislation/"));
+    assert!(html.contains("<html") && html.contains("</html>"), "{}", html);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("would write HTML index"), "{}", stderr);
+
+    assert!(!project.path().join("docs").exists());
+    assert_eq!(list_files(project.path()), before);
+}
@@ -0,0 +1,242 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::{commit_data, govbot_with_origins, init_origin};
+use git2::{Repository, RepositoryInitOptions, Signature, Time};
+use govbot::git::format_commit_age;
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+fn govbot(dir: &Path, args: &[&str]) -> Output {
+    let output = govbot_with_origins(dir).args(args).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+/// `govbot clone <args> --no-emoji`, returning stderr
+fn clone(dir: &Path, args: &[&str]) -> String {
+    let output = govbot_with_origins(dir).arg("clone").args(args).arg("--no-emoji").output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stderr).unwrap()
+}
+
+fn result_line<'a>(stderr: &'a str, locale: &str) -> Option<&'a str> {
+    stderr.lines().find(|l| l.split_whitespace().nth(1) == Some(locale))
+}
+
+/// Create an empty-tree commit dated `days_ago` days in the past
+fn init_repo_dated(path: &Path, days_ago: i64) {
+    let repo = Repository::init(path).unwrap();
+    let when = Utc::now() - Duration::days(days_ago) - Duration::hours(1);
+    let sig = Signature::new("test", "test@example.com", &Time::new(when.timestamp(), 0)).unwrap();
+    let tree = repo.find_tree(repo.index().unwrap().write_tree().unwrap()).unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[]).unwrap();
+}
+
+fn clone_wy(dir: &Path, args: &[&str], envs: &[(&str, &str)]) -> String {
+    let mut cmd = govbot_with_origins(dir);
+    cmd.args(["clone", "wy"])
+        .args(args)
+        .env_remove("NO_COLOR")
+        .env_remove("GOVBOT_PLAIN");
+    for (key, value) in envs {
+        cmd.env(key, value);
+    }
+    let output = cmd.output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn test_no_emoji_clone_output_is_ascii() {
+    let dir = tempfile::tempdir().unwrap();
+    init_origin(&dir.path().join("origin/wy-legislation.git"));
+
+    let stderr = clone_wy(dir.path(), &["--no-emoji"], &[]);
+    assert!(stderr.is_ascii(), "non-ASCII output:\n{}", stderr);
+    assert!(stderr.lines().any(|l| l.starts_with("new") && l.contains("wy")), "{}", stderr);
+
+    // Second run finds the repo already up to date
+    let stderr = clone_wy(dir.path(), &["--no-emoji"], &[]);
+    assert!(stderr.is_ascii(), "non-ASCII output:\n{}", stderr);
+    assert!(stderr.lines().any(|l| l.starts_with("ok") && l.contains("wy")), "{}", stderr);
+}
+
+#[test]
+fn test_plain_env_vars_disable_emoji() {
+    let dir = tempfile::tempdir().unwrap();
+    init_origin(&dir.path().join("origin/wy-legislation.git"));
+
+    assert!(clone_wy(dir.path(), &[], &[("NO_COLOR", "1")]).is_ascii());
+    assert!(clone_wy(dir.path(), &[], &[("GOVBOT_PLAIN", "1")]).is_ascii());
+    assert!(!clone_wy(dir.path(), &[], &[]).is_ascii());
+}
+
+#[test]
+fn test_manifest_locale_is_cloneable_and_deletable() {
+    let dir = tempfile::tempdir().unwrap();
+    init_origin(&dir.path().join("origin/acme-pipeline.git"));
+    init_origin(&dir.path().join("origin/wy-legislation.git"));
+    fs::create_dir_all(dir.path().join("govbot")).unwrap();
+    fs::write(dir.path().join("govbot/repos.txt"), "acme acme-pipeline\n").unwrap();
+
+    // Discovered from the govbot dir; built-in locales still resolve alongside it
+    govbot(dir.path(), &["clone", "acme", "wy", "--no-emoji"]);
+    assert!(dir.path().join("govbot/repos/acme-pipeline/.git").exists());
+    assert!(dir.path().join("govbot/repos/wy-legislation/.git").exists());
+
+    let list = govbot(dir.path(), &["clone", "--list"]);
+    let list = String::from_utf8(list.stdout).unwrap();
+    assert!(list.lines().any(|l| l.trim() == "acme"));
+    assert!(list.lines().any(|l| l.trim() == "wy"));
+
+    govbot(dir.path(), &["delete", "acme"]);
+    assert!(!dir.path().join("govbot/repos/acme-pipeline").exists());
+    assert!(dir.path().join("govbot/repos/wy-legislation").exists());
+}
+
+#[test]
+fn test_explicit_manifest_flag() {
+    let dir = tempfile::tempdir().unwrap();
+    init_origin(&dir.path().join("origin/acme-legislation.git"));
+    let manifest = dir.path().join("private.yml");
+    fs::write(&manifest, "repos:\n  - acme\n").unwrap();
+
+    govbot(dir.path(), &["clone", "acme", "--manifest", manifest.to_str().unwrap()]);
+    assert!(dir.path().join("govbot/repos/acme-legislation/.git").exists());
+}
+
+#[test]
+fn test_only_missing_skips_present_repos() {
+    let dir = tempfile::tempdir().unwrap();
+    init_origin(&dir.path().join("origin/wy-legislation.git"));
+    init_origin(&dir.path().join("origin/gu-legislation.git"));
+    clone(dir.path(), &["wy"]);
+
+    let stderr = clone(dir.path(), &["wy", "gu", "--only-missing"]);
+    assert!(result_line(&stderr, "wy").is_none(), "{}", stderr);
+    assert!(result_line(&stderr, "gu").unwrap().starts_with("new"), "{}", stderr);
+    assert!(stderr.contains("Syncing 1 repos"), "{}", stderr);
+
+    // Without the flag, present repos are pulled as usual
+    let stderr = clone(dir.path(), &["wy", "gu"]);
+    assert!(result_line(&stderr, "wy").is_some());
+    assert!(result_line(&stderr, "gu").is_some());
+}
+
+/// Parallel clones finish in any order, but the summary lists failures in request order
+#[test]
+fn test_parallel_clone_summary_follows_request_order() {
+    let dir = tempfile::tempdir().unwrap();
+    // Only wy has an origin; the others fail at different points relative to it
+    init_origin(&dir.path().join("origin/wy-legislation.git"));
+
+    for _ in 0..3 {
+        let govbot_dir = tempfile::tempdir().unwrap();
+        let output = govbot_with_origins(dir.path())
+            .args(["clone", "il", "wy", "gu", "ca", "--parallel", "4", "--no-emoji"])
+            .env("GOVBOT_DIR", govbot_dir.path())
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("Errors occurred: 3/4"), "{}", stderr);
+        assert!(stderr.lines().any(|l| l == "Failed: il, gu, ca"), "{}", stderr);
+    }
+}
+
+#[test]
+fn test_clone_status_reports_commit_age() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo_dated(&dir.path().join("govbot/repos/wy-legislation"), 3);
+
+    let stdout = String::from_utf8(govbot(dir.path(), &["clone", "--status"]).stdout).unwrap();
+    let line = |code: &str| {
+        stdout
+            .lines()
+            .find(|l| l.split_whitespace().next() == Some(code))
+            .unwrap_or_else(|| panic!("no status line for {}:\n{}", code, stdout))
+            .to_string()
+    };
+    assert!(line("wy").contains(" Wyoming ") && line("wy").ends_with("last commit 3 days ago"), "{}", line("wy"));
+    assert!(line("gu").contains(" Guam ") && line("gu").ends_with("not cloned"), "{}", line("gu"));
+}
+
+#[test]
+fn test_clone_status_uses_manifest_display_names() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("govbot")).unwrap();
+    fs::write(
+        dir.path().join("govbot/repos.yml"),
+        "replace: true\nrepos:\n  - locale: acme\n    name: Acme County\n  - wy\n",
+    )
+    .unwrap();
+
+    let stdout = String::from_utf8(govbot(dir.path(), &["clone", "--status"]).stdout).unwrap();
+    assert!(stdout.lines().any(|l| l.trim_start().starts_with("acme Acme County") && l.ends_with("not cloned")), "{}", stdout);
+    assert!(stdout.lines().any(|l| l.trim_start().starts_with("wy   Wyoming")), "{}", stdout);
+}
+
+#[test]
+fn test_format_commit_age() {
+    let now = Utc.with_ymd_and_hms(2025, 1, 10, 12, 0, 0).unwrap();
+    assert_eq!(format_commit_age(now, now), "last commit today");
+    assert_eq!(format_commit_age(now - Duration::days(1), now), "last commit 1 day ago");
+    assert_eq!(format_commit_age(now - Duration::days(9), now), "last commit 9 days ago");
+}
+
+#[test]
+fn test_dirty_repo_is_skipped_unless_allowed() {
+    let dir = tempfile::tempdir().unwrap();
+    let origin = Repository::init(dir.path().join("origin/wy-legislation.git")).unwrap();
+    commit_data(&origin, "v1");
+    clone(dir.path(), &["wy"]);
+
+    // A local edit, then a new upstream commit touching the same file
+    let local_file = dir.path().join("govbot/repos/wy-legislation/data.json");
+    fs::write(&local_file, "local edit").unwrap();
+    commit_data(&origin, "v2");
+
+    let stderr = clone(dir.path(), &["wy"]);
+    assert!(stderr.contains("uncommitted changes"), "{}", stderr);
+    assert!(stderr.contains("Skipped with uncommitted changes: wy"), "{}", stderr);
+    assert!(stderr.contains("Processed 0/1 repos"), "{}", stderr);
+    assert!(!stderr.contains("Successfully processed"), "{}", stderr);
+    assert_eq!(fs::read_to_string(&local_file).unwrap(), "local edit");
+
+    clone(dir.path(), &["wy", "--allow-dirty"]);
+    assert_eq!(fs::read_to_string(&local_file).unwrap(), "v2");
+}
+
+#[test]
+fn test_pull_reports_a_skipped_dirty_repo() {
+    let dir = tempfile::tempdir().unwrap();
+    let repos_dir = dir.path().join("repos");
+    let repo = Repository::init(repos_dir.join("wy-legislation")).unwrap();
+    commit_data(&repo, "v1");
+    fs::write(repos_dir.join("wy-legislation/data.json"), "local edit").unwrap();
+
+    let err = govbot::git::pull_repo_quiet("wy", &repos_dir, None, true).unwrap_err();
+    assert!(matches!(err, govbot::Error::DirtyWorkTree(_)), "{}", err);
+    assert_eq!(fs::read_to_string(repos_dir.join("wy-legislation/data.json")).unwrap(), "local edit");
+}
+
+#[test]
+fn test_clone_and_pull_follow_remote_default_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut options = RepositoryInitOptions::new();
+    options.initial_head("develop");
+    let origin = Repository::init_opts(dir.path().join("origin/wy-legislation.git"), &options).unwrap();
+    commit_data(&origin, "v1");
+
+    clone(dir.path(), &["wy"]);
+    let local_path = dir.path().join("govbot/repos/wy-legislation");
+    let local = Repository::open(&local_path).unwrap();
+    assert_eq!(local.head().unwrap().shorthand(), Some("develop"));
+    assert_eq!(fs::read_to_string(local_path.join("data.json")).unwrap(), "v1");
+
+    commit_data(&origin, "v2");
+    clone(dir.path(), &["wy"]);
+    assert_eq!(local.head().unwrap().shorthand(), Some("develop"));
+    assert_eq!(fs::read_to_string(local_path.join("data.json")).unwrap(), "v2");
+}
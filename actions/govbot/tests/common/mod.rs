@@ -0,0 +1,64 @@
+//! Fixtures shared by the integration tests
+#![allow(dead_code)]
+
+use git2::{Repository, Signature};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Local origin repo with one empty commit, so `clone` can run without network access
+pub fn init_origin(path: &Path) {
+    let repo = Repository::init(path).unwrap();
+    let sig = Signature::now("test", "test@example.com").unwrap();
+    let tree = repo.find_tree(repo.index().unwrap().write_tree().unwrap()).unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[]).unwrap();
+}
+
+/// Commit `data.json` with `contents` in `repo`'s work tree
+pub fn commit_data(repo: &Repository, contents: &str) {
+    fs::write(repo.workdir().unwrap().join("data.json"), contents).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("data.json")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("test", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, contents, &tree, &parents).unwrap();
+}
+
+/// `govbot` with its directory at `dir/govbot`, cloning `{locale}-legislation` repos from
+/// the origins under `dir/origin`
+pub fn govbot_with_origins(dir: &Path) -> Command {
+    let template = format!("file://{}/origin/{{locale}}-legislation.git", dir.display());
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_govbot"));
+    cmd.env("GOVBOT_DIR", dir.join("govbot"))
+        .env("GOVBOT_REPO_URL_TEMPLATE", template);
+    cmd
+}
+
+/// A `logs` output entry for bill `id` in the 2025 Wyoming session
+pub fn log_entry(id: &str, title: &str, tags: &[&str]) -> Value {
+    let tags: serde_json::Map<String, Value> = tags
+        .iter()
+        .map(|t| (t.to_string(), json!({ "final_score": 0.9 })))
+        .collect();
+    json!({
+        "id": id,
+        "log": { "action": { "description": "Introduced" } },
+        "bill": { "title": title },
+        "tags": tags,
+        "sources": {
+            "log": format!("wy-legislation/country:us/state:wy/sessions/2025/bills/{}/logs/20250101T000000Z_introduced.json", id)
+        },
+        "timestamp": "20250101T000000Z",
+    })
+}
+
+/// Write `contents` as the `20250101T000000Z_introduced.json` log of the bill at `bill_dir`
+pub fn write_log(bill_dir: &Path, contents: impl AsRef<[u8]>) {
+    let logs_dir = bill_dir.join("logs");
+    fs::create_dir_all(&logs_dir).unwrap();
+    fs::write(logs_dir.join("20250101T000000Z_introduced.json"), contents).unwrap();
+}
@@ -0,0 +1,58 @@
+use govbot::{ConfigBuilder, JoinOption, SortOrder};
+
+fn config_error(result: govbot::Result<impl std::fmt::Debug>) -> String {
+    result.unwrap_err().to_string()
+}
+
+#[test]
+fn test_missing_git_dir_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("no-such-repos");
+
+    let err = config_error(ConfigBuilder::new(&missing).build());
+    assert!(err.contains("Git directory does not exist"), "{}", err);
+    assert!(err.contains("no-such-repos"), "{}", err);
+    assert!(ConfigBuilder::new(&missing).validate().is_err());
+
+    let file = dir.path().join("repos.txt");
+    std::fs::write(&file, "").unwrap();
+    let err = config_error(ConfigBuilder::new(&file).build());
+    assert!(err.contains("not a directory"), "{}", err);
+}
+
+#[test]
+fn test_unknown_join_option_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = config_error(ConfigBuilder::new(dir.path()).join_options_str("bill,sponsors"));
+    assert!(err.contains("Invalid join value 'sponsors'"), "{}", err);
+    assert!(err.contains("bill, full_metadata"), "{}", err);
+
+    let err = config_error(ConfigBuilder::new(dir.path()).join_options_str("bill,,full_metadata"));
+    assert!(err.contains("Empty join option"), "{}", err);
+    assert!(err.contains("bill, full_metadata"), "{}", err);
+
+    // Every listed value is accepted
+    for value in JoinOption::VALUES {
+        assert!(ConfigBuilder::new(dir.path()).join_options_str(value).is_ok(), "{}", value);
+    }
+}
+
+#[test]
+fn test_invalid_sort_order_and_limits_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = config_error(ConfigBuilder::new(dir.path()).sort_order_str("newest"));
+    assert!(err.contains("Invalid sort order 'newest'"), "{}", err);
+    assert!(err.contains("Allowed values are: ASC, DESC"), "{}", err);
+    for value in SortOrder::VALUES {
+        assert!(SortOrder::parse(value).is_ok(), "{}", value);
+    }
+    assert_eq!(SortOrder::parse("asc").unwrap(), SortOrder::Ascending);
+
+    let err = config_error(ConfigBuilder::new(dir.path()).limit(0).build());
+    assert!(err.contains("Limit must be at least 1"), "{}", err);
+
+    let err = config_error(ConfigBuilder::new(dir.path()).walk_threads(0).build());
+    assert!(err.contains("Walk threads must be at least 1"), "{}", err);
+
+    assert!(ConfigBuilder::new(dir.path()).sort_order_str("ASC").unwrap().limit(5).walk_threads(1).build().is_ok());
+}
@@ -0,0 +1,46 @@
+use git2::Repository;
+use serde_json::Value;
+use std::fs;
+use std::process::Command;
+
+fn delete_json(parallel: &str) -> (tempfile::TempDir, Vec<Value>) {
+    let dir = tempfile::tempdir().unwrap();
+    let wy = dir.path().join("repos/wy-legislation");
+    Repository::init(&wy).unwrap();
+    fs::write(wy.join("README.md"), "wyoming").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["delete", "wy", "gu", "--json", "--parallel", parallel])
+        .env("GOVBOT_DIR", dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let records: Vec<Value> = serde_json::from_slice(&output.stdout).unwrap();
+    (dir, records)
+}
+
+fn assert_records(records: &[Value]) {
+    assert_eq!(records.len(), 2, "{:?}", records);
+    assert_eq!(records[0]["locale"], "wy");
+    assert_eq!(records[0]["status"], "deleted");
+    assert!(records[0]["freed_bytes"].as_u64().unwrap() > 0);
+    assert!(records[0]["error"].is_null());
+    assert_eq!(records[1]["locale"], "gu");
+    assert_eq!(records[1]["status"], "not_found");
+    assert_eq!(records[1]["freed_bytes"], 0);
+}
+
+#[test]
+fn test_sequential_delete_json() {
+    let (dir, records) = delete_json("1");
+    assert_records(&records);
+    assert!(!dir.path().join("repos/wy-legislation").exists());
+}
+
+#[test]
+fn test_parallel_delete_json() {
+    let (dir, records) = delete_json("4");
+    assert_records(&records);
+    assert!(!dir.path().join("repos/wy-legislation").exists());
+}
@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run_doctor(project: &Path, govbot_dir: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["doctor", "--no-emoji", "--offline"])
+        .current_dir(project)
+        .env("GOVBOT_DIR", govbot_dir)
+        .output()
+        .unwrap()
+}
+
+/// The checklist line for the check called `name`
+fn check_line<'a>(stdout: &'a str, name: &str) -> &'a str {
+    stdout
+        .lines()
+        .find(|line| line.contains(&format!(" {} ", name)))
+        .unwrap_or_else(|| panic!("no {} check in:\n{}", name, stdout))
+}
+
+#[test]
+fn test_missing_govbot_dir_is_a_failure() {
+    let project = tempfile::tempdir().unwrap();
+    let missing = project.path().join("nowhere/.govbot");
+
+    let output = run_doctor(project.path(), &missing);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = check_line(&stdout, "govbot dir");
+    assert!(line.starts_with("fail"), "{}", line);
+    assert!(line.contains("not found"), "{}", line);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 check(s) failed"));
+
+    // Without govbot.yml the config check only warns
+    assert!(check_line(&stdout, "govbot.yml").starts_with("warn"), "{}", stdout);
+    assert!(check_line(&stdout, "model").contains("not checked (offline)"), "{}", stdout);
+}
+
+#[test]
+fn test_writable_govbot_dir_and_valid_config_pass() {
+    let project = tempfile::tempdir().unwrap();
+    let govbot_dir = project.path().join(".govbot");
+    fs::create_dir_all(&govbot_dir).unwrap();
+    fs::write(project.path().join("govbot.yml"), "repos: [wy]\ntags:\n  animals:\n    description: Animals\n").unwrap();
+
+    let output = run_doctor(project.path(), &govbot_dir);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(check_line(&stdout, "govbot dir").starts_with("pass"), "{}", stdout);
+    assert!(check_line(&stdout, "govbot.yml").contains("(1 tags)"), "{}", stdout);
+
+    fs::write(project.path().join("govbot.yml"), "tags: [unclosed\n").unwrap();
+    let output = run_doctor(project.path(), &govbot_dir);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(check_line(&stdout, "govbot.yml").starts_with("fail"), "{}", stdout);
+}
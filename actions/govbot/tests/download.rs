@@ -0,0 +1,180 @@
+use govbot::embeddings::{download_file_with_retries, download_verified_with_retries};
+use sha2::{Digest, Sha256};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// How the fixture server answers one request
+enum Reply {
+    Status(u16),
+    /// Advertise the full length but close after sending this many bytes
+    Truncated(usize),
+    /// Full length, but with every byte flipped
+    Corrupt,
+    Full,
+}
+
+/// Minimal HTTP server answering successive requests from a script; returns the
+/// base URL and the request headers it received. Every response advertises the
+/// body's SHA-256 the way Hugging Face does for LFS files.
+fn serve(body: Vec<u8>, script: Vec<Reply>) -> (String, Arc<Mutex<Vec<Vec<String>>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let etag = format!("X-Linked-Etag: \"{:x}\"\r\n", Sha256::digest(&body));
+    let url = format!("http://{}/model.onnx", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    thread::spawn(move || {
+        for reply in script {
+            let (mut stream, _) = listener.accept().unwrap();
+            let headers = read_headers(&stream);
+            let range_start = headers
+                .iter()
+                .find_map(|h| h.to_lowercase().strip_prefix("range: bytes=").map(|r| r.trim_end_matches('-').to_string()))
+                .and_then(|start| start.parse::<usize>().ok());
+            seen.lock().unwrap().push(headers);
+
+            match reply {
+                Reply::Status(code) => {
+                    write!(stream, "HTTP/1.1 {} Error\r\nContent-Length: 0\r\nConnection: close\r\n{}\r\n", code, etag).unwrap();
+                }
+                Reply::Truncated(n) => {
+                    write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n", body.len(), etag).unwrap();
+                    stream.write_all(&body[..n]).unwrap();
+                }
+                Reply::Corrupt => {
+                    write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n", body.len(), etag).unwrap();
+                    stream.write_all(&body.iter().map(|b| !b).collect::<Vec<u8>>()).unwrap();
+                }
+                Reply::Full => match range_start {
+                    Some(start) => {
+                        write!(
+                            stream,
+                            "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {}-{}/{}\r\nConnection: close\r\n{}\r\n",
+                            body.len() - start, start, body.len() - 1, body.len(), etag
+                        )
+                        .unwrap();
+                        stream.write_all(&body[start..]).unwrap();
+                    }
+                    None => {
+                        write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n", body.len(), etag).unwrap();
+                        stream.write_all(&body).unwrap();
+                    }
+                },
+            }
+            stream.flush().unwrap();
+        }
+    });
+
+    (url, requests)
+}
+
+fn read_headers(stream: &TcpStream) -> Vec<String> {
+    let mut reader = BufReader::new(stream);
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+            break;
+        }
+        headers.push(line.trim_end().to_string());
+    }
+    headers
+}
+
+fn fixture_body() -> Vec<u8> {
+    (0..64 * 1024).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn test_download_retries_after_server_error() {
+    let body = fixture_body();
+    let (url, requests) = serve(body.clone(), vec![Reply::Status(503), Reply::Full]);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    download_file_with_retries(&url, &path, 3, Duration::ZERO).unwrap();
+
+    assert_eq!(std::fs::read(&path).unwrap(), body);
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_download_resumes_partial_body_with_range() {
+    let body = fixture_body();
+    let (url, requests) = serve(body.clone(), vec![Reply::Truncated(10_000), Reply::Full]);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    download_file_with_retries(&url, &path, 3, Duration::ZERO).unwrap();
+
+    assert_eq!(std::fs::read(&path).unwrap(), body);
+    let requests = requests.lock().unwrap();
+    assert!(requests[1].iter().any(|h| h.eq_ignore_ascii_case("range: bytes=10000-")), "{:?}", requests[1]);
+}
+
+#[test]
+fn test_download_gives_up_with_typed_error() {
+    let (url, requests) = serve(fixture_body(), vec![Reply::Status(500), Reply::Status(500)]);
+    let dir = tempfile::tempdir().unwrap();
+
+    let err = download_file_with_retries(&url, &dir.path().join("model.onnx"), 2, Duration::ZERO).unwrap_err();
+    assert!(matches!(err, govbot::Error::Download(_)), "{:?}", err);
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_large_download_streams_to_disk() {
+    // Several megabytes so the body arrives over many reads
+    let body: Vec<u8> = (0..8 * 1024 * 1024).map(|i| (i % 253) as u8).collect();
+    let (url, requests) = serve(body.clone(), vec![Reply::Full]);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    download_file_with_retries(&url, &path, 1, Duration::ZERO).unwrap();
+
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), body.len() as u64);
+    assert_eq!(std::fs::read(&path).unwrap(), body);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_truncated_download_is_an_error() {
+    let (url, _) = serve(fixture_body(), vec![Reply::Truncated(1_000)]);
+    let dir = tempfile::tempdir().unwrap();
+
+    let err = download_file_with_retries(&url, &dir.path().join("model.onnx"), 1, Duration::ZERO).unwrap_err();
+    assert!(matches!(err, govbot::Error::Download(_)), "{:?}", err);
+}
+
+#[test]
+fn test_corrupt_download_is_fetched_again() {
+    let body = fixture_body();
+    let (url, requests) = serve(body.clone(), vec![Reply::Corrupt, Reply::Full]);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    download_verified_with_retries(&url, &path, 3, Duration::ZERO, |_| Ok(())).unwrap();
+
+    assert_eq!(std::fs::read(&path).unwrap(), body);
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_download_that_stays_corrupt_reports_the_hashes() {
+    let body = fixture_body();
+    let (url, requests) = serve(body.clone(), vec![Reply::Corrupt, Reply::Corrupt]);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    let err = download_verified_with_retries(&url, &path, 2, Duration::ZERO, |_| Ok(())).unwrap_err().to_string();
+
+    let corrupt: Vec<u8> = body.iter().map(|b| !b).collect();
+    assert!(err.contains("model.onnx"), "{}", err);
+    assert!(err.contains(&format!("expected {:x}", Sha256::digest(&body))), "{}", err);
+    assert!(err.contains(&format!("got {:x}", Sha256::digest(&corrupt))), "{}", err);
+    assert!(!path.exists(), "a file that failed verification should be deleted");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
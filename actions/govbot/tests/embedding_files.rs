@@ -0,0 +1,87 @@
+use govbot::embeddings::{validate_encoder_outputs, validate_model_file, validate_tokenizer_file, MIN_MODEL_SIZE};
+use std::fs;
+
+/// A model cut short by an interrupted download must be rejected before it reaches ONNX Runtime
+#[test]
+fn test_truncated_model_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    // Valid ONNX leading byte, but only a fraction of the expected size
+    let mut truncated = vec![0x08u8, 0x07];
+    truncated.resize(4096, 0);
+    fs::write(&path, &truncated).unwrap();
+
+    let err = validate_model_file(&path).unwrap_err();
+    assert!(err.to_string().contains("too small"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_model_with_wrong_magic_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    // Large enough, but looks like an HTML error page rather than protobuf
+    let mut body = b"<!DOCTYPE html>".to_vec();
+    body.resize(MIN_MODEL_SIZE as usize, b' ');
+    fs::write(&path, &body).unwrap();
+
+    let err = validate_model_file(&path).unwrap_err();
+    assert!(err.to_string().contains("does not look like an ONNX model"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_complete_model_is_accepted() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+
+    let mut body = vec![0x08u8, 0x07];
+    body.resize(MIN_MODEL_SIZE as usize, 0);
+    fs::write(&path, &body).unwrap();
+
+    assert!(validate_model_file(&path).is_ok());
+}
+
+#[test]
+fn test_truncated_tokenizer_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tokenizer.json");
+
+    let complete = format!(
+        r#"{{"version":"1.0","model":{{"type":"WordPiece","vocab":{{{}}}}}}}"#,
+        (0..200).map(|i| format!("\"tok{}\":{}", i, i)).collect::<Vec<_>>().join(",")
+    );
+    fs::write(&path, &complete).unwrap();
+    assert!(validate_tokenizer_file(&path).is_ok());
+
+    fs::write(&path, &complete[..complete.len() - 10]).unwrap();
+    let err = validate_tokenizer_file(&path).unwrap_err();
+    assert!(err.to_string().contains("not valid JSON"), "unexpected error: {}", err);
+}
+
+/// Outputs as a probe run of the session would report them (name, shape)
+fn outputs(list: &[(&str, &[i64])]) -> Vec<(String, Vec<i64>)> {
+    list.iter().map(|(name, shape)| (name.to_string(), shape.to_vec())).collect()
+}
+
+#[test]
+fn test_pooling_model_is_rejected_at_startup() {
+    // Sentence-transformer exports that already pool only expose a [batch, hidden] embedding
+    let err = validate_encoder_outputs(&outputs(&[("sentence_embedding", &[1, 384])])).unwrap_err();
+    assert!(
+        err.to_string().contains("model output 'last_hidden_state' not found"),
+        "unexpected error: {}",
+        err
+    );
+    assert!(err.to_string().contains("sentence_embedding"));
+    assert!(err.to_string().contains("this matcher requires a token-level encoder"));
+
+    let err = validate_encoder_outputs(&outputs(&[("last_hidden_state", &[1, 384])])).unwrap_err();
+    assert!(err.to_string().contains("expected [batch, seq, hidden]"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_token_level_encoder_is_accepted() {
+    let probe = outputs(&[("last_hidden_state", &[1, 3, 384]), ("pooler_output", &[1, 384])]);
+    assert!(validate_encoder_outputs(&probe).is_ok());
+}
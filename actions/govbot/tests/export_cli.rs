@@ -0,0 +1,104 @@
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn write_tag_file(root: &Path, state: &str, session: &str, tag: &str, bills: &[(&str, f64)]) {
+    let tags_dir = root.join(format!("country:us/state:{}/sessions/{}/tags", state, session));
+    fs::create_dir_all(&tags_dir).unwrap();
+    let bills: serde_json::Map<String, Value> = bills
+        .iter()
+        .map(|(bill, score)| {
+            let result = json!({
+                "text_hash": format!("hash-{}", bill),
+                "score": { "final_score": score, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+            });
+            (bill.to_string(), result)
+        })
+        .collect();
+    let tag_file = json!({
+        "metadata": { "version": 1, "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "x" },
+        "tag_config": { "name": tag },
+        "bills": bills,
+    });
+    fs::write(tags_dir.join(format!("{}.tag.json", tag)), tag_file.to_string()).unwrap();
+}
+
+fn export(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .arg("export")
+        .args(args)
+        .current_dir(dir)
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_export_contains_every_bill_tag_row() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("govbot.yml"), "repos:\n  - wy\n").unwrap();
+    write_tag_file(dir.path(), "wy", "2025", "budget", &[("HB0001", 0.9), ("HB0002", 0.6)]);
+    write_tag_file(dir.path(), "il", "104th", "health", &[("SB0010", 0.75)]);
+
+    let output = export(dir.path(), &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let rows: Vec<Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let summary: Vec<(String, String, String, f64)> = rows
+        .iter()
+        .map(|row| {
+            (
+                row["session"].as_str().unwrap().to_string(),
+                row["tag"].as_str().unwrap().to_string(),
+                row["bill_id"].as_str().unwrap().to_string(),
+                row["final_score"].as_f64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("104th".to_string(), "health".to_string(), "SB0010".to_string(), 0.75),
+            ("2025".to_string(), "budget".to_string(), "HB0001".to_string(), 0.9),
+            ("2025".to_string(), "budget".to_string(), "HB0002".to_string(), 0.6),
+        ]
+    );
+    assert_eq!(rows[0]["state"], json!("il"));
+    assert_eq!(rows[1]["text_hash"], json!("hash-HB0001"));
+
+    // --output writes the same records to a file
+    let out = dir.path().join("export.ndjson");
+    let status = export(dir.path(), &["--output", out.to_str().unwrap()]);
+    assert!(status.status.success());
+    assert_eq!(fs::read_to_string(&out).unwrap().lines().count(), 3);
+}
+
+#[test]
+fn test_parquet_export_requires_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = export(dir.path(), &["--format", "parquet", "--tags-dir", "."]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_export_leaves_old_tag_files_untouched() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("govbot.yml"), "repos:\n  - wy\n").unwrap();
+    write_tag_file(dir.path(), "wy", "2025", "budget", &[("HB0001", 0.9)]);
+    // Rewrite it as a pre-versioning (version 0) file
+    let path = dir.path().join("country:us/state:wy/sessions/2025/tags/budget.tag.json");
+    let mut old: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    old["metadata"].as_object_mut().unwrap().remove("version");
+    let contents = old.to_string();
+    fs::write(&path, &contents).unwrap();
+
+    let output = export(dir.path(), &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).lines().count(), 1);
+    assert_eq!(fs::read_to_string(&path).unwrap(), contents);
+}
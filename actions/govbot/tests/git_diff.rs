@@ -0,0 +1,293 @@
+use git2::{Repository, Signature};
+use govbot::git::{changed_log_files, changed_metadata_files, deleted_metadata_files, DEFAULT_SINCE_REF};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const BILL_DIR: &str = "country:us/state:wy/sessions/2025/bills/HB0001";
+
+/// Write files into the work tree and commit everything
+fn commit_files(repo: &Repository, files: &[(&str, &str)], message: &str) {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    for (path, contents) in files {
+        let full = workdir.join(path);
+        fs::create_dir_all(full.parent().unwrap()).unwrap();
+        fs::write(full, contents).unwrap();
+    }
+    commit_all(repo, message);
+}
+
+/// Delete files from the work tree and commit the removal
+fn remove_files(repo: &Repository, paths: &[&str], message: &str) {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    for path in paths {
+        fs::remove_file(workdir.join(path)).unwrap();
+    }
+    commit_all(repo, message);
+}
+
+fn commit_all(repo: &Repository, message: &str) {
+    let mut index = repo.index().unwrap();
+    index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.update_all(["*"], None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("test", "test@example.com").unwrap();
+    let parents: Vec<git2::Commit> = repo
+        .head()
+        .ok()
+        .and_then(|h| h.peel_to_commit().ok())
+        .into_iter()
+        .collect();
+    let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parent_refs).unwrap();
+}
+
+fn init_fixture(dir: &Path) -> Repository {
+    let repo = Repository::init(dir).unwrap();
+    commit_files(
+        &repo,
+        &[
+            (&format!("{}/metadata.json", BILL_DIR), "{}"),
+            (&format!("{}/logs/20250101T000000Z_introduced.json", BILL_DIR), "{}"),
+        ],
+        "initial",
+    );
+    repo
+}
+
+#[test]
+fn test_diff_lists_only_new_log_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = init_fixture(dir.path());
+
+    let new_log = format!("{}/logs/20250201T000000Z_passed.json", BILL_DIR);
+    commit_files(
+        &repo,
+        &[
+            (&new_log, "{}"),
+            // Non-log changes are not listed
+            (&format!("{}/metadata.json", BILL_DIR), r#"{"title": "updated"}"#),
+        ],
+        "new action",
+    );
+
+    let changed = changed_log_files(dir.path(), DEFAULT_SINCE_REF).unwrap();
+    assert_eq!(changed, vec![PathBuf::from(new_log)]);
+}
+
+#[test]
+fn test_renamed_log_is_listed_under_its_new_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = init_fixture(dir.path());
+
+    let old_log = format!("{}/logs/20250101T000000Z_introduced.json", BILL_DIR);
+    let new_log = format!("{}/logs/20250101T000000Z_introduced_in_house.json", BILL_DIR);
+    fs::rename(dir.path().join(&old_log), dir.path().join(&new_log)).unwrap();
+    let mut index = repo.index().unwrap();
+    index.remove_path(Path::new(&old_log)).unwrap();
+    index.write().unwrap();
+    commit_files(&repo, &[], "rename");
+
+    let changed = changed_log_files(dir.path(), DEFAULT_SINCE_REF).unwrap();
+    assert_eq!(changed, vec![PathBuf::from(new_log)]);
+}
+
+#[test]
+fn test_logs_reads_only_paths_piped_from_diff() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let repo = init_fixture(&govbot_dir.path().join("repos/wy-legislation"));
+    let new_log = format!("{}/logs/20250201T000000Z_passed.json", BILL_DIR);
+    commit_files(&repo, &[(&new_log, r#"{"action": {"description": "Passed"}, "bill_id": "HB0001"}"#)], "new action");
+
+    let govbot = |args: &[&str], stdin: &[u8]| {
+        let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(args)
+            .env("GOVBOT_DIR", govbot_dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        std::io::Write::write_all(&mut child.stdin.take().unwrap(), stdin).unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let diff = govbot(&["diff", "--repos", "wy"], b"");
+    let logs = govbot(&["logs", "--repos", "wy", "--filter", "none", "--paths-from", "-"], diff.as_bytes());
+    let sources: Vec<String> = logs
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["sources"]["log"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(sources, vec![format!("wy-legislation/{}", new_log)]);
+
+    // Without the list, the walk finds both logs
+    assert_eq!(govbot(&["logs", "--repos", "wy", "--filter", "none"], b"").lines().count(), 2);
+}
+
+#[test]
+fn test_diff_unknown_ref_is_error() {
+    let dir = tempfile::tempdir().unwrap();
+    init_fixture(dir.path());
+
+    assert!(changed_log_files(dir.path(), "does-not-exist").is_err());
+}
+
+#[test]
+fn test_changed_metadata_lists_only_new_bill() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = init_fixture(dir.path());
+
+    let new_bill = "country:us/state:wy/sessions/2025/bills/HB0002";
+    commit_files(
+        &repo,
+        &[
+            (&format!("{}/metadata.json", new_bill), r#"{"identifier": "HB0002"}"#),
+            (&format!("{}/logs/20250201T000000Z_introduced.json", new_bill), "{}"),
+        ],
+        "new bill",
+    );
+
+    let changed = changed_metadata_files(dir.path(), DEFAULT_SINCE_REF).unwrap();
+    assert_eq!(changed, vec![PathBuf::from(format!("{}/metadata.json", new_bill))]);
+    assert!(changed_metadata_files(dir.path(), "does-not-exist").is_err());
+}
+
+#[test]
+fn test_deleted_metadata_lists_removed_and_renamed_bills() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = init_fixture(dir.path());
+    let other_bill = "country:us/state:wy/sessions/2025/bills/HB0002";
+    commit_files(
+        &repo,
+        &[(&format!("{}/metadata.json", other_bill), r#"{"identifier": "HB0002", "title": "Water rights"}"#)],
+        "second bill",
+    );
+
+    remove_files(&repo, &[&format!("{}/metadata.json", BILL_DIR)], "drop HB0001");
+    assert_eq!(
+        deleted_metadata_files(dir.path(), DEFAULT_SINCE_REF).unwrap(),
+        vec![PathBuf::from(format!("{}/metadata.json", BILL_DIR))]
+    );
+    assert!(changed_metadata_files(dir.path(), DEFAULT_SINCE_REF).unwrap().is_empty());
+
+    // A renamed bill is deleted under its old name and changed under its new one
+    let renamed = "country:us/state:wy/sessions/2025/bills/HB0003";
+    let workdir = repo.workdir().unwrap();
+    fs::create_dir_all(workdir.join(renamed)).unwrap();
+    fs::rename(
+        workdir.join(format!("{}/metadata.json", other_bill)),
+        workdir.join(format!("{}/metadata.json", renamed)),
+    )
+    .unwrap();
+    commit_all(&repo, "rename HB0002");
+    assert_eq!(
+        deleted_metadata_files(dir.path(), DEFAULT_SINCE_REF).unwrap(),
+        vec![PathBuf::from(format!("{}/metadata.json", other_bill))]
+    );
+    assert_eq!(
+        changed_metadata_files(dir.path(), DEFAULT_SINCE_REF).unwrap(),
+        vec![PathBuf::from(format!("{}/metadata.json", renamed))]
+    );
+}
+
+#[test]
+fn test_load_sqlite_since_commit_removes_deleted_and_adds_new_columns() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let repo_dir = govbot_dir.path().join("repos/wy-legislation");
+    let repo = init_fixture(&repo_dir);
+    let other_bill = "country:us/state:wy/sessions/2025/bills/HB0002";
+    commit_files(
+        &repo,
+        &[
+            (&format!("{}/metadata.json", BILL_DIR), r#"{"identifier": "HB0001"}"#),
+            (&format!("{}/metadata.json", other_bill), r#"{"identifier": "HB0002"}"#),
+        ],
+        "metadata",
+    );
+
+    let load = |args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["load", "--format", "sqlite"])
+            .args(args)
+            .env("GOVBOT_DIR", govbot_dir.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    };
+    load(&[]);
+
+    remove_files(&repo, &[&format!("{}/metadata.json", BILL_DIR)], "drop HB0001");
+    commit_files(
+        &repo,
+        &[(&format!("{}/metadata.json", other_bill), r#"{"identifier": "HB0002", "subtitle": "Amended"}"#)],
+        "new key",
+    );
+    load(&["--since-commit", "HEAD~2"]);
+
+    let conn = rusqlite::Connection::open(govbot_dir.path().join("govbot.sqlite")).unwrap();
+    let rows: Vec<(String, Option<String>)> = conn
+        .prepare("SELECT identifier, subtitle FROM bills ORDER BY identifier")
+        .unwrap()
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows, [("HB0002".to_string(), Some("Amended".to_string()))]);
+}
+
+/// End-to-end incremental load; needs the `duckdb` CLI, so it is a no-op where DuckDB isn't installed
+#[test]
+fn test_load_since_commit_inserts_only_changed_bill() {
+    if std::process::Command::new("duckdb").arg("--version").output().is_err() {
+        eprintln!("duckdb not installed; skipping");
+        return;
+    }
+
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let repo_dir = govbot_dir.path().join("repos/wy-legislation");
+    let repo = init_fixture(&repo_dir);
+    commit_files(&repo, &[(&format!("{}/metadata.json", BILL_DIR), r#"{"identifier": "HB0001"}"#)], "metadata");
+
+    let load = |args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .arg("load")
+            .args(args)
+            .env("GOVBOT_DIR", govbot_dir.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    };
+    let query = |sql: &str| -> String {
+        let output = std::process::Command::new("duckdb")
+            .args(["-noheader", "-list"])
+            .arg(govbot_dir.path().join("govbot.duckdb"))
+            .arg(sql)
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    };
+
+    load(&[]);
+    // Mark the existing row so a full reload would be detectable
+    query("UPDATE bills SET identifier = 'UNCHANGED'");
+
+    let new_bill = "country:us/state:wy/sessions/2025/bills/HB0002";
+    commit_files(&repo, &[(&format!("{}/metadata.json", new_bill), r#"{"identifier": "HB0002"}"#)], "new bill");
+    load(&["--since-commit", "HEAD~1"]);
+
+    assert_eq!(query("SELECT identifier FROM bills ORDER BY identifier"), "HB0002\nUNCHANGED");
+
+    // Deleted bills are removed and keys new to the table become columns
+    remove_files(&repo, &[&format!("{}/metadata.json", BILL_DIR)], "drop HB0001");
+    commit_files(
+        &repo,
+        &[(&format!("{}/metadata.json", new_bill), r#"{"identifier": "HB0002", "subtitle": "Amended"}"#)],
+        "new key",
+    );
+    load(&["--since-commit", "HEAD~2"]);
+
+    assert_eq!(query("SELECT identifier || ':' || subtitle FROM bills"), "HB0002:Amended");
+}
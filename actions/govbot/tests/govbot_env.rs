@@ -0,0 +1,87 @@
+use govbot::config::DEFAULT_JOBS;
+use govbot::{Config, GovbotEnv};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Environment variables are process-wide, so tests touching them run one at a time
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+fn with_env(vars: &[(&str, Option<&str>)], test: impl FnOnce()) {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    for name in ["GOVBOT_DIR", "GOVBOT_JOBS", "TOKEN"] {
+        std::env::remove_var(name);
+    }
+    for (name, value) in vars {
+        if let Some(value) = value {
+            std::env::set_var(name, value);
+        }
+    }
+    test();
+    for name in ["GOVBOT_DIR", "GOVBOT_JOBS", "TOKEN"] {
+        std::env::remove_var(name);
+    }
+}
+
+#[test]
+fn test_defaults_without_env() {
+    with_env(&[], || {
+        let env = GovbotEnv::from_env().unwrap();
+        assert_eq!(env.govbot_dir, std::env::current_dir().unwrap().join(".govbot"));
+        assert_eq!(env.repos_dir(), std::env::current_dir().unwrap().join(".govbot/repos"));
+        assert_eq!(env.jobs, DEFAULT_JOBS);
+        assert_eq!(env.token, None);
+    });
+}
+
+#[test]
+fn test_env_vars_are_read() {
+    with_env(
+        &[("GOVBOT_DIR", Some("/data/govbot")), ("GOVBOT_JOBS", Some("8")), ("TOKEN", Some("env-token"))],
+        || {
+            let env = GovbotEnv::from_env().unwrap();
+            assert_eq!(env.govbot_dir, PathBuf::from("/data/govbot"));
+            assert_eq!(env.jobs, 8);
+            assert_eq!(env.token.as_deref(), Some("env-token"));
+            assert_eq!(Config::from_env().unwrap().git_dir, PathBuf::from("/data/govbot/repos"));
+        },
+    );
+}
+
+#[test]
+fn test_flags_override_env_vars() {
+    with_env(
+        &[("GOVBOT_DIR", Some("/data/govbot")), ("GOVBOT_JOBS", Some("8")), ("TOKEN", Some("env-token"))],
+        || {
+            let env = GovbotEnv::resolve(Some("/flag/govbot"), Some(2), Some("flag-token")).unwrap();
+            assert_eq!(env.govbot_dir, PathBuf::from("/flag/govbot"));
+            assert_eq!(env.jobs, 2);
+            assert_eq!(env.token.as_deref(), Some("flag-token"));
+        },
+    );
+}
+
+#[test]
+fn test_empty_env_vars_count_as_unset() {
+    with_env(&[("GOVBOT_DIR", Some("")), ("GOVBOT_JOBS", Some("")), ("TOKEN", Some(""))], || {
+        let env = GovbotEnv::from_env().unwrap();
+        assert_eq!(env.govbot_dir, std::env::current_dir().unwrap().join(".govbot"));
+        assert_eq!(env.jobs, DEFAULT_JOBS);
+        assert_eq!(env.token, None);
+    });
+}
+
+#[test]
+fn test_invalid_jobs_are_rejected() {
+    with_env(&[("GOVBOT_JOBS", Some("many"))], || {
+        let err = GovbotEnv::from_env().unwrap_err();
+        assert!(err.to_string().contains("GOVBOT_JOBS"), "{}", err);
+        // A valid flag means the env var is never consulted
+        assert_eq!(GovbotEnv::resolve(None, Some(3), None).unwrap().jobs, 3);
+    });
+    with_env(&[("GOVBOT_JOBS", Some("0"))], || {
+        assert!(GovbotEnv::from_env().is_err());
+    });
+    with_env(&[], || {
+        assert!(GovbotEnv::resolve(None, Some(0), None).is_err());
+    });
+}
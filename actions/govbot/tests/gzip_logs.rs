@@ -0,0 +1,77 @@
+use flate2::write::GzEncoder;
+use govbot::prelude::*;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+const LOGS: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs";
+const LOG: &str = r#"{"action": {"description": "Introduced", "date": "2025-01-14"}, "bill_id": "HB0001"}"#;
+
+/// Write the fixture log into `repos`, gzipped as `.json.gz` when `gzip` is set
+fn write_log(repos: &Path, gzip: bool) {
+    let dir = repos.join(LOGS);
+    fs::create_dir_all(&dir).unwrap();
+    if gzip {
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(LOG.as_bytes()).unwrap();
+        fs::write(dir.join("20250114T000000Z_introduced.json.gz"), encoder.finish().unwrap()).unwrap();
+    } else {
+        fs::write(dir.join("20250114T000000Z_introduced.json"), LOG).unwrap();
+    }
+}
+
+async fn entries(config: Config) -> Vec<LogEntry> {
+    PipelineProcessor::new(config)
+        .process()
+        .map(|entry| entry.unwrap())
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn test_gzipped_log_is_processed_like_plain() {
+    for streaming_parse in [false, true] {
+        let plain = tempfile::tempdir().unwrap();
+        let gzipped = tempfile::tempdir().unwrap();
+        write_log(plain.path(), false);
+        write_log(gzipped.path(), true);
+
+        let config = |dir: &Path| ConfigBuilder::new(dir).streaming_parse(streaming_parse).build().unwrap();
+        let plain_entries = entries(config(plain.path())).await;
+        let gzipped_entries = entries(config(gzipped.path())).await;
+
+        assert_eq!(plain_entries.len(), 1);
+        assert_eq!(gzipped_entries.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&gzipped_entries[0].log).unwrap(),
+            serde_json::to_value(&plain_entries[0].log).unwrap()
+        );
+        assert_eq!(gzipped_entries[0].filename, format!("{}.gz", plain_entries[0].filename));
+    }
+}
+
+#[test]
+fn test_logs_command_reads_gzipped_logs() {
+    let logs = |gzip: bool| {
+        let dir = tempfile::tempdir().unwrap();
+        write_log(&dir.path().join("repos"), gzip);
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "wy", "--filter", "none", "--limit", "none"])
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines: Vec<serde_json::Value> = stdout.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 1);
+        lines[0].clone()
+    };
+
+    let plain = logs(false);
+    let gzipped = logs(true);
+    assert_eq!(gzipped["log"], plain["log"]);
+    assert_eq!(gzipped["timestamp"], plain["timestamp"]);
+    assert!(gzipped["sources"]["log"].as_str().unwrap().ends_with(".json.gz"));
+}
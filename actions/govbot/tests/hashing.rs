@@ -0,0 +1,31 @@
+use govbot::{hash_text, hash_text_fast};
+
+#[test]
+fn test_hash_text_fast_is_deterministic() {
+    let text = "An act relating to public school funding";
+    assert_eq!(hash_text_fast(text), hash_text_fast(text));
+    assert_eq!(hash_text_fast(""), hash_text_fast(""));
+}
+
+#[test]
+fn test_hash_text_fast_distinguishes_inputs() {
+    let texts = [
+        "",
+        "An act relating to public school funding",
+        "An act relating to public school funding.",
+        "an act relating to public school funding",
+        "HB 1",
+        "HB 11",
+    ];
+    let hashes: std::collections::HashSet<u64> = texts.iter().map(|t| hash_text_fast(t)).collect();
+    assert_eq!(hashes.len(), texts.len());
+}
+
+/// Tag files store SHA-256 keys, so the stable hash must stay unchanged
+#[test]
+fn test_hash_text_stays_sha256() {
+    assert_eq!(
+        hash_text("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
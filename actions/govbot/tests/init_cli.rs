@@ -0,0 +1,127 @@
+use govbot::embeddings::load_tags_config;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn run_init(dir: &Path, args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .arg("init")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn test_keyword_template_parses_with_keywords() {
+    let dir = tempfile::tempdir().unwrap();
+    run_init(dir.path(), &["--template", "keyword"]);
+
+    let config = fs::read_to_string(dir.path().join("govbot.yml")).unwrap();
+    assert!(config.contains("include_keywords"));
+    assert!(dir.path().join(".github/workflows/build.yml").exists());
+    assert_eq!(fs::read_to_string(dir.path().join(".gitignore")).unwrap(), ".govbot\n");
+
+    let tags = load_tags_config(dir.path().join("govbot.yml")).unwrap();
+    assert_eq!(tags.len(), 1);
+    assert!(!tags[0].include_keywords.is_empty());
+}
+
+#[test]
+fn test_scaffolded_tags() {
+    let dir = tempfile::tempdir().unwrap();
+    run_init(dir.path(), &["--template", "keyword", "--tags", "education, budget"]);
+
+    let config = fs::read_to_string(dir.path().join("govbot.yml")).unwrap();
+    assert!(config.contains("include_keywords: []"));
+
+    let mut names: Vec<String> = load_tags_config(dir.path().join("govbot.yml"))
+        .unwrap()
+        .into_iter()
+        .map(|t| t.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["budget", "education"]);
+}
+
+#[test]
+fn test_default_and_minimal_templates_parse() {
+    for template in ["default", "minimal"] {
+        let dir = tempfile::tempdir().unwrap();
+        run_init(dir.path(), &["--template", template]);
+        let tags = load_tags_config(dir.path().join("govbot.yml")).unwrap();
+        assert_eq!(tags[0].name, "education");
+    }
+}
+
+#[test]
+fn test_scaffolded_tag_names_are_quoted_as_yaml() {
+    let dir = tempfile::tempdir().unwrap();
+    run_init(dir.path(), &["--tags", "k-12: schools, #budget, 'quoted', yes"]);
+
+    let mut names: Vec<String> = load_tags_config(dir.path().join("govbot.yml"))
+        .unwrap()
+        .into_iter()
+        .map(|t| t.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["#budget", "'quoted'", "k-12: schools", "yes"]);
+}
+
+#[test]
+fn test_templates_and_feed_options_match_the_config_schema() {
+    let schema_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../schemas/govbot.schema.json");
+    let schema: serde_json::Value = serde_json::from_str(&fs::read_to_string(schema_path).unwrap()).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    let errors = |yaml: &str| -> Vec<String> {
+        let config: serde_json::Value = serde_yaml::from_str(yaml).unwrap();
+        validator.iter_errors(&config).map(|e| e.to_string()).collect()
+    };
+    let check = |yaml: &str| {
+        let errors = errors(yaml);
+        assert!(errors.is_empty(), "{:?}\n{}", errors, yaml);
+    };
+
+    for template in ["default", "keyword", "minimal"] {
+        let dir = tempfile::tempdir().unwrap();
+        run_init(dir.path(), &["--template", template]);
+        let mut config = fs::read_to_string(dir.path().join("govbot.yml")).unwrap();
+        check(&config);
+
+        if template == "default" {
+            config.push_str(
+                r#"  ttl: 60
+  skip_hours: [0, 23]
+  skip_days: [Saturday]
+  strip_html: false
+  strip_html_index: true
+  language: fr-ca
+  link_template: "https://example.com/{state}/{bill_id}"
+  category_domain: "https://example.com/tags"
+logs:
+  limit: none
+text_extraction:
+  include: [bill.classification]
+  exclude: [from_organization]
+"#,
+            );
+            check(&config);
+            let tagged = config.replacen("    description:", "    author: Curator\n    label: Schools\n    description:", 1);
+            assert_ne!(tagged, config);
+            check(&tagged);
+
+            // The options are typed, not just tolerated
+            for (valid, invalid) in [
+                ("ttl: 60", "ttl: soon"),
+                ("skip_hours: [0, 23]", "skip_hours: [24]"),
+                ("strip_html: false", "strip_html: sometimes"),
+                ("limit: none", "limit: 0"),
+                ("include: [bill.classification]", "include: bill.classification"),
+                ("author: Curator", "author: [Curator]"),
+            ] {
+                assert!(!errors(&tagged.replacen(valid, invalid, 1)).is_empty(), "{}", invalid);
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use govbot::selectors::extract_json_field;
+use serde_json::json;
+
+fn path(p: &str) -> Vec<String> {
+    p.split('.').map(str::to_string).collect()
+}
+
+fn bill() -> serde_json::Value {
+    json!({
+        "title": "An act relating to schools",
+        "sponsorships": [
+            { "name": "Smith", "primary": true, "person": { "party": "D" } },
+            { "name": "Jones", "primary": false },
+            { "name": "Lee", "primary": false, "person": { "party": "R" } }
+        ]
+    })
+}
+
+#[test]
+fn test_wildcard_maps_over_sponsorships() {
+    assert_eq!(
+        extract_json_field(&bill(), &path("sponsorships.*.name")),
+        Some(json!(["Smith", "Jones", "Lee"]))
+    );
+    // Indices and plain keys still work
+    assert_eq!(extract_json_field(&bill(), &path("sponsorships.1.name")), Some(json!("Jones")));
+    assert_eq!(extract_json_field(&bill(), &path("title")), Some(json!("An act relating to schools")));
+}
+
+#[test]
+fn test_wildcard_mid_path_followed_by_keys() {
+    // Sponsors without a `person` are skipped
+    assert_eq!(
+        extract_json_field(&bill(), &path("sponsorships.*.person.party")),
+        Some(json!(["D", "R"]))
+    );
+    assert_eq!(
+        extract_json_field(&bill(), &path("sponsorships.*.person")),
+        Some(json!([{ "party": "D" }, { "party": "R" }]))
+    );
+    // A wildcard on a non-array finds nothing
+    assert_eq!(extract_json_field(&bill(), &path("title.*")), None);
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::log_entry;
+use govbot::embeddings::{keyword_score, match_tags_keywords, match_tags_keywords_with_threshold, matches_keywords};
+use govbot::selectors::TextExtractionConfig;
+use govbot::TagDefinition;
+
+fn tag(name: &str, include: &[&str], exclude: &[&str], threshold: f32) -> TagDefinition {
+    TagDefinition {
+        name: name.to_string(),
+        description: String::new(),
+        examples: Vec::new(),
+        include_keywords: include.iter().map(|s| s.to_string()).collect(),
+        exclude_keywords: exclude.iter().map(|s| s.to_string()).collect(),
+        negative_examples: Vec::new(),
+        threshold,
+    }
+}
+
+#[test]
+fn test_keyword_score_scales_with_coverage() {
+    assert_eq!(keyword_score(0, 4), 0.0);
+    assert_eq!(keyword_score(1, 4), 0.75);
+    assert_eq!(keyword_score(4, 4), 1.0);
+    assert!(keyword_score(1, 100) >= 0.5);
+}
+
+#[test]
+fn test_keyword_hits_clear_or_fail_threshold() {
+    let keywords = ["budget", "appropriation", "revenue", "tax"];
+    let bill = log_entry("HB1", "General budget for the biennium", &[]);
+
+    // One of four keywords scores 0.75
+    let matches = match_tags_keywords(&[tag("budget", &keywords, &[], 0.7)], &bill);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].1.final_score, 0.75);
+    assert_eq!(matches[0].1.keyword_match, vec!["budget"]);
+
+    let matches = match_tags_keywords(&[tag("budget", &keywords, &[], 0.8)], &bill);
+    assert!(matches.is_empty());
+
+    // More coverage clears the higher threshold
+    let bill = log_entry("HB1", "Budget appropriation and tax revenue", &[]);
+    let matches = match_tags_keywords(&[tag("budget", &keywords, &[], 0.8)], &bill);
+    assert_eq!(matches[0].1.final_score, 1.0);
+}
+
+#[test]
+fn test_exclude_keyword_vetoes_match() {
+    let bill = log_entry("HB1", "School budget for the education department", &[]);
+    let tags = [tag("budget", &["budget"], &["school"], 0.5)];
+    assert!(match_tags_keywords(&tags, &bill).is_empty());
+}
+
+#[test]
+fn test_tag_threshold_drops_weak_matches() {
+    let keywords = ["budget", "appropriation", "revenue", "tax"];
+    let weak = log_entry("HB1", "General budget for the biennium", &[]);
+    let strong = log_entry("HB1", "Budget appropriation and tax revenue", &[]);
+
+    // A 0.1 threshold keeps a single-keyword hit
+    assert_eq!(match_tags_keywords(&[tag("budget", &keywords, &[], 0.1)], &weak).len(), 1);
+
+    // A higher one (as `tag --threshold` sets) needs more of the keywords
+    let strict = [tag("budget", &keywords, &[], 0.8)];
+    assert!(match_tags_keywords(&strict, &weak).is_empty());
+    assert_eq!(match_tags_keywords(&strict, &strong).len(), 1);
+}
+
+#[test]
+fn test_score_floor_drops_matches_the_tag_threshold_keeps() {
+    let keywords = ["budget", "appropriation", "revenue", "tax"];
+    let weak = log_entry("HB1", "General budget for the biennium", &[]);
+    let strong = log_entry("HB1", "Budget appropriation and tax revenue", &[]);
+    let tags = [tag("budget", &keywords, &[], 0.1)];
+    let extraction = TextExtractionConfig::default();
+
+    assert_eq!(match_tags_keywords_with_threshold(&tags, &weak, &extraction, 0.0).len(), 1);
+    assert!(match_tags_keywords_with_threshold(&tags, &weak, &extraction, 0.8).is_empty());
+    assert_eq!(match_tags_keywords_with_threshold(&tags, &strong, &extraction, 0.8).len(), 1);
+
+    // A floor below the tag's threshold doesn't loosen it
+    let strict = [tag("budget", &keywords, &[], 0.8)];
+    assert!(match_tags_keywords_with_threshold(&strict, &weak, &extraction, 0.1).is_empty());
+}
+
+#[test]
+fn test_matches_keywords_respects_word_boundaries() {
+    let keywords = vec!["procedural".to_string(), "rules change".to_string()];
+    assert!(matches_keywords("Procedural motion to adjourn", &keywords));
+    assert!(matches_keywords("Adopts a RULES CHANGE for the session", &keywords));
+    assert!(!matches_keywords("Funds proceduralism research", &keywords));
+    assert!(!matches_keywords("Procedural motion", &[]));
+}
@@ -0,0 +1,62 @@
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn write_tag_file(root: &Path, session: &str, tag: &str, bills: &[&str]) {
+    let tags_dir = root.join(format!("country:us/state:wy/sessions/{}/tags", session));
+    fs::create_dir_all(&tags_dir).unwrap();
+    let bills: serde_json::Map<String, Value> = bills
+        .iter()
+        .map(|bill| {
+            let result = json!({
+                "text_hash": format!("hash-{}", bill),
+                "score": { "final_score": 0.9, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+            });
+            (bill.to_string(), result)
+        })
+        .collect();
+    let tag_file = json!({
+        "metadata": { "version": 1, "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "x" },
+        "tag_config": { "name": tag },
+        "bills": bills,
+    });
+    fs::write(tags_dir.join(format!("{}.tag.json", tag)), tag_file.to_string()).unwrap();
+}
+
+#[test]
+fn test_list_tags_counts_sessions_and_bills() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("govbot.yml"),
+        "tags:\n  budget:\n    description: Budget\n    threshold: 0.6\n  water:\n    description: Water\n  housing:\n    description: Housing\n",
+    )
+    .unwrap();
+    write_tag_file(dir.path(), "2024", "budget", &["HB1", "HB2"]);
+    write_tag_file(dir.path(), "2025", "budget", &["HB7"]);
+    // A tag file exists but nothing matched
+    write_tag_file(dir.path(), "2025", "water", &[]);
+    // Left over from a tag that was removed from govbot.yml
+    write_tag_file(dir.path(), "2025", "zoning", &["SF3"]);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["--no-emoji", "list-tags"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let row = |tag: &str| -> Vec<String> {
+        let line = stdout.lines().find(|l| l.starts_with(&format!("{} ", tag))).unwrap_or_else(|| panic!("{}", stdout));
+        line.split_whitespace().map(String::from).collect()
+    };
+
+    assert!(stdout.lines().next().unwrap().starts_with("TAG"));
+    assert_eq!(row("budget"), vec!["budget", "0.60", "2", "3"]);
+    assert_eq!(row("water")[1..4], ["0.50", "1", "0"]);
+    assert!(row("water").join(" ").ends_with("no matches"));
+    assert_eq!(row("housing")[1..4], ["0.50", "0", "0"]);
+    assert!(row("housing").join(" ").ends_with("no matches"));
+    assert_eq!(row("zoning")[1..4], ["-", "1", "1"]);
+    assert!(row("zoning").join(" ").ends_with("not in govbot.yml"));
+}
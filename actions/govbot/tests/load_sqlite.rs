@@ -0,0 +1,113 @@
+use rusqlite::Connection;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn write_bill(repo: &Path, id: &str, metadata: &str) {
+    let dir = repo.join("country:us/state:wy/sessions/2025/bills").join(id);
+    fs::create_dir_all(dir.join("logs")).unwrap();
+    fs::write(dir.join("metadata.json"), metadata).unwrap();
+    fs::write(dir.join("logs/20250101T000000Z_introduced.json"), r#"{"action": {}}"#).unwrap();
+}
+
+fn load(govbot_dir: &Path, args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["load", "--format", "sqlite"])
+        .args(args)
+        .env("GOVBOT_DIR", govbot_dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn test_load_sqlite_creates_bills_table_and_summary() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let repo = govbot_dir.path().join("repos/wy-legislation");
+    write_bill(
+        &repo,
+        "HB0001",
+        r#"{"identifier": "HB0001", "title": "School funding", "jurisdiction": {"id": "ocd-jurisdiction/country:us/state:wy", "name": "Wyoming"}, "actions": [{"description": "Introduced"}, {"description": "Passed"}]}"#,
+    );
+    write_bill(&repo, "SF0002", r#"{"identifier": "SF0002", "title": "Water rights", "session_year": 2025}"#);
+
+    load(govbot_dir.path(), &[]);
+
+    let conn = Connection::open(govbot_dir.path().join("govbot.sqlite")).unwrap();
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM bills", [], |r| r.get(0)).unwrap();
+    assert_eq!(count, 2);
+
+    let name: String = conn
+        .query_row(
+            "SELECT json_extract(jurisdiction, '$.name') FROM bills WHERE identifier = 'HB0001'",
+            [],
+            |r| r.get(0),
+        )
+        .unwrap();
+    assert_eq!(name, "Wyoming");
+
+    // Keys from any file become columns; scalars keep their type
+    let year: i64 = conn
+        .query_row("SELECT session_year FROM bills WHERE identifier = 'SF0002'", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(year, 2025);
+
+    let (actions, jurisdiction): (i64, Option<String>) = conn
+        .query_row(
+            "SELECT action_count, jurisdiction_name FROM bills_summary WHERE identifier = 'HB0001'",
+            [],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!((actions, jurisdiction.as_deref()), (2, Some("Wyoming")));
+
+    // A second full load replaces the database instead of appending
+    load(govbot_dir.path(), &[]);
+    let conn = Connection::open(govbot_dir.path().join("govbot.sqlite")).unwrap();
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM bills", [], |r| r.get(0)).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_load_sqlite_merges_keys_differing_only_in_case() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let repo = govbot_dir.path().join("repos/wy-legislation");
+    write_bill(&repo, "HB0001", r#"{"identifier": "HB0001", "Title": "School funding", "Subject": "Education"}"#);
+    write_bill(&repo, "SF0002", r#"{"identifier": "SF0002", "SUBJECT": "Water", "subject": "Ignored"}"#);
+
+    load(govbot_dir.path(), &[]);
+
+    // Keys are read in sorted order, so `SUBJECT` is the first of its pair
+    let conn = Connection::open(govbot_dir.path().join("govbot.sqlite")).unwrap();
+    let subjects: Vec<(String, String)> = conn
+        .prepare("SELECT identifier, subject FROM bills ORDER BY identifier")
+        .unwrap()
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        subjects,
+        [
+            ("HB0001".to_string(), "Education".to_string()),
+            ("SF0002".to_string(), "Water".to_string())
+        ]
+    );
+    let title: String = conn
+        .query_row("SELECT title FROM bills WHERE identifier = 'HB0001'", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(title, "School funding");
+}
+
+#[test]
+fn test_load_without_cloned_repos_reports_the_doctor_check() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["load", "--format", "sqlite"])
+        .env("GOVBOT_DIR", govbot_dir.path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not found (run `govbot clone` to create it)"), "{}", stderr);
+    assert!(!govbot_dir.path().join("govbot.sqlite").exists());
+}
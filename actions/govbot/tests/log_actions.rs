@@ -0,0 +1,67 @@
+use govbot::prelude::*;
+use serde_json::json;
+
+fn full(log: serde_json::Value) -> LogContent {
+    LogContent::Full(log)
+}
+
+fn introduced() -> LogContent {
+    full(json!({
+        "bill_id": "HB0001",
+        "action": {
+            "description": "Introduced and referred",
+            "date": "2025-01-14",
+            "classification": ["introduction", "referral-committee"]
+        }
+    }))
+}
+
+#[test]
+fn test_action_description() {
+    assert_eq!(introduced().action_description(), Some("Introduced and referred"));
+    // Some scrapers store the action as a bare string
+    assert_eq!(full(json!({"action": "Signed by governor"})).action_description(), Some("Signed by governor"));
+    assert_eq!(full(json!({"bill_id": "HB0001"})).action_description(), None);
+    assert_eq!(full(json!({"action": {"date": "2025-01-14"}})).action_description(), None);
+}
+
+#[test]
+fn test_action_date() {
+    assert_eq!(introduced().action_date(), Some("2025-01-14"));
+    assert_eq!(full(json!({"action": "Signed by governor"})).action_date(), None);
+    assert_eq!(full(json!({"bill_id": "HB0001"})).action_date(), None);
+}
+
+#[test]
+fn test_action_classification() {
+    assert_eq!(introduced().action_classification(), vec!["introduction", "referral-committee"]);
+    assert_eq!(
+        full(json!({"action": {"classification": "passage"}})).action_classification(),
+        vec!["passage"]
+    );
+    assert!(full(json!({"action": {"description": "Read"}})).action_classification().is_empty());
+    assert!(full(json!({"bill_id": "HB0001"})).action_classification().is_empty());
+}
+
+#[test]
+fn test_vote_events_have_no_action() {
+    let vote = LogContent::VoteEvent {
+        result: VoteEventResult::Pass,
+        detail: json!({"action": {"description": "not a log action"}}),
+    };
+    assert_eq!(vote.action_description(), None);
+    assert_eq!(vote.action_date(), None);
+    assert!(vote.action_classification().is_empty());
+}
+
+#[test]
+fn test_log_entry_delegates_to_content() {
+    let entry = LogEntry {
+        log: introduced(),
+        filename: "wy/logs/20250114T000000Z_introduced.json".to_string(),
+        metadata: None,
+    };
+    assert_eq!(entry.action_description(), Some("Introduced and referred"));
+    assert_eq!(entry.action_date(), Some("2025-01-14"));
+    assert_eq!(entry.action_classification().len(), 2);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::write_log;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const BILLS: &str = "repos/wy-legislation/country:us/state:wy/sessions/2025/bills";
+
+/// HB0001 is a valid log, HB0002 isn't UTF-8 (so it can't be read), HB0003 isn't JSON
+fn write_fixture(govbot_dir: &Path) {
+    let files: [(&str, &[u8]); 3] = [
+        ("HB0001", br#"{"action": {"description": "Introduced"}, "bill_id": "HB0001"}"#),
+        ("HB0002", &[0xff, 0xfe, 0x00, 0x7b]),
+        ("HB0003", b"{\"action\": "),
+    ];
+    for (bill, contents) in files {
+        write_log(&govbot_dir.join(BILLS).join(bill), contents);
+    }
+}
+
+fn logs(govbot_dir: &Path, args: &[&str]) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy", "--filter", "none", "--limit", "none"])
+        .args(args)
+        .env("GOVBOT_DIR", govbot_dir)
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+#[test]
+fn test_errors_are_summarized_and_logged() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path());
+    let error_log = dir.path().join("errors.json");
+
+    let output = logs(dir.path(), &["--error-log", error_log.to_str().unwrap()]);
+
+    // Stdout only carries the one good entry
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<Value> = stdout.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["id"], "HB0001");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Errors: 1 file: read error; 1 file: JSON parse error; 1 file: metadata missing"),
+        "{}",
+        stderr
+    );
+    assert!(!stderr.contains("Error parsing JSON from"), "{}", stderr);
+
+    let errors: Vec<Value> = serde_json::from_str(&fs::read_to_string(&error_log).unwrap()).unwrap();
+    let mut kinds: Vec<(&str, &str)> = errors
+        .iter()
+        .map(|e| (e["kind"].as_str().unwrap(), e["path"].as_str().unwrap()))
+        .collect();
+    kinds.sort();
+    assert_eq!(kinds.len(), 3);
+    assert_eq!(kinds[0].0, "metadata_missing");
+    assert!(kinds[0].1.ends_with("bills/HB0001/metadata.json"), "{:?}", kinds);
+    assert_eq!(kinds[1].0, "parse");
+    assert!(kinds[1].1.contains("HB0003"), "{:?}", kinds);
+    assert_eq!(kinds[2].0, "read");
+    assert!(kinds[2].1.contains("HB0002"), "{:?}", kinds);
+    assert!(errors.iter().all(|e| !e["message"].as_str().unwrap().is_empty()));
+}
+
+#[test]
+fn test_clean_run_writes_an_empty_error_log() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path());
+    fs::remove_dir_all(dir.path().join(BILLS).join("HB0002")).unwrap();
+    fs::remove_dir_all(dir.path().join(BILLS).join("HB0003")).unwrap();
+    fs::write(dir.path().join(BILLS).join("HB0001/metadata.json"), r#"{"title": "Budget"}"#).unwrap();
+    let error_log = dir.path().join("errors.json");
+
+    let output = logs(dir.path(), &["--error-log", error_log.to_str().unwrap()]);
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("Errors:"));
+    assert_eq!(fs::read_to_string(&error_log).unwrap(), "[]");
+}
@@ -0,0 +1,353 @@
+mod common;
+
+use common::write_log;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// One log file per (state, session) under a single repo
+fn write_fixture(govbot_dir: &Path, logs: &[(&str, &str, &str)]) {
+    for (state, session, bill) in logs {
+        write_log(
+            &govbot_dir.join(format!("repos/wy-legislation/country:us/state:{}/sessions/{}/bills/{}", state, session, bill)),
+            format!(r#"{{"action": {{"description": "Introduced"}}, "bill_id": "{}"}}"#, bill),
+        );
+    }
+}
+
+fn logs(govbot_dir: &Path, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy", "--filter", "none", "--limit", "none"])
+        .args(args)
+        .env("GOVBOT_DIR", govbot_dir)
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let mut ids: Vec<String> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap()["id"].as_str().unwrap().to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_session_filter() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "104th", "HB0001"), ("wy", "104th", "HB0002"), ("wy", "105th", "HB0003")]);
+
+    assert_eq!(logs(dir.path(), &[]), vec!["HB0001", "HB0002", "HB0003"]);
+    assert_eq!(logs(dir.path(), &["--session", "104th"]), vec!["HB0001", "HB0002"]);
+    assert_eq!(logs(dir.path(), &["--session", "105th, 104th"]), vec!["HB0001", "HB0002", "HB0003"]);
+    assert!(logs(dir.path(), &["--session", "106th"]).is_empty());
+}
+
+#[test]
+fn test_state_and_country_filters() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "104th", "HB0001"), ("gu", "104th", "B0002"), ("gu", "105th", "B0003")]);
+
+    assert_eq!(logs(dir.path(), &["--state", "gu"]), vec!["B0002", "B0003"]);
+    assert_eq!(logs(dir.path(), &["--state", "wy,gu"]).len(), 3);
+    assert_eq!(logs(dir.path(), &["--country", "us"]).len(), 3);
+    assert!(logs(dir.path(), &["--country", "ca"]).is_empty());
+
+    // Composes with --session
+    assert_eq!(logs(dir.path(), &["--state", "gu", "--session", "104th"]), vec!["B0002"]);
+}
+
+#[test]
+fn test_summary_and_exit_code_with_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "104th", "HB0001")]);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy", "--filter", "none", "--fail-on-empty"])
+        .env("GOVBOT_DIR", dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("1 repos processed, 1 entries emitted, 0 skipped by filter, 0 parse errors"), "{}", stderr);
+}
+
+#[test]
+fn test_fail_on_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("repos")).unwrap();
+
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .arg("logs")
+            .args(args)
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap()
+    };
+
+    // Lenient by default
+    assert!(run(&[]).status.success());
+
+    let output = run(&["--fail-on-empty"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("0 entries emitted"));
+}
+
+#[test]
+fn test_symlinked_session_is_emitted_once() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001"), ("wy", "2025", "HB0002")]);
+    let sessions = dir.path().join("repos/wy-legislation/country:us/state:wy/sessions");
+
+    // An alias of a session already in the repo, plus a session that only exists via a link
+    std::os::unix::fs::symlink(sessions.join("2025"), sessions.join("current")).unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    write_fixture(outside.path(), &[("wy", "2026", "HB0003")]);
+    std::os::unix::fs::symlink(
+        outside.path().join("repos/wy-legislation/country:us/state:wy/sessions/2026"),
+        sessions.join("2026"),
+    )
+    .unwrap();
+
+    assert_eq!(logs(dir.path(), &[]), vec!["HB0001", "HB0002"]);
+    assert_eq!(logs(dir.path(), &["--follow-symlinks"]), vec!["HB0001", "HB0002", "HB0003"]);
+}
+
+#[test]
+fn test_max_file_size_skips_oversized_logs() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001"), ("wy", "2025", "HB0002")]);
+    let huge = format!(r#"{{"bill_id": "HB0002", "html": "{}"}}"#, "x".repeat(64 * 1024));
+    fs::write(
+        dir.path().join("repos/wy-legislation/country:us/state:wy/sessions/2025/bills/HB0002/logs/20250101T000000Z_introduced.json"),
+        huge,
+    )
+    .unwrap();
+
+    assert_eq!(logs(dir.path(), &[]), vec!["HB0001", "HB0002"]);
+    assert_eq!(logs(dir.path(), &["--max-file-size", "4096"]), vec!["HB0001"]);
+}
+
+#[test]
+fn test_repos_from_config_limits_default_repo_set() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001")]);
+    let il_logs = dir.path().join("repos/il-legislation/country:us/state:il/sessions/104th/bills/HB0002/logs");
+    fs::create_dir_all(&il_logs).unwrap();
+    fs::write(
+        il_logs.join("20250101T000000Z_introduced.json"),
+        r#"{"action": {"description": "Introduced"}, "bill_id": "HB0002"}"#,
+    )
+    .unwrap();
+    let config = dir.path().join("govbot.yml");
+    fs::write(&config, "repos:\n  - il\n").unwrap();
+
+    let run = |args: &[&str]| -> Vec<String> {
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--filter", "none", "--limit", "none", "--config"])
+            .arg(&config)
+            .args(args)
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let mut ids: Vec<String> = String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap()["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(run(&[]), vec!["HB0001", "HB0002"]);
+    assert_eq!(run(&["--repos-from-config"]), vec!["HB0002"]);
+    // An explicit --repos still wins
+    assert_eq!(run(&["--repos-from-config", "--repos", "wy"]), vec!["HB0001"]);
+}
+
+#[test]
+fn test_joined_tags_are_byte_identical_across_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001")]);
+    let config = dir.path().join("govbot.yml");
+    fs::write(&config, "repos:\n  - wy\n").unwrap();
+
+    // Tag files written out of name order, so directory order isn't alphabetical
+    let tags_dir = dir.path().join("country:us/state:wy/sessions/2025/tags");
+    fs::create_dir_all(&tags_dir).unwrap();
+    for (tag, score) in [("zoning", 0.9), ("budget", 0.7), ("health", 0.8)] {
+        let tag_file = serde_json::json!({
+            "metadata": { "version": 1, "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "x" },
+            "tag_config": { "name": tag },
+            "bills": {
+                "HB0001": {
+                    "text_hash": "abc",
+                    "score": { "final_score": score, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+                }
+            }
+        });
+        fs::write(tags_dir.join(format!("{}.tag.json", tag)), tag_file.to_string()).unwrap();
+    }
+
+    // The raw `"tags":{...}` text of the first output line
+    let run = || -> String {
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "wy", "--filter", "none", "--join", "tags", "--config"])
+            .arg(&config)
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let line = String::from_utf8(output.stdout).unwrap().lines().next().unwrap().to_string();
+        let start = line.find("\"tags\":{").unwrap_or_else(|| panic!("no tags in {}", line));
+        let mut depth = 0;
+        let end = line[start..]
+            .char_indices()
+            .find_map(|(i, c)| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(start + i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                None
+            })
+            .unwrap();
+        line[start..end].to_string()
+    };
+
+    let first = run();
+    assert_eq!(first.as_bytes(), run().as_bytes());
+    let positions: Vec<usize> = ["\"budget\"", "\"health\"", "\"zoning\""]
+        .iter()
+        .map(|tag| first.find(tag).unwrap_or_else(|| panic!("{} missing from {}", tag, first)))
+        .collect();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]), "{}", first);
+}
+
+#[test]
+fn test_tag_json_wins_over_plain_json_for_the_same_tag() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001")]);
+    let config = dir.path().join("govbot.yml");
+    fs::write(&config, "repos:\n  - wy\n").unwrap();
+
+    let tags_dir = dir.path().join("country:us/state:wy/sessions/2025/tags");
+    fs::create_dir_all(&tags_dir).unwrap();
+    for (file, score) in [("budget.tag.json", 0.9), ("budget.json", 0.3)] {
+        let tag_file = serde_json::json!({
+            "metadata": { "version": 1, "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "x" },
+            "tag_config": { "name": "budget" },
+            "bills": {
+                "HB0001": {
+                    "text_hash": "abc",
+                    "score": { "final_score": score, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+                }
+            }
+        });
+        fs::write(tags_dir.join(file), tag_file.to_string()).unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy", "--filter", "none", "--join", "tags", "--config"])
+        .arg(&config)
+        .env("GOVBOT_DIR", dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let entry: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+    assert_eq!(entry["tags"]["budget"]["final_score"], 0.9);
+}
+
+#[test]
+fn test_empty_sessions_reported_only_with_flag() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001")]);
+    // A session that was scraped but has no logs yet
+    fs::create_dir_all(dir.path().join("repos/wy-legislation/country:us/state:wy/sessions/2024/bills/HB0009")).unwrap();
+
+    let run = |args: &[&str]| -> Vec<Value> {
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "wy", "--filter", "none", "--limit", "none"])
+            .args(args)
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    };
+
+    let without = run(&[]);
+    assert_eq!(without.len(), 1);
+    assert!(without.iter().all(|e| e.get("empty_session").is_none()));
+
+    let with = run(&["--include-empty-sessions"]);
+    assert_eq!(with.len(), 2);
+    let markers: Vec<&Value> = with.iter().filter(|e| e.get("empty_session").is_some()).collect();
+    assert_eq!(markers.len(), 1);
+    assert_eq!(markers[0]["empty_session"]["session"], "2024");
+    assert_eq!(markers[0]["sources"]["session"], "wy-legislation/country:us/state:wy/sessions/2024");
+
+    // Path filters apply to markers too
+    assert_eq!(run(&["--include-empty-sessions", "--session", "2025"]).len(), 1);
+}
+
+#[test]
+fn test_tags_dir_points_the_join_at_a_separate_tree() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), &[("wy", "2025", "HB0001")]);
+    let config = dir.path().join("govbot.yml");
+    fs::write(&config, "repos:\n  - wy\n").unwrap();
+
+    // Tags published in their own checkout, away from govbot.yml and the logs repos
+    let tags_repo = tempfile::tempdir().unwrap();
+    let tags_dir = tags_repo.path().join("country:us/state:wy/sessions/2025/tags");
+    fs::create_dir_all(&tags_dir).unwrap();
+    let tag_file = serde_json::json!({
+        "metadata": { "version": 1, "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "x" },
+        "tag_config": { "name": "budget" },
+        "bills": {
+            "HB0001": {
+                "text_hash": "abc",
+                "score": { "final_score": 0.8, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+            }
+        }
+    });
+    fs::write(tags_dir.join("budget.tag.json"), tag_file.to_string()).unwrap();
+
+    let run = |extra: &[&str]| -> Value {
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "wy", "--filter", "none", "--join", "tags", "--config"])
+            .arg(&config)
+            .args(extra)
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        serde_json::from_str(String::from_utf8(output.stdout).unwrap().lines().next().unwrap()).unwrap()
+    };
+
+    assert!(run(&[]).get("tags").is_none());
+    let entry = run(&["--tags-dir", tags_repo.path().to_str().unwrap()]);
+    assert_eq!(entry["tags"]["budget"]["final_score"], 0.8);
+}
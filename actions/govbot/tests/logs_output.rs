@@ -0,0 +1,432 @@
+use flate2::read::GzDecoder;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn govbot() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_govbot"));
+    cmd.current_dir(manifest_dir())
+        .env("GOVBOT_DIR", "mocks/.govbot")
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE");
+    cmd
+}
+
+#[test]
+fn test_gz_output_matches_stdout() {
+    let plain = govbot().args(["logs", "--limit", "none"]).output().unwrap();
+    assert!(plain.status.success());
+    let plain = String::from_utf8(plain.stdout).unwrap();
+    assert!(!plain.is_empty(), "mock repos should produce log lines");
+
+    let dir = tempfile::tempdir().unwrap();
+    let gz_path = dir.path().join("logs.ndjson.gz");
+    let status = govbot()
+        .args(["logs", "--limit", "none", "--output"])
+        .arg(&gz_path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let mut decompressed = String::new();
+    GzDecoder::new(fs::File::open(&gz_path).unwrap())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+
+    assert_eq!(
+        decompressed.lines().collect::<Vec<_>>(),
+        plain.lines().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_compress_flag_gzips_stdout() {
+    let output = govbot().args(["logs", "--limit", "1", "--compress"]).output().unwrap();
+    assert!(output.status.success());
+    // Gzip magic bytes
+    assert_eq!(&output.stdout[..2], &[0x1f, 0x8b]);
+}
+
+#[test]
+fn test_pretty_output_is_indented() {
+    let default = govbot().args(["logs", "--limit", "2"]).output().unwrap();
+    let default = String::from_utf8(default.stdout).unwrap();
+    let lines: Vec<&str> = default.lines().collect();
+    assert!(lines.len() > 1);
+    for line in &lines {
+        // Each line is one complete entry
+        serde_json::from_str::<serde_json::Value>(line).unwrap();
+    }
+
+    let pretty = govbot().args(["logs", "--limit", "2", "--pretty"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&pretty.stderr).contains("--pretty"));
+    let pretty = String::from_utf8(pretty.stdout).unwrap();
+    assert!(pretty.contains("\n  \""));
+    assert!(pretty.lines().count() > lines.len());
+    assert!(serde_json::from_str::<serde_json::Value>(pretty.lines().next().unwrap()).is_err());
+}
+
+/// Run `logs` from a project directory holding `govbot_yml`, against the mock repos
+fn logs_in_project(govbot_yml: &str, args: &[&str]) -> usize {
+    let project = tempfile::tempdir().unwrap();
+    fs::write(project.path().join("govbot.yml"), govbot_yml).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .current_dir(project.path())
+        .env("GOVBOT_DIR", manifest_dir().join("mocks/.govbot"))
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .args(["logs", "--repos", "wy", "--filter", "none"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap().lines().count()
+}
+
+#[test]
+fn test_config_logs_limit_is_default() {
+    assert_eq!(logs_in_project("logs:\n  limit: 5\n", &[]), 5);
+}
+
+#[test]
+fn test_cli_limit_overrides_config() {
+    assert_eq!(logs_in_project("logs:\n  limit: 5\n", &["--limit", "2"]), 2);
+    assert!(logs_in_project("logs:\n  limit: none\n", &[]) > 5);
+}
+
+fn logs_with_empty_field(args: &[&str]) -> serde_json::Value {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let logs_dir = govbot_dir
+        .path()
+        .join("repos/wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs");
+    fs::create_dir_all(&logs_dir).unwrap();
+    fs::write(
+        logs_dir.join("20250101T000000Z_introduced.json"),
+        r#"{"action": {"description": "Introduced", "note": ""}, "bill_id": "HB0001"}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy", "--filter", "none"])
+        .args(args)
+        .env("GOVBOT_DIR", govbot_dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_empty_fields_pruned_by_default() {
+    let entry = logs_with_empty_field(&[]);
+    assert_eq!(entry["log"]["action"]["description"], "Introduced");
+    assert!(entry["log"]["action"].get("note").is_none());
+}
+
+#[test]
+fn test_no_prune_keeps_empty_fields() {
+    let entry = logs_with_empty_field(&["--no-prune"]);
+    assert_eq!(entry["log"]["action"]["note"], "");
+}
+
+#[test]
+fn test_every_record_is_newline_terminated() {
+    let output = govbot().args(["logs", "--limit", "none"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = output.stdout;
+    assert!(stdout.ends_with(b"\n"));
+    assert!(!stdout.ends_with(b"\n\n"), "stream should end in a single newline");
+
+    let text = String::from_utf8(stdout.clone()).unwrap();
+    let records: Vec<&str> = text.split_terminator('\n').collect();
+    assert!(records.len() > 1);
+    for record in &records {
+        assert!(!record.is_empty());
+        serde_json::from_str::<serde_json::Value>(record).unwrap();
+    }
+    assert_eq!(text.matches('\n').count(), records.len());
+
+    let ndjson = govbot().args(["logs", "--limit", "none", "--format", "ndjson"]).output().unwrap();
+    assert!(ndjson.status.success());
+    assert_eq!(ndjson.stdout, stdout);
+}
+
+#[test]
+fn test_count_only_matches_line_count() {
+    let full = govbot().args(["logs", "--limit", "none"]).output().unwrap();
+    assert!(full.status.success());
+    let lines = String::from_utf8(full.stdout).unwrap().lines().count();
+    assert!(lines > 1);
+
+    let counted = govbot().args(["logs", "--limit", "none", "--count-only"]).output().unwrap();
+    assert!(counted.status.success());
+    let counted = String::from_utf8(counted.stdout).unwrap();
+    let rows: Vec<(&str, usize)> = counted
+        .lines()
+        .map(|line| {
+            let (name, count) = line.split_once('\t').unwrap();
+            (name, count.parse().unwrap())
+        })
+        .collect();
+
+    let (label, total) = *rows.last().unwrap();
+    assert_eq!(label, "total");
+    assert_eq!(total, lines);
+    assert_eq!(rows[..rows.len() - 1].iter().map(|(_, n)| n).sum::<usize>(), lines);
+}
+
+#[test]
+fn test_yaml_format_round_trips_to_json_output() {
+    let json = govbot().args(["logs", "--limit", "1"]).output().unwrap();
+    assert!(json.status.success());
+    let json: Vec<serde_json::Value> = String::from_utf8(json.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert!(!json.is_empty());
+
+    let yaml = govbot().args(["logs", "--limit", "1", "--format", "yaml"]).output().unwrap();
+    assert!(yaml.status.success());
+    let yaml = String::from_utf8(yaml.stdout).unwrap();
+    assert!(yaml.starts_with("---\n"));
+    let documents: Vec<serde_json::Value> = serde_yaml::Deserializer::from_str(&yaml)
+        .map(|doc| serde::Deserialize::deserialize(doc).unwrap())
+        .collect();
+
+    assert_eq!(documents, json);
+}
+
+#[test]
+fn test_yaml_format_rejects_pretty() {
+    let output = govbot().args(["logs", "--format", "yaml", "--pretty"]).output().unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_table_format_has_header_and_row_per_entry() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let long_title = format!("An act relating to {}", "water rights and irrigation districts ".repeat(4));
+    for (id, title) in [("HB0001", "Short title"), ("HB0002", long_title.as_str())] {
+        let bill_dir = govbot_dir
+            .path()
+            .join("repos/wy-legislation/country:us/state:wy/sessions/2025/bills")
+            .join(id);
+        fs::create_dir_all(bill_dir.join("logs")).unwrap();
+        fs::write(bill_dir.join("metadata.json"), serde_json::json!({ "identifier": id, "title": title }).to_string()).unwrap();
+        fs::write(
+            bill_dir.join("logs/20250101T000000Z_introduced.json"),
+            format!(r#"{{"action": {{"description": "Introduced"}}, "bill_id": "{}"}}"#, id),
+        )
+        .unwrap();
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy", "--filter", "none", "--format", "table", "--sort", "ASC"])
+        .env("GOVBOT_DIR", govbot_dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let table = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = table.lines().collect();
+
+    assert_eq!(lines.len(), 4, "{}", table);
+    assert!(lines[0].starts_with("ID") && lines[0].contains("TITLE") && lines[0].contains("TAGS") && lines[0].contains("TIMESTAMP"));
+    assert!(lines[1].starts_with("---"));
+
+    let short = lines.iter().find(|l| l.starts_with("HB0001")).unwrap();
+    assert!(short.contains("Short title") && short.contains("20250101T000000Z"));
+    let long = lines.iter().find(|l| l.starts_with("HB0002")).unwrap();
+    assert!(long.contains("An act relating to water rights") && long.contains("..."), "{}", long);
+    assert!(!long.contains(long_title.trim_end()));
+
+    // Timestamps line up in one column
+    let column = lines[0].find("TIMESTAMP").unwrap();
+    assert_eq!(short.find("20250101T000000Z"), Some(column));
+    assert_eq!(long.find("20250101T000000Z"), Some(column));
+}
+
+#[test]
+fn test_concurrent_file_output_matches_serial() {
+    let dir = tempfile::tempdir().unwrap();
+    let run = |parallel: &str, name: &str| {
+        let path = dir.path().join(name);
+        let output = govbot()
+            .args(["logs", "--repos", "all", "--limit", "none", "--parallel", parallel, "--output"])
+            .arg(&path)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        fs::read_to_string(&path).unwrap()
+    };
+
+    let serial = run("1", "serial.ndjson");
+    let concurrent = run("4", "concurrent.ndjson");
+    assert!(serial.lines().count() > 1, "mock repos should produce several log lines");
+
+    // Same entries, merged back in repo order
+    assert_eq!(concurrent, serial);
+}
+
+#[test]
+fn test_geojson_places_entries_at_their_jurisdiction() {
+    let dir = tempfile::tempdir().unwrap();
+    for (repo, state, id, title) in [
+        ("wy-legislation", "wy", "HB0001", "Water rights"),
+        ("wy-legislation", "wy", "HB0002", "School funding"),
+        ("gu-legislation", "gu", "B0001", "No geometry for this one"),
+    ] {
+        let bill_dir = dir
+            .path()
+            .join("repos")
+            .join(repo)
+            .join(format!("country:us/state:{}/sessions/2025/bills", state))
+            .join(id);
+        fs::create_dir_all(bill_dir.join("logs")).unwrap();
+        fs::write(bill_dir.join("metadata.json"), serde_json::json!({ "identifier": id, "title": title }).to_string()).unwrap();
+        fs::write(
+            bill_dir.join("logs/20250101T000000Z_introduced.json"),
+            serde_json::json!({ "action": { "description": "Introduced" }, "bill_id": id }).to_string(),
+        )
+        .unwrap();
+    }
+
+    let tags_dir = dir.path().join("country:us/state:wy/sessions/2025/tags");
+    fs::create_dir_all(&tags_dir).unwrap();
+    let tag_file = serde_json::json!({
+        "metadata": { "version": 1, "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "x" },
+        "tag_config": { "name": "water" },
+        "bills": {
+            "HB0001": {
+                "text_hash": "abc",
+                "score": { "final_score": 0.9, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+            }
+        }
+    });
+    fs::write(tags_dir.join("water.tag.json"), tag_file.to_string()).unwrap();
+
+    let geometry = dir.path().join("geometry.json");
+    fs::write(&geometry, r#"{"wy": {"type": "Point", "coordinates": [-107.5, 43.0]}}"#).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--repos", "wy,gu", "--filter", "none", "--format", "geojson", "--geometry"])
+        .arg(&geometry)
+        .current_dir(dir.path())
+        .env("GOVBOT_DIR", dir.path())
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Skipped 1 entries with no matching geometry"));
+
+    let collection: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(collection["type"], "FeatureCollection");
+    // One feature per emitted entry, each carrying its jurisdiction's geometry
+    let features = collection["features"].as_array().unwrap();
+    assert_eq!(features.len(), 2);
+    assert!(features.iter().all(|f| f["geometry"]["coordinates"] == serde_json::json!([-107.5, 43.0])));
+
+    let feature = |id: &str| features.iter().find(|f| f["properties"]["id"] == id).unwrap();
+    let water = feature("HB0001");
+    assert_eq!(water["type"], "Feature");
+    assert_eq!(water["geometry"]["type"], "Point");
+    assert_eq!(water["properties"]["title"], "Water rights");
+    assert_eq!(water["properties"]["tags"], serde_json::json!(["water"]));
+    assert_eq!(water["properties"]["jurisdiction"], "wy");
+    let school = feature("HB0002");
+    assert_eq!(school["properties"]["title"], "School funding");
+    assert_eq!(school["properties"]["tags"], serde_json::json!([]));
+}
+
+#[test]
+fn test_geojson_requires_geometry() {
+    let output = govbot().args(["logs", "--format", "geojson"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--geometry"));
+}
+
+#[test]
+fn test_fields_file_matches_inline_fields() {
+    let inline = govbot()
+        .args(["logs", "--limit", "5", "--fields", "id,bill.title,log.action.description"])
+        .output()
+        .unwrap();
+    assert!(inline.status.success(), "{}", String::from_utf8_lossy(&inline.stderr));
+    let inline = String::from_utf8(inline.stdout).unwrap();
+    let first: serde_json::Value = serde_json::from_str(inline.lines().next().unwrap()).unwrap();
+    let keys: Vec<&String> = first.as_object().unwrap().keys().collect();
+    assert!(keys.iter().all(|k| ["id", "bill.title", "log.action.description"].contains(&k.as_str())), "{:?}", keys);
+    assert!(first.get("id").is_some());
+
+    let dir = tempfile::tempdir().unwrap();
+    for (name, contents) in [
+        ("fields.txt", "# standard projection\nid\nbill.title\n\nlog.action.description\n"),
+        ("fields.yml", "- id\n- bill.title\n- log.action.description\n"),
+    ] {
+        let path = dir.path().join(name);
+        fs::write(&path, contents).unwrap();
+        let from_file = govbot().args(["logs", "--limit", "5", "--fields-file"]).arg(&path).output().unwrap();
+        assert!(from_file.status.success(), "{}", String::from_utf8_lossy(&from_file.stderr));
+        assert_eq!(String::from_utf8(from_file.stdout).unwrap(), inline, "{}", name);
+
+        // --fields overrides the file
+        let overridden = govbot()
+            .args(["logs", "--limit", "5", "--fields", "id", "--fields-file"])
+            .arg(&path)
+            .output()
+            .unwrap();
+        let line: serde_json::Value =
+            serde_json::from_str(String::from_utf8(overridden.stdout).unwrap().lines().next().unwrap()).unwrap();
+        assert_eq!(line.as_object().unwrap().keys().collect::<Vec<_>>(), vec!["id"]);
+    }
+}
+
+#[test]
+fn test_concurrent_limit_counts_entries_left_after_dedup() {
+    let govbot_dir = tempfile::tempdir().unwrap();
+    let bills = "country:us/state:wy/sessions/2025/bills";
+    let write_log = |repo: &str, bill: &str| {
+        let logs = govbot_dir.path().join("repos").join(repo).join(bills).join(bill).join("logs");
+        fs::create_dir_all(&logs).unwrap();
+        let log = logs.join("20250101T000000Z_introduced.json");
+        fs::write(&log, format!(r#"{{"action": {{"description": "Introduced"}}, "bill_id": "{}"}}"#, bill)).unwrap();
+        log
+    };
+    let shared = write_log("ak-legislation", "HB0001");
+    // wy links to the log ak already emits, on both sides of its own log in creation order
+    let wy_logs = write_log("wy-legislation", "HB0002").parent().unwrap().to_path_buf();
+    for i in 0..20 {
+        let link = wy_logs.join(format!("20250102T0000{:02}Z_linked.json", i));
+        std::os::unix::fs::symlink(&shared, link).unwrap();
+        if i == 9 {
+            fs::remove_file(wy_logs.join("20250101T000000Z_introduced.json")).unwrap();
+            write_log("wy-legislation", "HB0002");
+        }
+    }
+
+    let run = |parallel: &str| {
+        let path = govbot_dir.path().join(format!("parallel-{}.ndjson", parallel));
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "ak,wy", "--filter", "none", "--limit", "1", "--parallel", parallel, "--output"])
+            .arg(&path)
+            .env("GOVBOT_DIR", govbot_dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        fs::read_to_string(&path).unwrap()
+    };
+
+    let serial = run("1");
+    let ids: Vec<String> = serial
+        .lines()
+        .map(|l| serde_json::from_str::<serde_json::Value>(l).unwrap()["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(ids, ["HB0001", "HB0002"]);
+    assert_eq!(run("4"), serial);
+}
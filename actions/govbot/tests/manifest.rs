@@ -0,0 +1,20 @@
+use govbot::RepoManifest;
+use std::fs;
+
+#[test]
+fn test_parse_yaml_and_list_manifests() {
+    let dir = tempfile::tempdir().unwrap();
+    let yml = dir.path().join("repos.yml");
+    fs::write(&yml, "replace: true\nrepos:\n  - wy\n  - locale: ACME\n    repo: acme-pipeline\n").unwrap();
+    let manifest = RepoManifest::load(&yml).unwrap();
+    assert!(manifest.replace);
+    assert_eq!(manifest.locales(), vec!["wy", "acme"]);
+    assert_eq!(manifest.repo_overrides().get("acme").map(String::as_str), Some("acme-pipeline"));
+
+    let list = RepoManifest::parse_list("# private pipelines\nacme acme-pipeline\nzz\n");
+    assert!(!list.replace);
+    assert!(list.contains("zz"));
+    let locales = list.locales();
+    assert!(locales.contains(&"wy".to_string()), "built-ins are kept when merging");
+    assert!(locales.ends_with(&["acme".to_string(), "zz".to_string()]));
+}
@@ -0,0 +1,67 @@
+use govbot::types::primary_source_url;
+use govbot::Metadata;
+use serde_json::json;
+use std::fs;
+
+/// Representative metadata.json from the mock Guam repo
+fn fixture() -> Metadata {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/mocks/.govbot/repos/gu-legislation/country:us/state:gu/sessions/38th/bills/B10-38/metadata.json");
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn empty() -> Metadata {
+    serde_json::from_value(json!({ "title": "Untitled" })).unwrap()
+}
+
+#[test]
+fn test_primary_source_url() {
+    assert_eq!(fixture().primary_source_url(), Some("https://guamlegislature.gov/bills/"));
+    assert_eq!(empty().primary_source_url(), None);
+
+    // Blank URLs are skipped
+    let metadata: Metadata = serde_json::from_value(json!({
+        "sources": [{ "url": "" }, { "url": "https://example.com/hb1", "note": "" }]
+    }))
+    .unwrap();
+    assert_eq!(metadata.primary_source_url(), Some("https://example.com/hb1"));
+}
+
+#[test]
+fn test_primary_source_url_of_raw_metadata() {
+    // Same lookup on the untyped body, as the RSS link fallback reads it
+    let bill = json!({ "sources": [{ "url": " " }, { "url": "https://example.com/hb1" }] });
+    assert_eq!(primary_source_url(&bill), Some("https://example.com/hb1"));
+    assert_eq!(primary_source_url(&json!({ "title": "Untitled" })), None);
+    assert_eq!(primary_source_url(&json!({ "sources": [{ "note": "no url" }] })), None);
+}
+
+#[test]
+fn test_jurisdiction_id() {
+    assert_eq!(
+        fixture().jurisdiction_id(),
+        Some("ocd-jurisdiction/country:us/territory:gu/government")
+    );
+    assert_eq!(empty().jurisdiction_id(), None);
+}
+
+#[test]
+fn test_primary_sponsor() {
+    assert_eq!(fixture().primary_sponsor(), Some("Eulogio Shawn Gumataotao"));
+    assert_eq!(empty().primary_sponsor(), None);
+
+    // Falls back to classification when no sponsorship is flagged primary
+    let metadata: Metadata = serde_json::from_value(json!({
+        "sponsorships": [
+            { "name": "Cosponsor", "classification": "cosponsor", "primary": false },
+            { "name": "Author", "classification": "primary" }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(metadata.primary_sponsor(), Some("Author"));
+
+    let metadata: Metadata = serde_json::from_value(json!({
+        "sponsorships": [{ "name": "Cosponsor", "classification": "cosponsor", "primary": false }]
+    }))
+    .unwrap();
+    assert_eq!(metadata.primary_sponsor(), None);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::write_log;
+use futures::StreamExt;
+use govbot::prelude::*;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+const BILL_DIR: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001";
+
+fn write_fixture(git_dir: &Path) -> serde_json::Value {
+    let metadata = json!({
+        "identifier": "HB0001",
+        "title": "Budget bill",
+        "sponsorships": [{ "name": "Sponsor", "primary": true }],
+        "jurisdiction": { "id": "ocd-jurisdiction/country:us/state:wy/government" }
+    });
+    let bill_dir = git_dir.join(BILL_DIR);
+    write_log(
+        &bill_dir,
+        r#"{"action": {"description": "Introduced", "date": "2025-01-01"}, "bill_id": "HB0001"}"#,
+    );
+    fs::write(bill_dir.join("metadata.json"), metadata.to_string()).unwrap();
+    metadata
+}
+
+async fn first_entry(git_dir: &Path, join: &str) -> LogEntry {
+    let config = ConfigBuilder::new(git_dir)
+        .join_options_str(join)
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut stream = PipelineProcessor::new(config).process();
+    stream.next().await.unwrap().unwrap()
+}
+
+#[tokio::test]
+async fn test_full_metadata_join_attaches_metadata() {
+    let dir = tempfile::tempdir().unwrap();
+    let metadata = write_fixture(dir.path());
+
+    let entry = first_entry(dir.path(), "full_metadata").await;
+    assert_eq!(entry.metadata, Some(metadata));
+}
+
+#[tokio::test]
+async fn test_metadata_absent_without_join() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path());
+
+    assert_eq!(first_entry(dir.path(), "").await.metadata, None);
+    assert_eq!(first_entry(dir.path(), "bill").await.metadata, None);
+}
+
+#[tokio::test]
+async fn test_transform_runs_on_every_entry() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path());
+    let bill_dir = dir.path().join(BILL_DIR);
+    fs::write(
+        bill_dir.join("logs/20250102T000000Z_referred.json"),
+        r#"{"action": {"description": "Referred", "date": "2025-01-02"}, "bill_id": "HB0001"}"#,
+    )
+    .unwrap();
+    fs::write(bill_dir.join("logs/20250103T000000Z_vote.vote_event.pass.json"), "{}").unwrap();
+
+    let config = ConfigBuilder::new(dir.path())
+        .join_options_str("full_metadata")
+        .unwrap()
+        .build()
+        .unwrap();
+    let entries: Vec<LogEntry> = PipelineProcessor::new(config)
+        .with_transform(|entry| {
+            // Joins have already run when the transform sees the entry
+            let title = entry.metadata.as_ref().and_then(|m| m["title"].as_str()).map(str::to_string);
+            entry.metadata.get_or_insert_with(|| json!({}))["synthetic"] = json!(title);
+        })
+        .process()
+        .map(|r| r.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(entries.len(), 3);
+    for entry in &entries {
+        assert_eq!(entry.metadata.as_ref().unwrap()["synthetic"], json!("Budget bill"));
+    }
+}
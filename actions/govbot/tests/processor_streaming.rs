@@ -0,0 +1,53 @@
+use futures::StreamExt;
+use govbot::prelude::*;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+const LOGS_DIR: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs";
+
+async fn collect(git_dir: &Path, streaming: bool) -> Vec<LogEntry> {
+    let config = ConfigBuilder::new(git_dir)
+        .streaming_parse(streaming)
+        .build()
+        .unwrap();
+    PipelineProcessor::new(config)
+        .process()
+        .map(|entry| entry.unwrap())
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn test_streaming_parse_matches_full_parse_fields() {
+    let dir = tempfile::tempdir().unwrap();
+    let logs = dir.path().join(LOGS_DIR);
+    fs::create_dir_all(&logs).unwrap();
+    // A few MB of embedded document text alongside the fields an entry needs
+    let page = "Section 1. ".repeat(1000);
+    let log = json!({
+        "action": {
+            "description": "Introduced",
+            "date": "2025-01-01T00:00:00+00:00",
+            "classification": ["introduction"],
+        },
+        "bill_id": "HB0001",
+        "documents": (0..300).map(|i| json!({ "page": i, "text": page })).collect::<Vec<_>>(),
+    });
+    fs::write(logs.join("20250101T000000Z_introduced.json"), log.to_string()).unwrap();
+
+    let full = collect(dir.path(), false).await;
+    let streamed = collect(dir.path(), true).await;
+    assert_eq!(full.len(), 1);
+    assert_eq!(streamed.len(), 1);
+    assert_eq!(streamed[0].filename, full[0].filename);
+
+    let (LogContent::Full(full_log), LogContent::Full(streamed_log)) = (&full[0].log, &streamed[0].log) else {
+        panic!("expected full log content");
+    };
+    assert!(full_log.get("documents").is_some());
+    assert_eq!(streamed_log.get("documents"), None);
+    assert_eq!(streamed_log["action"], full_log["action"]);
+    assert_eq!(streamed_log["bill_id"], full_log["bill_id"]);
+    assert_eq!(streamed_log.as_object().unwrap().len(), 2);
+}
@@ -0,0 +1,96 @@
+use futures::StreamExt;
+use govbot::prelude::*;
+use std::fs;
+use std::path::Path;
+
+const LOGS_DIR: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs";
+
+fn write_log(git_dir: &Path, name: &str, contents: &str) {
+    let logs = git_dir.join(LOGS_DIR);
+    fs::create_dir_all(&logs).unwrap();
+    fs::write(logs.join(name), contents).unwrap();
+}
+
+async fn collect(git_dir: &Path, validate: bool) -> Vec<govbot::Result<LogEntry>> {
+    let config = ConfigBuilder::new(git_dir)
+        .validate_entries(validate)
+        .build()
+        .unwrap();
+    PipelineProcessor::new(config).process().collect().await
+}
+
+#[tokio::test]
+async fn test_conforming_log_passes_validation() {
+    let dir = tempfile::tempdir().unwrap();
+    write_log(
+        dir.path(),
+        "20250101T000000Z_introduced.json",
+        r#"{"action": {"description": "Introduced", "date": "2025-01-01T00:00:00+00:00", "organization_id": "~{\"classification\": \"lower\"}", "classification": ["introduction"], "related_entities": []}, "bill_id": "HB0001"}"#,
+    );
+
+    let results = collect(dir.path(), true).await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_ok(), "{:?}", results[0]);
+}
+
+#[tokio::test]
+async fn test_log_missing_required_field_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    // No bill_id
+    write_log(
+        dir.path(),
+        "20250101T000000Z_introduced.json",
+        r#"{"action": {"description": "Introduced", "date": "2025-01-01"}}"#,
+    );
+
+    let results = collect(dir.path(), true).await;
+    assert_eq!(results.len(), 1);
+    match &results[0] {
+        Err(govbot::Error::Validation(msg)) => assert!(msg.contains("20250101T000000Z_introduced.json")),
+        other => panic!("expected validation error, got {:?}", other),
+    }
+
+    // Validation is off by default, so the same file passes through
+    let results = collect(dir.path(), false).await;
+    assert!(results[0].is_ok());
+}
+
+#[tokio::test]
+async fn test_oversized_log_is_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    let small = r#"{"action": {"description": "Introduced"}, "bill_id": "HB0001"}"#;
+    write_log(dir.path(), "20250101T000000Z_introduced.json", small);
+    let huge = format!(r#"{{"bill_id": "HB0001", "html": "{}"}}"#, "x".repeat(64 * 1024));
+    write_log(dir.path(), "20250102T000000Z_scraped.json", &huge);
+
+    let config = ConfigBuilder::new(dir.path()).max_file_size(4096).build().unwrap();
+    let processor = PipelineProcessor::new(config);
+    let results: Vec<_> = processor.process().collect().await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].as_ref().unwrap().filename.ends_with("_introduced.json"));
+    assert_eq!(processor.oversized_skipped(), 1);
+
+    // Unlimited by default
+    assert_eq!(collect(dir.path(), false).await.len(), 2);
+}
+
+#[test]
+fn test_oversized_file_reports_unreadable_sizes() {
+    let dir = tempfile::tempdir().unwrap();
+    write_log(dir.path(), "20250101T000000Z_introduced.json", "{}");
+    let log = dir.path().join(LOGS_DIR).join("20250101T000000Z_introduced.json");
+
+    assert_eq!(govbot::processor::oversized_file(&log, None).unwrap(), None);
+    assert_eq!(govbot::processor::oversized_file(&log, Some(2)).unwrap(), None);
+    assert_eq!(govbot::processor::oversized_file(&log, Some(1)).unwrap(), Some(2));
+    assert!(govbot::processor::oversized_file(&dir.path().join("missing.json"), Some(1)).is_err());
+}
+
+#[tokio::test]
+async fn test_mock_repo_logs_conform_to_the_published_schema() {
+    let repos = Path::new(env!("CARGO_MANIFEST_DIR")).join("mocks/.govbot/repos");
+    let results = collect(&repos, true).await;
+    assert!(!results.is_empty());
+    let invalid: Vec<String> = results.iter().filter_map(|r| r.as_ref().err().map(|e| e.to_string())).collect();
+    assert!(invalid.is_empty(), "{:#?}", invalid);
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use common::log_entry;
+use govbot::publish::{
+    deduplicate_entries_by, deduplicate_entries_with_ids, entries_after, exclude_tags_from, filter_by_tags, filter_by_tags_with_min_score, get_repos_from_config, get_repos_from_config_with_locales, is_watch_trigger,
+    read_cursor, sort_by_timestamp, sort_by_timestamp_order, write_cursor,
+};
+use govbot::publish::DedupMode;
+use govbot::SortOrder;
+use govbot::rss::{extract_guid, json_to_rss};
+use serde_json::{json, Value};
+use std::path::Path;
+
+#[test]
+fn test_excluded_tag_entries_absent_from_feed() {
+    let all_tags = vec!["budget".to_string(), "education".to_string(), "transit".to_string()];
+    let tags_to_use = exclude_tags_from(all_tags, &["transit".to_string()]);
+    assert_eq!(tags_to_use, vec!["budget", "education"]);
+
+    let entries = vec![
+        log_entry("HB1", "Bill HB1", &["budget"]),
+        log_entry("HB2", "Bill HB2", &["education"]),
+        log_entry("HB3", "Bill HB3", &["transit"]),
+        log_entry("HB4", "Bill HB4", &["transit", "education"]),
+    ];
+
+    let kept: Vec<Value> = entries
+        .into_iter()
+        .filter(|e| filter_by_tags(e, &tags_to_use))
+        .collect();
+    let ids: Vec<&str> = kept.iter().map(|e| e["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["HB1", "HB2", "HB4"]);
+
+    let feed = json_to_rss(kept, "Feed", "Desc", "https://example.com", None, "en-us");
+    assert!(feed.contains("Bill HB1"));
+    assert!(feed.contains("Bill HB4"));
+    assert!(!feed.contains("Bill HB3"));
+}
+
+#[test]
+fn test_min_score_drops_low_confidence_matches() {
+    let tags_to_use = vec!["budget".to_string(), "education".to_string()];
+    let mut low = log_entry("HB1", "Bill HB1", &["budget"]);
+    low["tags"]["budget"]["final_score"] = json!(0.42);
+    let high = log_entry("HB2", "Bill HB2", &["budget"]);
+    // A high score on a tag that wasn't selected doesn't count
+    let mut other = log_entry("HB3", "Bill HB3", &["budget", "transit"]);
+    other["tags"]["budget"]["final_score"] = json!(0.3);
+
+    let kept: Vec<&str> = [&low, &high, &other]
+        .into_iter()
+        .filter(|e| filter_by_tags_with_min_score(e, &tags_to_use, 0.8))
+        .map(|e| e["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(kept, vec!["HB2"]);
+
+    // Without a threshold every match is kept
+    assert!(filter_by_tags(&low, &tags_to_use));
+    assert!(filter_by_tags_with_min_score(&low, &tags_to_use, 0.4));
+}
+
+fn timed_entry(id: &str, timestamp: &str) -> Value {
+    let mut e = log_entry(id, &format!("Bill {}", id), &["budget"]);
+    e["timestamp"] = json!(timestamp);
+    e["sources"]["log"] = json!(format!(
+        "country:us/state:wy/sessions/2025/bills/{}/logs/{}_x.json",
+        id, timestamp
+    ));
+    e
+}
+
+/// Simulate one `build --after @last` run and return the GUIDs it published
+fn publish_run(dir: &std::path::Path, entries: Vec<Value>) -> Vec<String> {
+    let entries = sort_by_timestamp(entries);
+    let entries = match read_cursor(dir).unwrap() {
+        Some(cursor) => entries_after(entries, &cursor),
+        None => entries,
+    };
+    write_cursor(dir, &entries).unwrap();
+    entries.iter().map(extract_guid).collect()
+}
+
+#[test]
+fn test_after_last_never_reemits_entries() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let first_batch = vec![
+        timed_entry("HB1", "20250101T000000Z"),
+        timed_entry("HB2", "20250102T000000Z"),
+    ];
+    let first = publish_run(dir.path(), first_batch.clone());
+    assert_eq!(first.len(), 2);
+
+    let mut second_batch = first_batch;
+    second_batch.push(timed_entry("HB3", "20250103T000000Z"));
+    let second = publish_run(dir.path(), second_batch.clone());
+    assert_eq!(second.len(), 1);
+    assert!(second[0].contains("HB3"));
+    assert!(second.iter().all(|guid| !first.contains(guid)));
+
+    // Nothing new: nothing is re-emitted and the cursor stays put
+    let third = publish_run(dir.path(), second_batch);
+    assert!(third.is_empty());
+    assert_eq!(read_cursor(dir.path()).unwrap().as_deref(), Some(second[0].as_str()));
+}
+
+#[test]
+fn test_after_timestamp_cursor() {
+    let entries = sort_by_timestamp(vec![
+        timed_entry("HB1", "20250101T000000Z"),
+        timed_entry("HB2", "20250102T000000Z"),
+    ]);
+    let kept = entries_after(entries, "20250101T000000Z");
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0]["id"], "HB2");
+}
+
+#[test]
+fn test_sort_order_controls_feed_chronology() {
+    let entries = vec![
+        timed_entry("HB2", "20250102T000000Z"),
+        timed_entry("HB1", "20250101T000000Z"),
+        timed_entry("HB3", "20250103T000000Z"),
+    ];
+    let ids = |entries: &[Value]| -> Vec<String> {
+        entries.iter().map(|e| e["id"].as_str().unwrap().to_string()).collect()
+    };
+
+    let asc = sort_by_timestamp_order(entries.clone(), SortOrder::Ascending);
+    assert_eq!(ids(&asc), vec!["HB1", "HB2", "HB3"]);
+
+    let desc = sort_by_timestamp_order(entries, SortOrder::Descending);
+    assert_eq!(ids(&desc), vec!["HB3", "HB2", "HB1"]);
+
+    // Limiting keeps the oldest for ASC and the newest for DESC
+    assert_eq!(ids(&asc[..1]), vec!["HB1"]);
+    assert_eq!(ids(&desc[..1]), vec!["HB3"]);
+}
+
+#[test]
+fn test_mixed_timestamp_formats_sort_chronologically() {
+    // Lexically "2025-..." sorts before "20250...", which would put the newest entry last
+    let entries = sort_by_timestamp(vec![
+        timed_entry("HB1", "20250101T120000Z"),
+        timed_entry("HB2", "2025-01-01T13:00:00Z"),
+        timed_entry("HB3", "2025-01-01T06:00:00-05:00"),
+        timed_entry("HB4", "not a timestamp"),
+    ]);
+    let ids: Vec<&str> = entries.iter().map(|e| e["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["HB2", "HB1", "HB3", "HB4"]);
+
+    let kept = entries_after(entries, "2025-01-01T12:30:00Z");
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0]["id"], "HB2");
+}
+
+#[test]
+fn test_repo_groups_expand_to_member_locales() {
+    let config: Value = serde_yaml::from_str(
+        "repo_groups:\n  midwest: [il, oh, mi, in, wi]\n  lakes: [mi, wi, mn]\nrepos: [midwest, ca, lakes, il]\n",
+    )
+    .unwrap();
+    assert_eq!(
+        get_repos_from_config(&config).unwrap(),
+        vec!["il", "oh", "mi", "in", "wi", "ca", "mn"]
+    );
+
+    // Without groups, names are still lowercased and checked
+    let plain: Value = serde_yaml::from_str("repos: [IL, ca, il]\n").unwrap();
+    assert_eq!(get_repos_from_config(&plain).unwrap(), vec!["il", "ca"]);
+    let unknown: Value = serde_yaml::from_str("repos: [acme, ca]\n").unwrap();
+    let err = get_repos_from_config(&unknown).unwrap_err();
+    assert!(err.to_string().contains("Unknown locale 'acme'"), "{}", err);
+}
+
+#[test]
+fn test_manifest_locales_are_accepted_in_repos_and_groups() {
+    let known: Vec<String> = ["ca", "acme"].iter().map(|s| s.to_string()).collect();
+    let plain: Value = serde_yaml::from_str("repos: [Acme, ca]\n").unwrap();
+    assert_eq!(get_repos_from_config_with_locales(&plain, &known).unwrap(), vec!["acme", "ca"]);
+
+    let grouped: Value = serde_yaml::from_str("repo_groups:\n  west: [ca]\nrepos: [west, acme]\n").unwrap();
+    assert_eq!(get_repos_from_config_with_locales(&grouped, &known).unwrap(), vec!["ca", "acme"]);
+
+    let bad_member: Value = serde_yaml::from_str("repo_groups:\n  west: [ca, zz]\nrepos: [west]\n").unwrap();
+    let err = get_repos_from_config_with_locales(&bad_member, &known).unwrap_err();
+    assert!(err.to_string().contains("Unknown locale 'zz' in repo_groups.west"), "{}", err);
+}
+
+#[test]
+fn test_unknown_repo_group_is_an_error() {
+    let config: Value =
+        serde_yaml::from_str("repo_groups:\n  midwest: [il, oh]\nrepos: [midwest, southeast]\n").unwrap();
+    let err = get_repos_from_config(&config).unwrap_err();
+    assert!(err.to_string().contains("Unknown repo group 'southeast'"), "{}", err);
+    assert!(err.to_string().contains("midwest"), "{}", err);
+}
+
+#[test]
+fn test_watch_triggers_on_config_and_tag_files_only() {
+    let config = Path::new("/project/govbot.yml");
+    assert!(is_watch_trigger(config, config));
+    assert!(is_watch_trigger(
+        Path::new("/project/country:us/state:wy/sessions/2025/tags/animals.tag.json"),
+        config
+    ));
+    assert!(!is_watch_trigger(Path::new("/project/country:us/state:wy/sessions/2025/tags/.animals.swp"), config));
+    assert!(!is_watch_trigger(Path::new("/project/other.yml"), config));
+}
+
+#[test]
+fn test_content_dedup_collapses_rescraped_actions() {
+    // Same action recorded under two log filenames, so the GUIDs differ
+    let mut first = timed_entry("HB1", "20250101T000000Z");
+    first["log"]["action"]["date"] = json!("2025-01-01");
+    let mut rescraped = timed_entry("HB1", "20250103T000000Z");
+    rescraped["log"]["action"]["date"] = json!("2025-01-01");
+    assert_ne!(extract_guid(&first), extract_guid(&rescraped));
+    let other = timed_entry("HB2", "20250102T000000Z");
+
+    let entries = vec![first.clone(), rescraped.clone(), other.clone()];
+    assert_eq!(deduplicate_entries_by(entries.clone(), DedupMode::Guid).len(), 3);
+
+    let deduped = deduplicate_entries_by(entries, DedupMode::Content);
+    assert_eq!(deduped.len(), 2);
+    assert_eq!(deduped[0]["timestamp"], "20250103T000000Z", "newest copy is kept");
+    assert_eq!(deduped[1]["id"], "HB2");
+
+    // A different action date is a different action
+    let mut later = rescraped;
+    later["log"]["action"]["date"] = json!("2025-02-01");
+    assert_eq!(deduplicate_entries_by(vec![first, later], DedupMode::Content).len(), 2);
+}
+
+#[test]
+fn test_content_dedup_keeps_other_jurisdictions_and_undated_actions() {
+    let dated = |id: &str, timestamp: &str, date: Option<&str>| {
+        let mut e = timed_entry(id, timestamp);
+        e["log"]["action"] = json!({"description": "Introduced"});
+        if let Some(date) = date {
+            e["log"]["action"]["date"] = json!(date);
+        }
+        e
+    };
+
+    // The same bill id and action in another state or session is a different bill
+    let wy = dated("HB1", "20250101T000000Z", Some("2025-01-01"));
+    let mut co = dated("HB1", "20250102T000000Z", Some("2025-01-01"));
+    co["sources"]["log"] = json!("country:us/state:co/sessions/2025/bills/HB1/logs/x.json");
+    let mut next_session = dated("HB1", "20250103T000000Z", Some("2025-01-01"));
+    next_session["sources"]["log"] = json!("country:us/state:wy/sessions/2026/bills/HB1/logs/x.json");
+    assert_eq!(deduplicate_entries_by(vec![wy, co, next_session], DedupMode::Content).len(), 3);
+
+    // Without a date there is nothing to tell two actions apart
+    let undated = vec![dated("HB1", "20250101T000000Z", None), dated("HB1", "20250102T000000Z", None)];
+    assert_eq!(deduplicate_entries_by(undated, DedupMode::Content).len(), 2);
+}
+
+#[test]
+fn test_normalized_identifiers_dedup_spelling_variants() {
+    let entries: Vec<Value> = ["HB 1", "HB1", "hb-1"]
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let mut e = timed_entry(id, &format!("2025010{}T000000Z", i + 1));
+            e["log"]["action"] = json!({"description": "Introduced", "date": "2025-01-01"});
+            e
+        })
+        .collect();
+
+    assert_eq!(deduplicate_entries_with_ids(entries.clone(), DedupMode::Content, false).len(), 3);
+
+    let deduped = deduplicate_entries_with_ids(entries, DedupMode::Content, true);
+    assert_eq!(deduped.len(), 1);
+    // The newest entry survives with its raw id
+    assert_eq!(deduped[0]["id"], "hb-1");
+}
@@ -0,0 +1,329 @@
+mod common;
+
+use common::log_entry;
+use govbot::publish::{get_feed_options_from_config, paginate_entries};
+use govbot::rss::{
+    extract_link, item_guid, json_to_html, json_to_rss, json_to_rss_with_options, render_link_template, strip_html, validate_feed,
+    FeedOptions, FeedPage,
+};
+use govbot::SortOrder;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+fn entry() -> Value {
+    log_entry("HB0001", "An act relating to budgets", &["budget"])
+}
+
+fn feed() -> String {
+    json_to_rss(vec![entry()], "Feed", "Description", "https://example.com", None, "en-us")
+}
+
+#[test]
+fn test_generated_feed_validates() {
+    assert_eq!(validate_feed(&feed()), Ok(()));
+}
+
+#[test]
+fn test_control_chars_in_description_are_caught() {
+    let corrupted = feed().replacen("Introduced", "Intro\u{0008}duced", 1);
+    let problems = validate_feed(&corrupted).unwrap_err();
+    assert!(problems.iter().any(|p| p.contains("U+0008")), "{:?}", problems);
+}
+
+#[test]
+fn test_missing_channel_elements_are_caught() {
+    let xml = r#"<?xml version="1.0"?><rss version="2.0"><channel><title></title><link>https://example.com</link><description>d</description><item><pubDate>not a date</pubDate></item></channel></rss>"#;
+    let problems = validate_feed(xml).unwrap_err();
+    assert!(problems.iter().any(|p| p.contains("<title>")));
+    assert!(problems.iter().any(|p| p.contains("neither")));
+    assert!(problems.iter().any(|p| p.contains("<guid>")));
+    assert!(problems.iter().any(|p| p.contains("<pubDate>")));
+}
+
+#[test]
+fn test_control_chars_in_bill_title_are_stripped() {
+    let mut messy = entry();
+    messy["bill"]["title"] = json!("An act\u{0008} relating\u{000C} to budgets");
+
+    let xml = json_to_rss(vec![messy], "Feed\u{000B}", "Description", "https://example.com", None, "en-us");
+    assert_eq!(validate_feed(&xml), Ok(()));
+
+    let channel = rss::Channel::read_from(xml.as_bytes()).unwrap();
+    assert_eq!(channel.title(), "Feed");
+    assert!(channel.items()[0].title().unwrap().contains("An act relating to budgets"));
+}
+
+#[test]
+fn test_html_links_to_custom_feed_path() {
+    let html = json_to_html(
+        vec![entry()],
+        Some("Budget Bills"),
+        "https://example.com",
+        Some("https://example.com/feeds/"),
+        Some("budget.xml"),
+    );
+    assert!(html.contains(
+        r#"<link rel="alternate" type="application/rss+xml" title="Budget Bills" href="https://example.com/feeds/budget.xml">"#
+    ));
+    assert!(html.contains(r#"<a href="https://example.com/feeds/budget.xml" class="rss-link">"#));
+    assert!(!html.contains("feed.xml"));
+
+    // Defaults to feed.xml
+    let html = json_to_html(vec![entry()], Some("Budget Bills"), "https://example.com", None, None);
+    assert!(html.contains(r#"href="https://example.com/feed.xml""#));
+}
+
+#[test]
+fn test_guid_is_permalink_when_item_has_link() {
+    let mut linked = entry();
+    linked["sources"]["bill"] = json!("wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/metadata.json");
+    let xml = json_to_rss(vec![linked.clone()], "Feed", "Description", "https://example.com", None, "en-us");
+
+    let expected = "https://example.com/wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/metadata.json#20250101T000000Z_introduced";
+    // isPermaLink defaults to true, so the attribute is omitted
+    assert!(xml.contains(&format!("<guid>{}</guid>", expected)), "{}", xml);
+
+    let guid = item_guid(&linked, Some("https://example.com/bill"));
+    assert!(guid.permalink);
+}
+
+#[test]
+fn test_guid_falls_back_to_log_path_without_link() {
+    let xml = feed();
+    assert!(xml.contains(r#"<guid isPermaLink="false">wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs/20250101T000000Z_introduced.json</guid>"#), "{}", xml);
+    assert!(!item_guid(&entry(), None).permalink);
+}
+
+#[test]
+fn test_permalink_guids_stay_unique_per_log_entry() {
+    let mut introduced = entry();
+    introduced["sources"]["bill"] = json!("bills/HB0001/metadata.json");
+    let mut passed = introduced.clone();
+    passed["sources"]["log"] = json!("bills/HB0001/logs/20250201T000000Z_passed.json");
+    passed["timestamp"] = json!("20250201T000000Z");
+
+    // Same bill link, distinct log entries: both kept with distinct GUIDs; exact repeats still dropped
+    let xml = json_to_rss(
+        vec![introduced.clone(), passed, introduced],
+        "Feed", "Description", "https://example.com", None, "en-us",
+    );
+    assert_eq!(xml.matches("<item>").count(), 2);
+    assert!(xml.contains("metadata.json#20250101T000000Z_introduced</guid>"));
+    assert!(xml.contains("metadata.json#20250201T000000Z_passed</guid>"));
+}
+
+#[test]
+fn test_feed_schedule_sets_ttl_and_skip_elements() {
+    let config: Value = serde_yaml::from_str(
+        "build:\n  ttl: 1440\n  skip_hours: [0, 1, 25]\n  skip_days: [saturday, Sunday, Someday]\n",
+    )
+    .unwrap();
+    let schedule = get_feed_options_from_config(&config);
+    assert_eq!(
+        schedule,
+        FeedOptions {
+            ttl_minutes: Some(1440),
+            skip_hours: vec![0, 1],
+            skip_days: vec!["Saturday".to_string(), "Sunday".to_string()],
+            strip_html: true,
+            strip_html_index: true,
+            page: None,
+            link_template: None,
+            tag_authors: BTreeMap::new(),
+            category_domain: None,
+            tag_labels: BTreeMap::new(),
+        }
+    );
+
+    let xml = json_to_rss_with_options(vec![entry()], "Feed", "Description", "https://example.com", None, "en-us", &schedule);
+    assert!(xml.contains("<ttl>1440</ttl>"), "{}", xml);
+    assert!(xml.contains("<skipHours><hour>0</hour><hour>1</hour></skipHours>"), "{}", xml);
+    assert!(xml.contains("<skipDays><day>Saturday</day><day>Sunday</day></skipDays>"), "{}", xml);
+    assert!(validate_feed(&xml).is_ok());
+
+    // Unconfigured schedules leave the elements out
+    assert_eq!(get_feed_options_from_config(&serde_json::json!({ "build": {} })), FeedOptions::default());
+    let plain = feed();
+    assert!(!plain.contains("<ttl>") && !plain.contains("<skipHours>") && !plain.contains("<skipDays>"));
+}
+
+#[test]
+fn test_markup_is_stripped_from_descriptions() {
+    let mut marked = entry();
+    marked["log"]["action"]["description"] =
+        serde_json::json!("<b>Passed</b> the <i>Senate</i><br/>with amendments &amp; notes");
+
+    let xml = json_to_rss_with_options(
+        vec![marked.clone()],
+        "Feed",
+        "Description",
+        "https://example.com",
+        None,
+        "en-us",
+        &FeedOptions::default(),
+    );
+    assert!(xml.contains("Passed the Senate with amendments & notes"), "{}", xml);
+    assert!(!xml.contains("<b>"), "{}", xml);
+
+    // Disabled, the markup is kept
+    let config: Value = serde_yaml::from_str("build:\n  strip_html: false\n").unwrap();
+    let options = get_feed_options_from_config(&config);
+    assert!(!options.strip_html && options.strip_html_index);
+    let raw = json_to_rss_with_options(vec![marked], "Feed", "Description", "https://example.com", None, "en-us", &options);
+    assert!(raw.contains("<b>Passed</b>"), "{}", raw);
+
+    assert_eq!(strip_html("a < b &lt;ok&gt;"), "a < b <ok>");
+}
+
+#[test]
+fn test_paged_feed_links_neighbor_archives() {
+    let entries: Vec<Value> = ["20250101T000000Z", "20250201T000000Z", "20250301T000000Z"]
+        .iter()
+        .map(|ts| {
+            let mut e = entry();
+            e["timestamp"] = json!(ts);
+            e["sources"]["log"] = json!(format!(
+                "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs/{}_introduced.json",
+                ts
+            ));
+            e
+        })
+        .collect();
+
+    let pages = paginate_entries(&entries, 2, SortOrder::Descending);
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 1]);
+    // Page 1 holds the oldest entries
+    assert_eq!(pages[0][1]["timestamp"], json!("20250101T000000Z"));
+
+    let render = |number: usize, page_entries: Vec<Value>| {
+        let options = FeedOptions {
+            page: Some(FeedPage {
+                number,
+                total: 2,
+                base_url: "https://example.com/feeds/".to_string(),
+                file_name: "feed.xml".to_string(),
+            }),
+            ..FeedOptions::default()
+        };
+        json_to_rss_with_options(page_entries, "Feed", "Description", "https://example.com", None, "en-us", &options)
+    };
+    let archive = render(1, pages[0].clone());
+    let current = render(2, pages[1].clone());
+
+    assert!(archive.contains(r#"<atom:link href="https://example.com/feeds/feed.xml" rel="current"/>"#), "{}", archive);
+    assert!(archive.contains("<fh:archive"), "{}", archive);
+    assert!(archive.contains(r#"xmlns:fh="http://purl.org/syndication/history/1.0""#));
+    assert!(!archive.contains("prev-archive") && !archive.contains("next-archive"));
+
+    assert!(current.contains(r#"<atom:link href="https://example.com/feeds/feed-1.xml" rel="prev-archive"/>"#), "{}", current);
+    assert!(!current.contains("<fh:archive") && !current.contains(r#"rel="current""#));
+    assert!(validate_feed(&archive).is_ok() && validate_feed(&current).is_ok());
+
+    // A middle archive links both ways
+    let middle = FeedPage { number: 2, total: 4, base_url: "https://example.com".to_string(), file_name: "feed.xml".to_string() };
+    let xml = json_to_rss_with_options(vec![entry()], "Feed", "Description", "https://example.com", None, "en-us", &FeedOptions { page: Some(middle), ..FeedOptions::default() });
+    assert!(xml.contains(r#"href="https://example.com/feed-1.xml" rel="prev-archive""#), "{}", xml);
+    assert!(xml.contains(r#"href="https://example.com/feed-3.xml" rel="next-archive""#));
+    assert!(xml.contains(r#"href="https://example.com/feed.xml" rel="current""#));
+}
+
+#[test]
+fn test_link_template_renders_per_entry_urls() {
+    let config: Value =
+        serde_yaml::from_str("build:\n  link_template: \"https://site/bill/{state}/{session}/{bill_id}\"\n").unwrap();
+    let options = get_feed_options_from_config(&config);
+
+    let mut senate = entry();
+    senate["id"] = json!("SF 12");
+    senate["sources"]["log"] = json!("wy-legislation/country:us/state:wy/sessions/2024/bills/SF12/logs/20240101T000000Z_introduced.json");
+    let xml = json_to_rss_with_options(vec![entry(), senate.clone()], "Feed", "Description", "https://example.com", None, "en-us", &options);
+    assert!(xml.contains("<link>https://site/bill/wy/2025/HB0001</link>"), "{}", xml);
+    assert!(xml.contains("<link>https://site/bill/wy/2024/SF%2012</link>"), "{}", xml);
+
+    // Entries without the path fall back to the default link
+    assert_eq!(render_link_template("https://site/{state}/{bill_id}", &json!({ "id": "HB1" })), None);
+}
+
+#[test]
+fn test_links_unchanged_without_template() {
+    let mut linked = entry();
+    linked["sources"]["bill"] = json!("wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/metadata.json");
+    let options = get_feed_options_from_config(&serde_yaml::from_str("build:\n  ttl: 60\n").unwrap());
+    assert_eq!(options.link_template, None);
+
+    let xml = json_to_rss_with_options(vec![linked], "Feed", "Description", "https://example.com", None, "en-us", &options);
+    assert!(xml.contains("<link>https://example.com/wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/metadata.json</link>"), "{}", xml);
+}
+
+#[test]
+fn test_tag_author_is_written_for_matched_items() {
+    let config: Value = serde_yaml::from_str(
+        "tags:\n  budget:\n    description: \"Budgets\"\n    author: \"budget@example.org (Budget Desk)\"\n  transit:\n    author: \"transit@example.org\"\n  schools:\n    description: \"No curator\"\n  zoning:\n    author: \"Jane Doe\"\n",
+    )
+    .unwrap();
+    let options = get_feed_options_from_config(&config);
+    assert_eq!(options.tag_authors.len(), 3);
+
+    let mut both = entry();
+    both["id"] = json!("HB0002");
+    both["sources"]["log"] = json!("wy-legislation/country:us/state:wy/sessions/2025/bills/HB0002/logs/20250101T000000Z_introduced.json");
+    both["tags"] = json!({ "transit": { "final_score": 0.9 }, "budget": { "final_score": 0.8 } });
+    let mut uncurated = entry();
+    uncurated["id"] = json!("HB0003");
+    uncurated["sources"]["log"] = json!("wy-legislation/country:us/state:wy/sessions/2025/bills/HB0003/logs/20250101T000000Z_introduced.json");
+    uncurated["tags"] = json!({ "schools": { "final_score": 0.9 } });
+    let mut named = entry();
+    named["id"] = json!("HB0004");
+    named["sources"]["log"] = json!("wy-legislation/country:us/state:wy/sessions/2025/bills/HB0004/logs/20250101T000000Z_introduced.json");
+    named["tags"] = json!({ "zoning": { "final_score": 0.9 } });
+
+    let xml = json_to_rss_with_options(vec![both, uncurated, named], "Feed", "Description", "https://example.com", None, "en-us", &options);
+    let channel = rss::Channel::read_from(xml.as_bytes()).unwrap();
+    let authors: Vec<Option<&str>> = channel.items().iter().map(|i| i.author()).collect();
+    assert_eq!(authors, vec![Some("budget@example.org (Budget Desk)"), None, None]);
+
+    // A name without an email address isn't a valid RSS 2.0 <author>, so it becomes <dc:creator>
+    let creators: Vec<Vec<String>> = channel
+        .items()
+        .iter()
+        .map(|i| i.dublin_core_ext().map(|dc| dc.creators.clone()).unwrap_or_default())
+        .collect();
+    assert_eq!(creators, vec![vec![], vec![], vec!["Jane Doe".to_string()]]);
+    assert!(xml.contains("xmlns:dc="), "{}", xml);
+    assert!(validate_feed(&xml).is_ok());
+}
+
+#[test]
+fn test_category_domain_and_tag_labels() {
+    let config: Value = serde_yaml::from_str(
+        "build:\n  category_domain: \"https://example.org/topics\"\ntags:\n  budget:\n    label: \"Budget & Appropriations\"\n  transit: {}\n",
+    )
+    .unwrap();
+    let options = get_feed_options_from_config(&config);
+
+    let mut tagged = entry();
+    tagged["tags"] = json!({ "budget": { "final_score": 0.9 }, "transit": { "final_score": 0.8 } });
+    let xml = json_to_rss_with_options(vec![tagged.clone()], "Feed", "Description", "https://example.com", None, "en-us", &options);
+    assert!(
+        xml.contains(r#"<category domain="https://example.org/topics">Budget &amp; Appropriations</category>"#),
+        "{}",
+        xml
+    );
+    // Tags without a label keep their key
+    assert!(xml.contains(r#"<category domain="https://example.org/topics">transit</category>"#), "{}", xml);
+    assert!(validate_feed(&xml).is_ok());
+
+    // Unconfigured: bare tag keys, no domain
+    let plain = json_to_rss_with_options(vec![tagged], "Feed", "Description", "https://example.com", None, "en-us", &FeedOptions::default());
+    assert!(plain.contains("<category>budget</category>"), "{}", plain);
+}
+
+#[test]
+fn test_link_falls_back_to_first_nonempty_bill_source() {
+    // `title` isn't a string, so this bill wouldn't parse as `Metadata`; the link is still found
+    let entry = json!({
+        "bill": { "title": 7, "sources": [{ "url": " " }, { "url": "https://example.com/hb1" }] }
+    });
+    assert_eq!(extract_link(&entry, None).as_deref(), Some("https://example.com/hb1"));
+    assert_eq!(extract_link(&json!({ "bill": { "sources": [{ "url": "" }] } }), None), None);
+}
@@ -0,0 +1,316 @@
+use std::io::Write;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+const GOVBOT_YML: &str = r#"
+tags:
+  budget:
+    description: "Budget and appropriations"
+    include_keywords: ["budget"]
+    threshold: 0.5
+"#;
+
+/// Embedding files that pass the download checks but fail to load, so `tag` uses keyword matching offline
+fn write_unloadable_embedding_files(dir: &Path) {
+    let mut model = vec![0x08u8, 0x07];
+    model.resize(govbot::embeddings::MIN_MODEL_SIZE as usize, 0);
+    fs::write(dir.join("model.onnx"), model).unwrap();
+    let tokenizer = format!(r#"{{"model":{{"type":"unknown"}},"padding":"{}"}}"#, " ".repeat(1024));
+    fs::write(dir.join("tokenizer.json"), tokenizer).unwrap();
+}
+
+fn fixture() -> String {
+    (0..250)
+        .map(|i| {
+            let title = if i % 2 == 0 { "General budget bill" } else { "Fishing licenses" };
+            format!(
+                r#"{{"id":"HB{}","bill":{{"title":"{}"}},"log":{{"action":{{"description":"Introduced"}}}},"sources":{{"log":"country:us/state:wy/sessions/2025/logs/HB{}.json"}}}}"#,
+                i, title, i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+fn run_tag(dir: &Path, args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .arg("tag")
+        .args(args)
+        .current_dir(dir)
+        .env("GOVBOT_DIR", dir.join(".govbot"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.unwrap_or("").as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn setup() -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    fs::write(tmp.path().join("govbot.yml"), GOVBOT_YML).unwrap();
+    fs::create_dir_all(tmp.path().join(".govbot")).unwrap();
+    write_unloadable_embedding_files(&tmp.path().join(".govbot"));
+    fs::write(tmp.path().join("fixture.ndjson"), fixture()).unwrap();
+    tmp
+}
+
+#[test]
+fn test_input_file_reports_total_and_completion() {
+    let tmp = setup();
+    let out = run_tag(tmp.path(), &["--input", "fixture.ndjson"], None);
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("Reading 250 JSON lines from fixture.ndjson"), "{}", stderr);
+    assert!(stderr.contains("Read 250/250 lines (100%"), "{}", stderr);
+
+    // Same matches as the stdin path
+    let piped = setup();
+    let from_stdin = run_tag(piped.path(), &[], Some(&fixture()));
+    assert!(from_stdin.status.success(), "{}", String::from_utf8_lossy(&from_stdin.stderr));
+    assert_eq!(out.stdout, from_stdin.stdout);
+    assert_eq!(String::from_utf8_lossy(&out.stdout).lines().count(), 125);
+    assert!(!String::from_utf8_lossy(&from_stdin.stderr).contains("100%"));
+}
+
+#[test]
+fn test_missing_input_file_is_an_error() {
+    let tmp = setup();
+    let out = run_tag(tmp.path(), &["--input", "missing.ndjson"], None);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("Failed to open missing.ndjson"));
+}
+
+#[test]
+fn test_no_download_skips_network_and_uses_keywords() {
+    let tmp = tempfile::tempdir().unwrap();
+    fs::write(tmp.path().join("govbot.yml"), GOVBOT_YML).unwrap();
+
+    // Any download attempt would connect to this proxy
+    let proxy = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    proxy.set_nonblocking(true).unwrap();
+    let proxy_url = format!("http://{}", proxy.local_addr().unwrap());
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["tag", "--no-download"])
+        .current_dir(tmp.path())
+        .env("GOVBOT_DIR", tmp.path().join(".govbot"))
+        .env("HTTPS_PROXY", &proxy_url)
+        .env("HTTP_PROXY", &proxy_url)
+        .env_remove("NO_PROXY")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(fixture().as_bytes()).unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("--no-download is set"), "{}", stderr);
+    assert!(stderr.contains("using keyword-based matching"), "{}", stderr);
+    assert!(!stderr.contains("Downloading"), "{}", stderr);
+    assert_eq!(
+        proxy.accept().map_err(|e| e.kind()).err(),
+        Some(std::io::ErrorKind::WouldBlock),
+        "a download was attempted"
+    );
+    assert!(!tmp.path().join(".govbot/model.onnx").exists());
+    assert_eq!(String::from_utf8_lossy(&out.stdout).lines().count(), 125);
+}
+
+/// Three bills hitting 1, 2, and 4 of the tag's 4 keywords (keyword scores 0.75, ~0.85, 1.0)
+fn threshold_project() -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    fs::write(
+        tmp.path().join("govbot.yml"),
+        "tags:\n  budget:\n    include_keywords: [budget, appropriation, fiscal, revenue]\n    threshold: 0.8\n",
+    )
+    .unwrap();
+    fs::create_dir_all(tmp.path().join(".govbot")).unwrap();
+    write_unloadable_embedding_files(&tmp.path().join(".govbot"));
+    tmp
+}
+
+fn threshold_fixture() -> String {
+    ["General budget bill", "Budget appropriation bill", "Budget appropriation fiscal revenue act"]
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            format!(
+                r#"{{"id":"HB{}","bill":{{"title":"{}"}},"log":{{"action":{{"description":"Introduced"}}}},"sources":{{"log":"country:us/state:wy/sessions/2025/logs/HB{}.json"}}}}"#,
+                i, title, i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+/// Bill ids written to the `budget` tag file by a run with `args`
+fn tagged_with(args: &[&str]) -> Vec<String> {
+    let tmp = threshold_project();
+    let out = run_tag(tmp.path(), args, Some(&threshold_fixture()));
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    let tag_path = tmp.path().join("country:us/state:wy/sessions/2025/tags/budget.tag.json");
+    if !tag_path.exists() {
+        return Vec::new();
+    }
+    let tag_file: serde_json::Value = serde_json::from_str(&fs::read_to_string(tag_path).unwrap()).unwrap();
+    let mut ids: Vec<String> = tag_file["bills"].as_object().unwrap().keys().cloned().collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_threshold_override_applies_to_every_tag() {
+    assert_eq!(tagged_with(&[]), vec!["HB1", "HB2"]);
+    // Lower: the single-keyword bill the config rejects is now written
+    assert_eq!(tagged_with(&["--threshold", "0.7"]), vec!["HB0", "HB1", "HB2"]);
+    // Higher: the weaker match the config accepts is suppressed
+    assert_eq!(tagged_with(&["--threshold", "0.9"]), vec!["HB2"]);
+
+    // The override isn't recorded as the tag's configuration
+    let tmp = threshold_project();
+    let out = run_tag(tmp.path(), &["--threshold", "0.7"], Some(&threshold_fixture()));
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    let tag_path = tmp.path().join("country:us/state:wy/sessions/2025/tags/budget.tag.json");
+    let tag_file = govbot::embeddings::load_tag_file(&tag_path).unwrap();
+    assert_eq!(tag_file.tag_config.threshold, 0.8);
+    let configured = govbot::embeddings::load_tags_config(tmp.path().join("govbot.yml")).unwrap();
+    assert_eq!(
+        tag_file.metadata.tag_config_hash,
+        govbot::embeddings::hash_text(&serde_json::to_string(&configured[0]).unwrap())
+    );
+
+    let out = run_tag(tmp.path(), &["--threshold", "1.5"], Some(&threshold_fixture()));
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("between 0.0 and 1.0"));
+}
+
+#[test]
+fn test_min_score_only_raises_the_bar() {
+    assert_eq!(tagged_with(&["--min-score", "0.9"]), vec!["HB2"]);
+    // Below the configured threshold it changes nothing
+    assert_eq!(tagged_with(&["--min-score", "0.5"]), vec!["HB1", "HB2"]);
+    // Combined with --threshold, the stricter of the two applies
+    assert_eq!(tagged_with(&["--threshold", "0.7", "--min-score", "0.8"]), vec!["HB1", "HB2"]);
+
+    let tmp = threshold_project();
+    let out = run_tag(tmp.path(), &["--min-score", "1.5"], Some(&threshold_fixture()));
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("between 0.0 and 1.0"));
+}
+
+#[test]
+fn test_jobs_keeps_output_and_rejects_zero() {
+    // Keyword mode has no embedding sessions to share, so --jobs must leave results unchanged
+    let tmp = setup();
+    let out = run_tag(tmp.path(), &["--jobs", "3"], Some(&fixture()));
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    assert_eq!(String::from_utf8_lossy(&out.stdout).lines().count(), 125);
+
+    let out = run_tag(tmp.path(), &["--jobs", "0"], Some(&fixture()));
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("--jobs must be at least 1"));
+}
+
+#[test]
+fn test_global_exclude_keyword_vetoes_matching_entries() {
+    let fixture = [
+        ("HB1", "General budget bill"),
+        ("HB2", "Procedural budget resolution"),
+        ("HB3", "Budget for proceduralism studies"),
+    ]
+    .iter()
+    .map(|(id, title)| {
+        format!(
+            r#"{{"id":"{}","bill":{{"title":"{}"}},"sources":{{"log":"country:us/state:wy/sessions/2025/logs/{}.json"}}}}"#,
+            id, title, id
+        )
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    let tmp = setup();
+    let out = run_tag(tmp.path(), &["--exclude-keyword", "procedural"], Some(&fixture));
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    let ids: Vec<String> = String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["id"].as_str().unwrap().to_string())
+        .collect();
+    // Word-boundary match: "proceduralism" doesn't contain the keyword "procedural"
+    assert_eq!(ids, vec!["HB1", "HB3"]);
+
+    let tag_file = fs::read_to_string(tmp.path().join("country:us/state:wy/sessions/2025/tags/budget.tag.json")).unwrap();
+    assert!(!tag_file.contains("HB2"));
+}
+
+#[test]
+fn test_normalized_identifiers_share_one_tag_entry() {
+    let fixture = ["HB 1", "HB1", "hb-1"]
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id":"{}","bill":{{"title":"General budget bill"}},"sources":{{"log":"country:us/state:wy/sessions/2025/logs/x.json"}}}}"#,
+                id
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let tag_ids = |args: &[&str]| {
+        let tmp = setup();
+        let out = run_tag(tmp.path(), args, Some(&fixture));
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        // Output lines keep their raw ids either way
+        assert_eq!(String::from_utf8_lossy(&out.stdout).lines().count(), 3);
+        let tag_file: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(tmp.path().join("country:us/state:wy/sessions/2025/tags/budget.tag.json")).unwrap(),
+        )
+        .unwrap();
+        let mut ids: Vec<String> = tag_file["bills"].as_object().unwrap().keys().cloned().collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(tag_ids(&[]), vec!["HB 1", "HB1", "hb-1"]);
+    assert_eq!(tag_ids(&["--normalize-identifiers"]), vec!["HB1"]);
+}
+
+#[test]
+fn test_logs_join_finds_tags_written_under_normalized_identifiers() {
+    let tmp = setup();
+    let logs_dir = tmp.path().join(".govbot/repos/wy-legislation/country:us/state:wy/sessions/2025/bills/HB1/logs");
+    fs::create_dir_all(&logs_dir).unwrap();
+    fs::write(
+        logs_dir.join("20250101T000000Z_introduced.json"),
+        r#"{"action": {"description": "Introduced general budget bill"}, "bill_id": "HB 1"}"#,
+    )
+    .unwrap();
+    let logs = |join: &str| -> serde_json::Value {
+        let out = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "wy", "--filter", "none", "--join", join])
+            .current_dir(tmp.path())
+            .env("GOVBOT_DIR", tmp.path().join(".govbot"))
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        serde_json::from_str(String::from_utf8(out.stdout).unwrap().lines().next().unwrap()).unwrap()
+    };
+
+    let entry = logs("bill");
+    assert_eq!(entry["id"], "HB 1");
+    let out = run_tag(tmp.path(), &["--normalize-identifiers"], Some(&format!("{}\n", entry)));
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    let tag_file = fs::read_to_string(tmp.path().join("country:us/state:wy/sessions/2025/tags/budget.tag.json")).unwrap();
+    assert!(tag_file.contains("\"HB1\""), "{}", tag_file);
+
+    let joined = logs("tags");
+    assert!(joined["tags"]["budget"]["final_score"].as_f64().unwrap() > 0.5, "{}", joined);
+}
@@ -0,0 +1,50 @@
+use govbot::embeddings::load_tag_file;
+use govbot::{hash_text, TAG_FILE_VERSION};
+use serde_json::{json, Value};
+use std::fs;
+
+/// A tag file as written before schema versioning: no `version`, no `keyword_match`
+fn version_zero_fixture() -> Value {
+    json!({
+        "metadata": { "last_run": "2025-01-01T00:00:00Z", "model": "keyword-fallback", "tag_config_hash": "stale" },
+        "tag_config": { "name": "budget", "description": "Budget bills", "include_keywords": ["budget"] },
+        "text_cache": {},
+        "bills": {
+            "HB0001": {
+                "text_hash": "abc",
+                "score": { "final_score": 0.75, "base_embedding": null, "example_similarity": null, "negative_penalty": 0.0 }
+            }
+        }
+    })
+}
+
+#[test]
+fn test_version_zero_tag_file_is_migrated_in_memory() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("budget.tag.json");
+    fs::write(&path, version_zero_fixture().to_string()).unwrap();
+
+    let tag_file = load_tag_file(&path).unwrap();
+    assert_eq!(tag_file.metadata.version, TAG_FILE_VERSION);
+    let expected_hash = hash_text(&serde_json::to_string(&tag_file.tag_config).unwrap());
+    assert_eq!(tag_file.metadata.tag_config_hash, expected_hash);
+    assert!(tag_file.bills["HB0001"].score.keyword_match.is_empty());
+    assert_eq!(tag_file.tag_config.threshold, 0.5);
+
+    // Reading never modifies the file; only `tag` writes the upgraded form
+    assert_eq!(fs::read_to_string(&path).unwrap(), version_zero_fixture().to_string());
+}
+
+#[test]
+fn test_current_tag_file_is_not_rewritten() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("budget.tag.json");
+    let mut fixture = version_zero_fixture();
+    fixture["metadata"]["version"] = json!(TAG_FILE_VERSION);
+    let contents = fixture.to_string();
+    fs::write(&path, &contents).unwrap();
+
+    let tag_file = load_tag_file(&path).unwrap();
+    assert_eq!(tag_file.metadata.tag_config_hash, "stale");
+    assert_eq!(fs::read_to_string(&path).unwrap(), contents);
+}
@@ -0,0 +1,90 @@
+use govbot::{Embedder, TagDefinition, TagMatcher};
+use ndarray::Array1;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Letter-frequency embedder that counts calls across all of its forks
+struct CountingEmbedder {
+    calls: Arc<AtomicUsize>,
+    forks: Arc<AtomicUsize>,
+}
+
+impl Embedder for CountingEmbedder {
+    fn embed(&mut self, text: &str) -> anyhow::Result<Array1<f32>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let mut counts = vec![0f32; 26];
+        for c in text.to_lowercase().chars().filter(char::is_ascii_lowercase) {
+            counts[(c as u8 - b'a') as usize] += 1.0;
+        }
+        Ok(Array1::from(counts))
+    }
+
+    fn fork(&self) -> anyhow::Result<Self> {
+        self.forks.fetch_add(1, Ordering::SeqCst);
+        Ok(Self {
+            calls: Arc::clone(&self.calls),
+            forks: Arc::clone(&self.forks),
+        })
+    }
+}
+
+fn budget_tag() -> TagDefinition {
+    TagDefinition {
+        name: "budget".to_string(),
+        description: "State budget and appropriations".to_string(),
+        examples: vec!["General appropriations act".to_string(), "Supplemental budget".to_string()],
+        include_keywords: vec!["budget".to_string()],
+        exclude_keywords: Vec::new(),
+        negative_examples: vec!["Fishing license fees".to_string()],
+        threshold: 0.5,
+    }
+}
+
+#[test]
+fn test_shared_matchers_score_identically_and_precompute_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let forks = Arc::new(AtomicUsize::new(0));
+    let embedder = CountingEmbedder {
+        calls: Arc::clone(&calls),
+        forks: Arc::clone(&forks),
+    };
+
+    let matcher = TagMatcher::new(embedder, vec![budget_tag()]).unwrap();
+    // Description, two examples, one negative example
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    let shared = matcher.clone_shared().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 4, "clone_shared must not recompute tag embeddings");
+    assert_eq!(forks.load(Ordering::SeqCst), 1);
+    assert!(matcher.shares_tags_with(&shared));
+
+    let entry = json!({"bill": {"title": "Amends the state budget"}, "log": {"action": {"description": "Introduced"}}});
+    let scores = |m: &TagMatcher<CountingEmbedder>| {
+        m.match_json_value(&entry)
+            .unwrap()
+            .into_iter()
+            .map(|(tag, score)| (tag, serde_json::to_value(score).unwrap()))
+            .collect::<Vec<_>>()
+    };
+    let original = scores(&matcher);
+    assert_eq!(original.len(), 1);
+    assert_eq!(original, scores(&shared));
+    // One embedding per scored entry, none for the tags
+    assert_eq!(calls.load(Ordering::SeqCst), 6);
+}
+
+#[test]
+fn test_threshold_override_does_not_leak_into_shared_matchers() {
+    let embedder = CountingEmbedder {
+        calls: Arc::new(AtomicUsize::new(0)),
+        forks: Arc::new(AtomicUsize::new(0)),
+    };
+    let matcher = TagMatcher::new(embedder, vec![budget_tag()]).unwrap();
+    let shared = matcher.clone_shared().unwrap();
+
+    let strict = shared.with_threshold(0.99);
+    assert_eq!(strict.tag_definitions()["budget"].threshold, 0.99);
+    assert_eq!(matcher.tag_definitions()["budget"].threshold, 0.5);
+    assert!(!matcher.shares_tags_with(&strict));
+}
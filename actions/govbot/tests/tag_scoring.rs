@@ -0,0 +1,45 @@
+use govbot::embeddings::{composite_score, min_base_similarity};
+
+/// Similarities sampled across the cosine range the matcher clamps to
+fn samples() -> Vec<f32> {
+    (0..=40).map(|i| i as f32 / 40.0).collect()
+}
+
+/// A tag the fast-reject skips must never have passed its threshold on the exhaustive path,
+/// so the matched set is unchanged
+#[test]
+fn test_fast_reject_never_drops_a_match() {
+    for threshold in [0.0, 0.2, 0.35, 0.5, 0.65, 0.8, 0.95] {
+        for has_examples in [false, true] {
+            let floor = min_base_similarity(threshold, has_examples);
+            for base in samples().into_iter().filter(|b| *b < floor) {
+                for example in samples() {
+                    for negative in [None, Some(0.0), Some(0.5)] {
+                        let example = has_examples.then_some(example);
+                        let score = composite_score(Some(base), example, false, negative, threshold);
+                        assert!(
+                            score < threshold,
+                            "skipped tag would have matched: threshold {threshold}, base {base}, example {example:?}, score {score}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fast_reject_skips_unrelated_entries() {
+    // Default threshold: an unrelated entry is rejected on its base similarity alone
+    assert!(0.1 < min_base_similarity(0.5, false));
+    assert!(0.1 < min_base_similarity(0.5, true));
+    // ...while a base similarity that examples could lift over the threshold is still scored
+    assert!(0.2 > min_base_similarity(0.5, true));
+    assert!(composite_score(Some(0.2), Some(1.0), false, None, 0.5) >= 0.5);
+}
+
+#[test]
+fn test_keyword_match_meets_threshold() {
+    assert_eq!(composite_score(Some(0.0), Some(0.0), true, None, 0.6), 0.6);
+    assert!(composite_score(Some(0.0), None, true, Some(1.0), 0.6) < 0.6);
+}
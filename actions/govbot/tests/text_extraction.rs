@@ -0,0 +1,85 @@
+mod common;
+
+use common::log_entry;
+use govbot::embeddings::{load_text_extraction_config, match_tags_keywords, match_tags_keywords_with_extraction};
+use govbot::selectors::{ocd_files_select, ocd_files_select_default, TextExtractionConfig};
+use govbot::TagDefinition;
+use serde_json::json;
+
+fn entry() -> serde_json::Value {
+    let mut entry = log_entry("SF0001", "An act relating to schools", &[]);
+    entry["bill"]["from_organization"] = json!("Senate");
+    entry["bill"]["classification"] = json!(["appropriation"]);
+    entry["bill"]["notes"] = json!([{ "text": "Fiscal note attached" }, { "text": "Amended in committee" }]);
+    entry
+}
+
+fn config(include: &[&str], exclude: &[&str]) -> TextExtractionConfig {
+    TextExtractionConfig {
+        include: include.iter().map(|s| s.to_string()).collect(),
+        exclude: exclude.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_default_config_matches_default_selector() {
+    assert_eq!(ocd_files_select(&entry(), &TextExtractionConfig::default()), ocd_files_select_default(&entry()));
+}
+
+#[test]
+fn test_included_paths_contribute_text() {
+    let default = ocd_files_select_default(&entry());
+    assert!(!default.contains("appropriation"));
+
+    let text = ocd_files_select(&entry(), &config(&["bill.classification", "bill.notes.text"], &[]));
+    assert!(text.starts_with(&default));
+    assert!(text.contains("appropriation"));
+    assert!(text.contains("Fiscal note attached") && text.contains("Amended in committee"));
+}
+
+#[test]
+fn test_excluded_keys_are_removed() {
+    assert!(ocd_files_select_default(&entry()).contains("Senate"));
+
+    let text = ocd_files_select(&entry(), &config(&["bill.from_organization"], &["from_organization"]));
+    assert!(!text.contains("Senate"), "{}", text);
+    assert!(text.contains("An act relating to schools"));
+}
+
+#[test]
+fn test_keyword_matching_uses_extraction_config() {
+    let tag = TagDefinition {
+        name: "budget".to_string(),
+        description: String::new(),
+        examples: Vec::new(),
+        include_keywords: vec!["appropriation".to_string()],
+        exclude_keywords: Vec::new(),
+        negative_examples: Vec::new(),
+        threshold: 0.5,
+    };
+    let tags = [tag];
+
+    assert!(match_tags_keywords(&tags, &entry()).is_empty());
+    let matched = match_tags_keywords_with_extraction(&tags, &entry(), &config(&["bill.classification"], &[]));
+    assert_eq!(matched.len(), 1);
+}
+
+#[test]
+fn test_text_extraction_loaded_from_govbot_yml() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("govbot.yml");
+    std::fs::write(&path, "tags: {}\ntext_extraction:\n  include: [bill.classification]\n  exclude: [from_organization]\n").unwrap();
+    assert_eq!(load_text_extraction_config(&path).unwrap(), config(&["bill.classification"], &["from_organization"]));
+
+    std::fs::write(&path, "tags: {}\n").unwrap();
+    assert_eq!(load_text_extraction_config(&path).unwrap(), TextExtractionConfig::default());
+}
+
+/// `TagMatcher::match_json_value_verbose` (and `tag --show-text`) report exactly this text;
+/// building a matcher needs the ONNX model, so the extraction is checked directly
+#[test]
+fn test_extracted_text_contains_title_and_action() {
+    let text = ocd_files_select(&entry(), &TextExtractionConfig::default());
+    assert!(text.contains("An act relating to schools"), "{}", text);
+    assert!(text.contains("Introduced"), "{}", text);
+}
@@ -0,0 +1,63 @@
+use futures::StreamExt;
+use govbot::prelude::*;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+const LOGS_DIR: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs";
+
+fn write_vote_event(git_dir: &Path) -> serde_json::Value {
+    let body = json!({
+        "motion_text": "Third reading",
+        "counts": [{ "option": "yes", "value": 40 }, { "option": "no", "value": 20 }],
+        "votes": [{ "option": "yes", "voter_name": "Smith" }]
+    });
+    let logs = git_dir.join(LOGS_DIR);
+    fs::create_dir_all(&logs).unwrap();
+    fs::write(logs.join("20250102T000000Z_third_reading.vote_event.pass.json"), body.to_string()).unwrap();
+    body
+}
+
+async fn vote_event(git_dir: &Path, detail: bool) -> LogContent {
+    let config = ConfigBuilder::new(git_dir).vote_event_detail(detail).build().unwrap();
+    let mut stream = PipelineProcessor::new(config).process();
+    stream.next().await.unwrap().unwrap().log
+}
+
+#[tokio::test]
+async fn test_vote_event_detail_carries_tallies() {
+    let dir = tempfile::tempdir().unwrap();
+    let body = write_vote_event(dir.path());
+
+    match vote_event(dir.path(), true).await {
+        LogContent::VoteEvent { result, detail } => {
+            assert_eq!(result, VoteEventResult::Pass);
+            assert_eq!(detail, body);
+            assert_eq!(detail["counts"][0]["value"], 40);
+        }
+        other => panic!("expected a vote event, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_vote_event_detail_is_opt_in() {
+    let dir = tempfile::tempdir().unwrap();
+    write_vote_event(dir.path());
+
+    let content = vote_event(dir.path(), false).await;
+    assert!(matches!(content, LogContent::VoteEvent { result: VoteEventResult::Pass, ref detail } if detail.is_null()));
+    assert_eq!(serde_json::to_value(&content).unwrap(), json!({ "result": "pass" }));
+}
+
+#[test]
+fn test_vote_event_result_tokens_round_trip() {
+    for token in ["pass", "fail", "tie", "withdrawn", "tabled", "no_quorum", "unknown"] {
+        let result = VoteEventResult::from(token);
+        assert_eq!(result.as_str(), token);
+        assert_eq!(result.to_string(), token);
+        assert_eq!(serde_json::to_value(result).unwrap(), json!(token));
+        assert_eq!(serde_json::from_value::<VoteEventResult>(json!(token)).unwrap(), result);
+    }
+    assert_eq!(VoteEventResult::from("NO-QUORUM"), VoteEventResult::NoQuorum);
+    assert_eq!(VoteEventResult::from("adjourned"), VoteEventResult::Unknown);
+}
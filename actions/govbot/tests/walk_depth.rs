@@ -0,0 +1,72 @@
+use govbot::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const SHALLOW: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0001/logs";
+// Five extra directories put this log 14 levels below the repos directory
+const DEEP: &str = "wy-legislation/country:us/state:wy/sessions/2025/bills/HB0002/a/b/c/d/e/logs";
+
+fn write_logs(repos: &Path) {
+    for (dir, bill) in [(SHALLOW, "HB0001"), (DEEP, "HB0002")] {
+        fs::create_dir_all(repos.join(dir)).unwrap();
+        fs::write(
+            repos.join(dir).join("20250101T000000Z_introduced.json"),
+            format!(r#"{{"action": {{"description": "Introduced"}}, "bill_id": "{}"}}"#, bill),
+        )
+        .unwrap();
+    }
+}
+
+async fn bills(config: Config) -> Vec<String> {
+    let mut bills: Vec<String> = PipelineProcessor::new(config)
+        .process()
+        .filter_map(|entry| async move { entry.ok() })
+        .filter_map(|entry| async move {
+            match entry.log {
+                LogContent::Full(log) => log["bill_id"].as_str().map(str::to_string),
+                _ => None,
+            }
+        })
+        .collect()
+        .await;
+    bills.sort();
+    bills
+}
+
+#[tokio::test]
+async fn test_logs_beyond_max_depth_are_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    write_logs(dir.path());
+
+    assert_eq!(bills(ConfigBuilder::new(dir.path()).build().unwrap()).await, vec!["HB0001"]);
+    assert_eq!(
+        bills(ConfigBuilder::new(dir.path()).max_depth(Some(20)).build().unwrap()).await,
+        vec!["HB0001", "HB0002"]
+    );
+    assert_eq!(
+        bills(ConfigBuilder::new(dir.path()).max_depth(None).build().unwrap()).await,
+        vec!["HB0001", "HB0002"]
+    );
+}
+
+#[test]
+fn test_logs_command_max_depth() {
+    let dir = tempfile::tempdir().unwrap();
+    write_logs(&dir.path().join("repos"));
+
+    let count = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_govbot"))
+            .args(["logs", "--repos", "wy", "--filter", "none", "--limit", "none"])
+            .args(args)
+            .env("GOVBOT_DIR", dir.path())
+            .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap().lines().count()
+    };
+
+    assert_eq!(count(&[]), 1);
+    assert_eq!(count(&["--max-depth", "20"]), 2);
+}
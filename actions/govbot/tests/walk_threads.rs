@@ -0,0 +1,48 @@
+use govbot::prelude::*;
+
+const MOCK_REPOS: &str = "mocks/.govbot/repos";
+
+async fn discovered_files(config: Config) -> Vec<String> {
+    let mut ids: Vec<String> = PipelineProcessor::new(config)
+        .process()
+        .filter_map(|entry| async move { entry.ok().map(|e| e.filename) })
+        .collect()
+        .await;
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn test_single_walk_thread_discovers_full_file_set() {
+    let default_files = discovered_files(ConfigBuilder::new(MOCK_REPOS).build().unwrap()).await;
+    let serial_files = discovered_files(ConfigBuilder::new(MOCK_REPOS).walk_threads(1).build().unwrap()).await;
+    let pooled_files = discovered_files(ConfigBuilder::new(MOCK_REPOS).walk_threads(2).build().unwrap()).await;
+
+    assert!(!default_files.is_empty());
+    assert_eq!(serial_files, default_files);
+    assert_eq!(pooled_files, default_files);
+}
+
+#[test]
+fn test_walks_with_the_same_thread_count_share_one_pool() {
+    let pool = |threads| match govbot::processor::walk_parallelism(Some(threads)) {
+        jwalk::Parallelism::RayonExistingPool { pool, .. } => pool,
+        _ => panic!("{} threads should walk on a rayon pool", threads),
+    };
+    assert!(std::sync::Arc::ptr_eq(&pool(3), &pool(3)));
+    assert_eq!(pool(3).current_num_threads(), 3);
+    assert!(matches!(govbot::processor::walk_parallelism(Some(1)), jwalk::Parallelism::Serial));
+}
+
+#[test]
+fn test_logs_rejects_zero_threads() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_govbot"))
+        .args(["logs", "--threads", "0"])
+        .env("GOVBOT_DIR", "mocks/.govbot")
+        .env_remove("GOVBOT_REPO_URL_TEMPLATE")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--threads"), "{}", stderr);
+}